@@ -0,0 +1,4 @@
+-- Season 8 qualifiers disabled race room chat via a hardcoded check in the racetime.gg bot.
+-- Record this as per-race chat settings instead, keeping any explicit overrides.
+UPDATE races SET allow_prerace_chat = FALSE WHERE series = 's' AND event = '8' AND phase = 'Qualifier' AND allow_prerace_chat IS NULL;
+UPDATE races SET allow_midrace_chat = FALSE WHERE series = 's' AND event = '8' AND phase = 'Qualifier' AND allow_midrace_chat IS NULL;
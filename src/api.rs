@@ -586,3 +586,385 @@ pub(crate) async fn entrants_csv(db_pool: &State<PgPool>, http_client: &State<re
     }
     Ok((ContentType::CSV, csv.into_inner()?))
 }
+
+#[derive(Debug, thiserror::Error, rocket_util::Error)]
+pub(crate) enum ValidateSettingsError {
+    #[error(transparent)] Json(#[from] serde_json::Error),
+    #[error(transparent)] Roll(#[from] racetime_bot::RollError),
+    #[error(transparent)] Sql(#[from] sqlx::Error),
+}
+
+impl<E: Into<ValidateSettingsError>> From<E> for StatusOrError<ValidateSettingsError> {
+    fn from(e: E) -> Self {
+        Self::Err(e.into())
+    }
+}
+
+/// Checks a settings preset against the randomizer version used for the given goal, without rolling a seed.
+#[rocket::post("/api/v1/validate-settings?<goal>&<api_key>", data = "<settings>")]
+pub(crate) async fn validate_settings(db_pool: &State<PgPool>, goal: &str, api_key: &str, settings: String) -> Result<(ContentType, String), StatusOrError<ValidateSettingsError>> {
+    let mut transaction = db_pool.begin().await?;
+    Scopes::default().validate(&mut transaction, api_key).await?.ok_or(StatusOrError::Status(Status::Forbidden))?;
+    transaction.commit().await?;
+    let goal = goal.parse::<racetime_bot::Goal>().map_err(|racetime_bot::GoalFromStrError| StatusOrError::Status(Status::NotFound))?;
+    // random settings goals don't have a fixed randomizer version to validate against
+    let rando_version = goal.try_rando_version(None).ok_or(StatusOrError::Status(Status::BadRequest))?;
+    let settings = serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(&settings).map_err(|_| StatusOrError::Status(Status::BadRequest))?;
+    let error = racetime_bot::validate_settings(rando_version, &settings).await?;
+    Ok((ContentType::JSON, serde_json::to_string(&json!({
+        "valid": error.is_none(),
+        "error": error,
+    }))?))
+}
+
+#[derive(Debug, thiserror::Error, rocket_util::Error)]
+pub(crate) enum GoalsError {
+    #[error(transparent)] Json(#[from] serde_json::Error),
+    #[error(transparent)] Sql(#[from] sqlx::Error),
+}
+
+impl<E: Into<GoalsError>> From<E> for StatusOrError<GoalsError> {
+    fn from(e: E) -> Self {
+        Self::Err(e.into())
+    }
+}
+
+/// Lists the racetime.gg goals handled by the race bot, along with the ways `!seed` can be used for each.
+#[rocket::get("/api/v1/goals?<api_key>")]
+pub(crate) async fn goals(db_pool: &State<PgPool>, api_key: &str) -> Result<(ContentType, String), StatusOrError<GoalsError>> {
+    let mut transaction = db_pool.begin().await?;
+    Scopes::default().validate(&mut transaction, api_key).await?.ok_or(StatusOrError::Status(Status::Forbidden))?;
+    transaction.commit().await?;
+    let goals = all::<racetime_bot::Goal>().map(|goal| json!({
+        "name": goal.as_str(),
+        "custom": goal.is_custom(),
+        "language": goal.language().short_code(),
+        "bilingual": goal.is_bilingual(),
+        "presets": goal.seed_presets(),
+    })).collect_vec();
+    Ok((ContentType::JSON, serde_json::to_string(&goals)?))
+}
+
+/// Lists the randomizer version used by the race bot for each goal, so that local generators can be kept in sync.
+///
+/// Goals that use random settings are listed as dynamic since their version is determined by the RSL script.
+#[rocket::get("/api/v1/rando-versions?<api_key>")]
+pub(crate) async fn rando_versions(db_pool: &State<PgPool>, api_key: &str) -> Result<(ContentType, String), StatusOrError<GoalsError>> {
+    let mut transaction = db_pool.begin().await?;
+    Scopes::default().validate(&mut transaction, api_key).await?.ok_or(StatusOrError::Status(Status::Forbidden))?;
+    transaction.commit().await?;
+    let versions = all::<racetime_bot::Goal>().map(|goal| json!({
+        "name": goal.as_str(),
+        "version": goal.try_rando_version(None).map_or_else(|| json!({"type": "dynamic"}), |version| version.to_json()),
+    })).collect_vec();
+    Ok((ContentType::JSON, serde_json::to_string(&versions)?))
+}
+
+#[derive(Debug, thiserror::Error, rocket_util::Error)]
+pub(crate) enum MwCompatError {
+    #[error(transparent)] Cal(#[from] cal::Error),
+    #[error(transparent)] EventData(#[from] event::DataError),
+    #[error(transparent)] Json(#[from] serde_json::Error),
+    #[error(transparent)] LatestAppVersion(#[from] crate::mw::LatestAppVersionError),
+    #[error(transparent)] Sql(#[from] sqlx::Error),
+}
+
+impl<E: Into<MwCompatError>> From<E> for StatusOrError<MwCompatError> {
+    fn from(e: E) -> Self {
+        Self::Err(e.into())
+    }
+}
+
+/// Lets the multiworld app check whether it can be used for seeds of the given race or goal.
+///
+/// The required app version is the latest release, see [`crate::mw::latest_app_version`]. The randomizer version used by the race or goal is included for reference.
+///
+/// Doesn't require an API key since it's called by the multiworld app itself.
+#[rocket::get("/api/v1/mw-compat?<version>&<race>&<goal>")]
+pub(crate) async fn mw_compat(db_pool: &State<PgPool>, http_client: &State<reqwest::Client>, version: &str, race: Option<Id<Races>>, goal: Option<&str>) -> Result<(ContentType, String), StatusOrError<MwCompatError>> {
+    let version = version.parse::<semver::Version>().map_err(|_| StatusOrError::Status(Status::BadRequest))?;
+    let mut transaction = db_pool.begin().await?;
+    let (goal, event) = match (race, goal) {
+        (Some(race), None) => {
+            let race = cal::Race::from_id(&mut transaction, http_client, race).await?;
+            let goal = racetime_bot::Goal::for_event(race.series, &race.event).ok_or(StatusOrError::Status(Status::NotFound))?;
+            (goal, Some(race.event(&mut transaction).await?))
+        }
+        (None, Some(goal)) => (goal.parse::<racetime_bot::Goal>().map_err(|racetime_bot::GoalFromStrError| StatusOrError::Status(Status::NotFound))?, None),
+        (None, None) | (Some(_), Some(_)) => return Err(StatusOrError::Status(Status::BadRequest)),
+    };
+    transaction.commit().await?;
+    // random settings seeds are rolled on the latest version supported by the RSL script
+    let rando_version = goal.try_rando_version(event.as_ref()).unwrap_or(racetime_bot::VersionedBranch::Latest(ootr_utils::Branch::Dev));
+    let required_version = crate::mw::latest_app_version(http_client).await?;
+    let supported = version >= required_version;
+    Ok((ContentType::JSON, serde_json::to_string(&json!({
+        "supported": supported,
+        "requiredVersion": required_version.to_string(),
+        "randoVersion": rando_version.to_json(),
+        "message": (!supported).then(|| format!("Mido's House Multiworld version {required_version} or later is required for this race, but you have version {version}. Please update the app to continue.")),
+    }))?))
+}
+
+#[derive(Debug, thiserror::Error, rocket_util::Error)]
+pub(crate) enum PendingSpoilerUnlocksError {
+    #[error(transparent)] Io(#[from] io::Error),
+    #[error(transparent)] Json(#[from] serde_json::Error),
+    #[error(transparent)] Sql(#[from] sqlx::Error),
+}
+
+impl<E: Into<PendingSpoilerUnlocksError>> From<E> for StatusOrError<PendingSpoilerUnlocksError> {
+    fn from(e: E) -> Self {
+        Self::Err(e.into())
+    }
+}
+
+#[derive(Serialize)]
+struct PendingSpoilerUnlock {
+    file_stem: Option<String>,
+    web_id: Option<i64>,
+    tfb_uuid: Option<Uuid>,
+    race: Option<Id<Races>>,
+    room: Option<String>,
+    /// `None` if the spoiler log will be unlocked once the race ends but the race hasn't ended yet.
+    expected_unlock: Option<DateTime<Utc>>,
+    overdue: bool,
+}
+
+/// Lists seeds whose spoiler logs are still locked, so admins can check that unlocks actually happen.
+///
+/// Triforce Blitz seeds are unlocked by triforceblitz.com, which can't be checked from here, so they're listed until their race has ended.
+///
+/// Triforce Blitz seeds of the day are not listed. They're only used in unofficial race rooms, so they're not recorded in the database, and triforceblitz.com unlocks their spoiler logs on a fixed schedule regardless of any race.
+#[rocket::get("/api/v1/pending-spoiler-unlocks?<api_key>")]
+pub(crate) async fn pending_spoiler_unlocks(db_pool: &State<PgPool>, seed_metadata: &State<Arc<RwLock<HashMap<String, racetime_bot::SeedMetadata>>>>, api_key: &str) -> Result<(ContentType, String), StatusOrError<PendingSpoilerUnlocksError>> {
+    let mut transaction = db_pool.begin().await?;
+    let me = Scopes::default().validate(&mut transaction, api_key).await?.ok_or(StatusOrError::Status(Status::Forbidden))?;
+    if !me.is_archivist {
+        return Err(StatusOrError::Status(Status::Forbidden))
+    }
+    let now = Utc::now();
+    let mut pending = Vec::default();
+    let mut seen_file_stems = HashSet::new();
+    for row in sqlx::query!(r#"SELECT id AS "id: Id<Races>", room, file_stem, locked_spoiler_log_path, web_id, web_gen_time, tfb_uuid, start, async_start1, async_start2, async_start3, end_time, async_end1, async_end2, async_end3 FROM races WHERE NOT ignored AND (locked_spoiler_log_path IS NOT NULL OR web_id IS NOT NULL OR tfb_uuid IS NOT NULL)"#).fetch_all(&mut *transaction).await? {
+        let expected_unlock = if row.start.is_some() {
+            row.end_time
+        } else {
+            [(row.async_start1, row.async_end1), (row.async_start2, row.async_end2), (row.async_start3, row.async_end3)].into_iter()
+                .filter(|(start, _)| start.is_some())
+                .map(|(_, end)| end)
+                .collect::<Option<Vec<_>>>()
+                .and_then(|ends| ends.into_iter().max())
+        };
+        let is_locked = if let Some(ref locked_spoiler_log_path) = row.locked_spoiler_log_path {
+            // the locked spoiler log is moved to the public seed directory when unlocked
+            tokio::fs::try_exists(locked_spoiler_log_path).await?
+        } else if let (Some(ref file_stem), Some(web_gen_time)) = (&row.file_stem, row.web_gen_time) {
+            // seeds are deleted from ootrandomizer.com after a while so their spoiler logs can't be unlocked anymore
+            web_gen_time > now - seed::WEB_TIMEOUT && !tokio::fs::try_exists(Path::new(seed::DIR).join(format!("{file_stem}_Spoiler.json"))).await?
+        } else if row.tfb_uuid.is_some() {
+            expected_unlock.map_or(true, |expected_unlock| expected_unlock > now)
+        } else {
+            false
+        };
+        if !is_locked { continue }
+        if let Some(ref file_stem) = row.file_stem {
+            seen_file_stems.insert(file_stem.clone());
+        }
+        pending.push(PendingSpoilerUnlock {
+            file_stem: row.file_stem,
+            web_id: row.web_id,
+            tfb_uuid: row.tfb_uuid,
+            race: Some(row.id),
+            room: row.room,
+            overdue: expected_unlock.is_some_and(|expected_unlock| expected_unlock < now),
+            expected_unlock,
+        });
+    }
+    transaction.commit().await?;
+    // seeds rolled in unofficial rooms handled by this instance
+    lock!(@read seed_metadata = seed_metadata; for (file_stem, metadata) in &*seed_metadata {
+        if metadata.locked_spoiler_log_path.is_some() && !seen_file_stems.contains(file_stem) {
+            pending.push(PendingSpoilerUnlock {
+                file_stem: Some(file_stem.clone()),
+                web_id: None,
+                tfb_uuid: None,
+                race: None,
+                room: None,
+                expected_unlock: None,
+                overdue: false,
+            });
+        }
+    });
+    Ok((ContentType::JSON, serde_json::to_string(&pending)?))
+}
+
+#[derive(Debug, thiserror::Error, rocket_util::Error)]
+pub(crate) enum OpenRoomsError {
+    #[error(transparent)] Json(#[from] serde_json::Error),
+    #[error(transparent)] Sql(#[from] sqlx::Error),
+}
+
+impl<E: Into<OpenRoomsError>> From<E> for StatusOrError<OpenRoomsError> {
+    fn from(e: E) -> Self {
+        Self::Err(e.into())
+    }
+}
+
+/// Lists the racetime.gg rooms currently handled by this instance of the race bot.
+#[rocket::get("/api/v1/open-rooms?<api_key>")]
+pub(crate) async fn open_rooms(db_pool: &State<PgPool>, clean_shutdown: &State<Arc<Mutex<racetime_bot::CleanShutdown>>>, api_key: &str) -> Result<(ContentType, String), StatusOrError<OpenRoomsError>> {
+    let mut transaction = db_pool.begin().await?;
+    Scopes::default().validate(&mut transaction, api_key).await?.ok_or(StatusOrError::Status(Status::Forbidden))?;
+    transaction.commit().await?;
+    let open_rooms = lock!(clean_shutdown = clean_shutdown; clean_shutdown.open_rooms.iter().map(|(room_url, open_room)| json!({
+        "url": format!("https://{}{room_url}", racetime_host()),
+        "goal": open_room.goal.as_str(),
+        "unlisted": open_room.unlisted,
+        "official": open_room.official,
+    })).collect_vec());
+    Ok((ContentType::JSON, serde_json::to_string(&open_rooms)?))
+}
+
+#[derive(Debug, thiserror::Error, rocket_util::Error)]
+pub(crate) enum RaceResultSummaryError {
+    #[error(transparent)] Cal(#[from] cal::Error),
+    #[error(transparent)] Json(#[from] serde_json::Error),
+    #[error(transparent)] Sql(#[from] sqlx::Error),
+}
+
+impl<E: Into<RaceResultSummaryError>> From<E> for StatusOrError<RaceResultSummaryError> {
+    fn from(e: E) -> Self {
+        Self::Err(e.into())
+    }
+}
+
+/// Returns the summaries recorded by the race bot for the race rooms of a finished official race, in the order the rooms were raced.
+///
+/// For asynchronous races, this is only available once all halves have finished, so it can't be used to learn a time before racing against it.
+/// For Triforce Blitz races, this is only available once the scores have been confirmed.
+#[rocket::get("/api/v1/race/<race>/result-summary?<api_key>")]
+pub(crate) async fn race_result_summary(db_pool: &State<PgPool>, http_client: &State<reqwest::Client>, race: Id<Races>, api_key: &str) -> Result<(ContentType, String), StatusOrError<RaceResultSummaryError>> {
+    let mut transaction = db_pool.begin().await?;
+    Scopes::default().validate(&mut transaction, api_key).await?.ok_or(StatusOrError::Status(Status::Forbidden))?;
+    let race = cal::Race::from_id(&mut transaction, http_client, race).await?;
+    let summaries = sqlx::query!("SELECT room, summary FROM race_result_summaries WHERE race = $1", race.id as _).fetch_all(&mut *transaction).await?;
+    let results_published = sqlx::query_scalar!("SELECT results_published FROM races WHERE id = $1", race.id as _).fetch_one(&mut *transaction).await?;
+    transaction.commit().await?;
+    if race.series == crate::series::Series::TriforceBlitz && !results_published {
+        return Err(StatusOrError::Status(Status::NotFound))
+    }
+    // only serve results once every part of the race has been played, so a half that's still pending doesn't leak the other half's results
+    let rooms = race.cal_events()
+        .map(|cal_event| cal_event.end().and(cal_event.room()).map(|room| room.to_string()))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(StatusOrError::Status(Status::NotFound))?;
+    if rooms.is_empty() {
+        return Err(StatusOrError::Status(Status::NotFound))
+    }
+    let parts = rooms.iter()
+        .map(|room| summaries.iter().find(|row| row.room == *room).map(|row| &row.summary))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(StatusOrError::Status(Status::NotFound))?;
+    Ok((ContentType::JSON, serde_json::to_string(&json!({
+        "race": race.id,
+        "parts": parts,
+    }))?))
+}
+
+#[derive(Debug, thiserror::Error, rocket_util::Error)]
+pub(crate) enum VerifySeedPasswordError {
+    #[error(transparent)] Cal(#[from] cal::Error),
+    #[error(transparent)] Json(#[from] serde_json::Error),
+    #[error(transparent)] Sql(#[from] sqlx::Error),
+}
+
+impl<E: Into<VerifySeedPasswordError>> From<E> for StatusOrError<VerifySeedPasswordError> {
+    fn from(e: E) -> Self {
+        Self::Err(e.into())
+    }
+}
+
+/// The seed password is revealed in the race room once the race starts.
+fn is_password_revealed(start: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+    start.is_some_and(|start| start <= now)
+}
+
+/// Lets the multiworld app check whether a player entered the correct password for a password-locked seed.
+///
+/// The password is given in the request body using the same note characters as the race room message. Only entrants of the race may use this once their race has started, and the password itself is never returned.
+#[rocket::post("/api/v1/race/<race>/verify-password?<api_key>", data = "<password>")]
+pub(crate) async fn verify_seed_password(db_pool: &State<PgPool>, http_client: &State<reqwest::Client>, race: Id<Races>, api_key: &str, password: String) -> Result<(ContentType, String), StatusOrError<VerifySeedPasswordError>> {
+    let mut transaction = db_pool.begin().await?;
+    let me = Scopes::default().validate(&mut transaction, api_key).await?.ok_or(StatusOrError::Status(Status::Forbidden))?;
+    let race = cal::Race::from_id(&mut transaction, http_client, race).await?;
+    let entrants = match race.entrants {
+        Entrants::Open | Entrants::Count { .. } | Entrants::Named(_) => &[][..],
+        Entrants::Two(ref entrants) => &entrants[..],
+        Entrants::Three(ref entrants) => &entrants[..],
+    };
+    let mut entrant_idx = None;
+    for (idx, entrant) in entrants.iter().enumerate() {
+        if entrant.includes_user(&mut transaction, &me).await? {
+            entrant_idx = Some(idx);
+            break
+        }
+    }
+    transaction.commit().await?;
+    let Some(entrant_idx) = entrant_idx else { return Err(StatusOrError::Status(Status::Forbidden)) };
+    // the password is stored as soon as the seed is rolled, so refuse to check it before it's revealed to prevent brute-forcing
+    let start = race.cal_events().nth(if let RaceSchedule::Async { .. } = race.schedule { entrant_idx } else { 0 }).and_then(|cal_event| cal_event.start());
+    if !is_password_revealed(start, Utc::now()) {
+        return Err(StatusOrError::Status(Status::Conflict))
+    }
+    let Some(expected) = race.seed.password else { return Err(StatusOrError::Status(Status::NotFound)) };
+    let Ok(password) = password.trim().chars().map(OcarinaNote::try_from).collect::<Result<Vec<_>, _>>() else { return Err(StatusOrError::Status(Status::BadRequest)) };
+    Ok((ContentType::JSON, serde_json::to_string(&json!({
+        "matches": password == expected,
+    }))?))
+}
+
+#[derive(Debug, thiserror::Error, rocket_util::Error)]
+pub(crate) enum CharityTotalError {
+    #[error(transparent)] Event(#[from] event::Error),
+    #[error(transparent)] EventData(#[from] event::DataError),
+    #[error(transparent)] Sql(#[from] sqlx::Error),
+}
+
+impl<E: Into<CharityTotalError>> From<E> for StatusOrError<CharityTotalError> {
+    fn from(e: E) -> Self {
+        Self::Err(e.into())
+    }
+}
+
+/// Updates the donation total reported by the `!charity` race room command, given in cents.
+///
+/// Requires an API key with the `write` scope belonging to an organizer of the event.
+#[rocket::post("/api/v1/event/<series>/<event>/charity-total?<api_key>&<total>")]
+pub(crate) async fn set_charity_total(db_pool: &State<PgPool>, series: crate::series::Series, event: &str, api_key: &str, total: u32) -> Result<Status, StatusOrError<CharityTotalError>> {
+    let mut transaction = db_pool.begin().await?;
+    let me = Scopes { write: true, ..Scopes::default() }.validate(&mut transaction, api_key).await?.ok_or(StatusOrError::Status(Status::Forbidden))?;
+    let event = event::Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    if !event.organizers(&mut transaction).await?.contains(&me) {
+        return Err(StatusOrError::Status(Status::Forbidden))
+    }
+    if event.charity_donation_url.is_none() {
+        return Err(StatusOrError::Status(Status::NotFound))
+    }
+    sqlx::query!("UPDATE events SET charity_total = $1 WHERE series = $2 AND event = $3", i64::from(total), event.series as _, &event.event).execute(&mut *transaction).await?;
+    transaction.commit().await?;
+    Ok(Status::NoContent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn password_not_revealed_before_start() {
+        let now = Utc::now();
+        assert!(!is_password_revealed(None, now));
+        assert!(!is_password_revealed(Some(now + TimeDelta::minutes(15)), now));
+        assert!(is_password_revealed(Some(now - TimeDelta::seconds(1)), now));
+    }
+}
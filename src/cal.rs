@@ -78,6 +78,16 @@ impl Entrant {
         })
     }
 
+    /// Checks whether the given user is this entrant or a member of this entrant's team.
+    pub(crate) async fn includes_user(&self, transaction: &mut Transaction<'_, Postgres>, user: &User) -> sqlx::Result<bool> {
+        let racetime_matches = |racetime_id: &Option<String>| racetime_id.as_ref().is_some_and(|racetime_id| user.racetime.as_ref().is_some_and(|racetime| racetime.id == *racetime_id));
+        Ok(match self {
+            Self::MidosHouseTeam(team) => team.member_ids(transaction).await?.contains(&user.id),
+            Self::Discord { id, racetime_id, .. } => user.discord.as_ref().is_some_and(|discord| discord.id == *id) || racetime_matches(racetime_id),
+            Self::Named { racetime_id, .. } => racetime_matches(racetime_id),
+        })
+    }
+
     pub(crate) async fn to_html(&self, transaction: &mut Transaction<'_, Postgres>, discord_ctx: &DiscordCtx, running_text: bool) -> Result<RawHtml<String>, discord_bot::Error> {
         Ok(match self {
             Self::MidosHouseTeam(team) => team.to_html(transaction, running_text).await?,
@@ -328,10 +338,16 @@ pub(crate) struct Race {
     pub(crate) seed: seed::Data,
     pub(crate) video_urls: HashMap<Language, Url>,
     pub(crate) restreamers: HashMap<Language, String>,
+    /// Languages whose restreams must be ready before auto-start is unlocked. If empty, all restreams are required.
+    pub(crate) required_restream_languages: HashSet<Language>,
     pub(crate) last_edited_by: Option<Id<Users>>,
     pub(crate) last_edited_at: Option<DateTime<Utc>>,
     pub(crate) ignored: bool,
     pub(crate) schedule_locked: bool,
+    /// Overrides the event's setting for whether chat is allowed in the race room before the race starts.
+    pub(crate) allow_prerace_chat: Option<bool>,
+    /// Overrides the event's setting for whether chat is allowed in the race room during the race.
+    pub(crate) allow_midrace_chat: Option<bool>,
     pub(crate) notified: bool,
 }
 
@@ -397,10 +413,16 @@ impl Race {
             restreamer_de,
             video_url_pt,
             restreamer_pt,
+            restream_required,
+            restream_required_fr,
+            restream_required_de,
+            restream_required_pt,
             last_edited_by AS "last_edited_by: Id<Users>",
             last_edited_at,
             ignored,
             schedule_locked,
+            allow_prerace_chat,
+            allow_midrace_chat,
             notified
         FROM races WHERE id = $1"#, id as _).fetch_one(&mut **transaction).await?;
         let source = if let Some(id) = row.challonge_match {
@@ -498,6 +520,7 @@ impl Race {
         update_end!(async_end1, async_room1, "UPDATE races SET async_end1 = $1 WHERE id = $2");
         update_end!(async_end2, async_room2, "UPDATE races SET async_end2 = $1 WHERE id = $2");
         update_end!(async_end3, async_room3, "UPDATE races SET async_end3 = $1 WHERE id = $2");
+        let progression_spoiler = racetime_bot::Goal::for_event(row.series, &row.event).is_some_and(|goal| goal.unlock_spoiler_log(None, true, false) == racetime_bot::UnlockSpoilerLog::Progression);
         Ok(Self {
             series: row.series,
             event: row.event,
@@ -528,7 +551,7 @@ impl Race {
                 row.hash4,
                 row.hash5,
                 row.seed_password.as_deref(),
-                false, // no official races with progression spoilers so far
+                progression_spoiler,
             ),
             video_urls: all().filter_map(|language| match language {
                 English => row.video_url.clone(),
@@ -542,10 +565,18 @@ impl Race {
                 German => row.restreamer_de.clone(),
                 Portuguese => row.restreamer_pt.clone(),
             }.map(|restreamer| (language, restreamer))).collect(),
+            required_restream_languages: all().filter(|language| match language {
+                English => row.restream_required,
+                French => row.restream_required_fr,
+                German => row.restream_required_de,
+                Portuguese => row.restream_required_pt,
+            }).collect(),
             last_edited_by: row.last_edited_by,
             last_edited_at: row.last_edited_at,
             ignored: row.ignored,
             schedule_locked: row.schedule_locked,
+            allow_prerace_chat: row.allow_prerace_chat,
+            allow_midrace_chat: row.allow_midrace_chat,
             notified: row.notified,
             id, source, entrants,
         })
@@ -689,6 +720,7 @@ impl Race {
                                 HashMap::default()
                             },
                             restreamers: HashMap::default(),
+                            required_restream_languages: HashSet::default(),
                             last_edited_by: None,
                             last_edited_at: None,
                             ignored: match race.status {
@@ -696,6 +728,8 @@ impl Race {
                                 league::MatchStatus::Confirmed => false,
                             },
                             schedule_locked: false,
+                            allow_prerace_chat: None,
+                            allow_midrace_chat: None,
                             notified: false,
                             id,
                         }).await?;
@@ -740,10 +774,13 @@ impl Race {
                     seed: seed::Data::default(), //TODO
                     video_urls: event.video_url.iter().map(|video_url| (English, video_url.clone())).collect(), //TODO sync between event and race? Video URL fields for other languages on event::Data?
                     restreamers: HashMap::default(),
+                    required_restream_languages: HashSet::default(),
                     last_edited_by: None,
                     last_edited_at: None,
                     ignored: false,
                     schedule_locked: false,
+                    allow_prerace_chat: None,
+                    allow_midrace_chat: None,
                     notified: false,
                     id, schedule,
                 }).await?;
@@ -802,10 +839,13 @@ impl Race {
                             collect![English => format!("https://twitch.tv/{restream}").parse()?]
                         },
                         restreamers: HashMap::default(),
+                        required_restream_languages: HashSet::default(),
                         last_edited_by: None,
                         last_edited_at: None,
                         ignored: false,
                         schedule_locked: false,
+                        allow_prerace_chat: None,
+                        allow_midrace_chat: None,
                         notified: false,
                         id, entrants,
                     }).await?;
@@ -831,10 +871,13 @@ impl Race {
                             seed: seed::Data::default(),
                             video_urls: HashMap::default(),
                             restreamers: HashMap::default(),
+                            required_restream_languages: HashSet::default(),
                             last_edited_by: None,
                             last_edited_at: None,
                             ignored: false,
                             schedule_locked: false,
+                            allow_prerace_chat: None,
+                            allow_midrace_chat: None,
                             notified: false,
                             schedule,
                         };
@@ -1495,6 +1538,7 @@ pub(crate) enum RaceHandleMode {
 #[derive(Debug, thiserror::Error, rocket_util::Error)]
 pub(crate) enum Error {
     #[error(transparent)] ChronoParse(#[from] chrono::format::ParseError),
+    #[error(transparent)] Csv(#[from] csv::Error),
     #[error(transparent)] Discord(#[from] discord_bot::Error),
     #[error(transparent)] Event(#[from] event::DataError),
     #[error(transparent)] ParseInt(#[from] std::num::ParseIntError),
@@ -1506,6 +1550,8 @@ pub(crate) enum Error {
     #[error(transparent)] TimeFromLocal(#[from] wheel::traits::TimeFromLocalError<DateTime<Tz>>),
     #[error(transparent)] Url(#[from] url::ParseError),
     #[error(transparent)] Wheel(#[from] wheel::Error),
+    #[error("schedule sheet has no “start” column")]
+    ScheduleSheetStartColumn,
     #[error("no team with this ID")]
     UnknownTeam,
     #[error("start.gg team ID {0} is not associated with a Mido's House team")]
@@ -1528,6 +1574,7 @@ impl IsNetworkError for Error {
     fn is_network_error(&self) -> bool {
         match self {
             Self::ChronoParse(_) => false,
+            Self::Csv(_) => false,
             Self::Discord(_) => false,
             Self::Event(_) => false,
             Self::ParseInt(_) => false,
@@ -1539,6 +1586,7 @@ impl IsNetworkError for Error {
             Self::TimeFromLocal(_) => false,
             Self::Url(_) => false,
             Self::Wheel(e) => e.is_network_error(),
+            Self::ScheduleSheetStartColumn => false,
             Self::UnknownTeam => false,
             Self::UnknownTeamStartGG(_) => false,
             Self::UnqualifiedEntrant { .. } => false,
@@ -1578,12 +1626,35 @@ fn dtend<Z: TimeZone + IntoIcsTzid>(datetime: DateTime<Z>) -> DtEnd<'static> {
     dtend
 }
 
-async fn add_event_races(transaction: &mut Transaction<'_, Postgres>, discord_ctx: &DiscordCtx, http_client: &reqwest::Client, cal: &mut ICalendar<'_>, event: &event::Data<'_>) -> Result<(), Error> {
+/// Adds the event's races to the calendar. If a user is given, only races they're entered in are added, and of async races only their own half.
+async fn add_event_races(transaction: &mut Transaction<'_, Postgres>, discord_ctx: &DiscordCtx, http_client: &reqwest::Client, cal: &mut ICalendar<'_>, event: &event::Data<'_>, user: Option<&User>) -> Result<(), Error> {
     let now = Utc::now();
     let mut latest_instantiated_weeklies = HashMap::new();
     for race in Race::for_event(transaction, http_client, event).await?.into_iter() {
         for race_event in race.cal_events() {
             if let Some(start) = race_event.start() {
+                if let Some(user) = user {
+                    let entrants = match race.entrants {
+                        Entrants::Open | Entrants::Count { .. } | Entrants::Named(_) => &[][..],
+                        Entrants::Two(ref entrants) => &entrants[..],
+                        Entrants::Three(ref entrants) => &entrants[..],
+                    };
+                    // don't reveal when the user's opponents are playing their halves of an async
+                    let entrants = match race_event.kind {
+                        EventKind::Normal => entrants,
+                        EventKind::Async1 => entrants.get(0..1).unwrap_or_default(),
+                        EventKind::Async2 => entrants.get(1..2).unwrap_or_default(),
+                        EventKind::Async3 => entrants.get(2..3).unwrap_or_default(),
+                    };
+                    let mut is_entrant = false;
+                    for entrant in entrants {
+                        if entrant.includes_user(&mut *transaction, user).await? {
+                            is_entrant = true;
+                            break
+                        }
+                    }
+                    if !is_entrant { continue }
+                }
                 let mut cal_event = ics::Event::new(format!("{}{}@midos.house",
                     race.id,
                     match race_event.kind {
@@ -1728,6 +1799,13 @@ pub(crate) async fn index_help(pool: &State<PgPool>, me: Option<User>, uri: Orig
             }
             li : "In Mozilla Thunderbird, select New Calendar → On the Network. Paste the link into the “Location” field and click “Find Calendars”, then “Properties”. Enable “Read Only” and click “OK”, then “Subscribe”.";
         }
+        @if let Some(ref me) = me {
+            p {
+                : "A calendar of only your own races can be found at ";
+                code : uri!("https://midos.house", for_user(Suffix(me.id, "ics"))).to_string();
+                : ". For async races, it only includes your own half.";
+            }
+        }
         //p : "You can also find calendar links for individual events on their pages."; //TODO
     }).await
 }
@@ -1738,7 +1816,7 @@ pub(crate) async fn index(discord_ctx: &State<RwFuture<DiscordCtx>>, pool: &Stat
     let mut cal = ICalendar::new("2.0", concat!("midos.house/", env!("CARGO_PKG_VERSION")));
     for row in sqlx::query!(r#"SELECT series AS "series: Series", event FROM events WHERE listed"#).fetch_all(&mut *transaction).await? {
         let event = event::Data::new(&mut transaction, row.series, row.event).await?.expect("event deleted during calendar load");
-        add_event_races(&mut transaction, &*discord_ctx.read().await, http_client, &mut cal, &event).await?;
+        add_event_races(&mut transaction, &*discord_ctx.read().await, http_client, &mut cal, &event, None).await?;
     }
     transaction.commit().await?;
     Ok(Response(cal))
@@ -1750,7 +1828,21 @@ pub(crate) async fn for_series(discord_ctx: &State<RwFuture<DiscordCtx>>, pool:
     let mut cal = ICalendar::new("2.0", concat!("midos.house/", env!("CARGO_PKG_VERSION")));
     for event in sqlx::query_scalar!(r#"SELECT event FROM events WHERE listed AND series = $1"#, series as _).fetch_all(&mut *transaction).await? {
         let event = event::Data::new(&mut transaction, series, event).await?.expect("event deleted during calendar load");
-        add_event_races(&mut transaction, &*discord_ctx.read().await, http_client, &mut cal, &event).await?;
+        add_event_races(&mut transaction, &*discord_ctx.read().await, http_client, &mut cal, &event, None).await?;
+    }
+    transaction.commit().await?;
+    Ok(Response(cal))
+}
+
+#[rocket::get("/calendar/user/<id_ext>")]
+pub(crate) async fn for_user(discord_ctx: &State<RwFuture<DiscordCtx>>, pool: &State<PgPool>, http_client: &State<reqwest::Client>, id_ext: Suffix<'_, Id<Users>>) -> Result<Response<ICalendar<'static>>, StatusOrError<Error>> {
+    let Suffix(id, "ics") = id_ext else { return Err(StatusOrError::Status(Status::NotFound)) };
+    let mut transaction = pool.begin().await?;
+    let user = User::from_id(&mut *transaction, id).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let mut cal = ICalendar::new("2.0", concat!("midos.house/", env!("CARGO_PKG_VERSION")));
+    for row in sqlx::query!(r#"SELECT series AS "series: Series", event FROM events WHERE listed"#).fetch_all(&mut *transaction).await? {
+        let event = event::Data::new(&mut transaction, row.series, row.event).await?.expect("event deleted during calendar load");
+        add_event_races(&mut transaction, &*discord_ctx.read().await, http_client, &mut cal, &event, Some(&user)).await?;
     }
     transaction.commit().await?;
     Ok(Response(cal))
@@ -1761,7 +1853,7 @@ pub(crate) async fn for_event(discord_ctx: &State<RwFuture<DiscordCtx>>, pool: &
     let mut transaction = pool.begin().await?;
     let event = event::Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
     let mut cal = ICalendar::new("2.0", concat!("midos.house/", env!("CARGO_PKG_VERSION")));
-    add_event_races(&mut transaction, &*discord_ctx.read().await, http_client, &mut cal, &event).await?;
+    add_event_races(&mut transaction, &*discord_ctx.read().await, http_client, &mut cal, &event, None).await?;
     transaction.commit().await?;
     Ok(Response(cal))
 }
@@ -1992,10 +2084,13 @@ pub(crate) async fn create_race_post(pool: &State<PgPool>, discord_ctx: &State<R
                     seed: seed::Data::default(),
                     video_urls: HashMap::default(),
                     restreamers: HashMap::default(),
+                    required_restream_languages: HashSet::default(),
                     last_edited_by: None,
                     last_edited_at: None,
                     ignored: false,
                     schedule_locked: false,
+                    allow_prerace_chat: None,
+                    allow_midrace_chat: None,
                     notified: false,
                     scheduling_thread,
                 };
@@ -2505,134 +2600,282 @@ async fn import_race<'a>(mut transaction: Transaction<'a, Postgres>, discord_ctx
     Ok(transaction)
 }
 
-async fn auto_import_races_inner(db_pool: PgPool, http_client: reqwest::Client, config: Config, mut shutdown: rocket::Shutdown, discord_ctx: RwFuture<DiscordCtx>) -> Result<(), event::Error> {
-    loop {
-        let mut transaction = db_pool.begin().await?;
-        for row in sqlx::query!(r#"SELECT series AS "series: Series", event FROM events WHERE end_time IS NULL OR end_time > NOW()"#).fetch_all(&mut *transaction).await? {
-            let event = event::Data::new(&mut transaction, row.series, row.event).await?.expect("event deleted during transaction");
-            if event.auto_import && event.is_started(&mut transaction).await? {
-                match event.match_source() {
-                    MatchSource::Manual => {}
-                    MatchSource::Challonge { .. } => {} // Challonge's API doesn't provide enough data to automate race imports
-                    MatchSource::League => {}
-                    MatchSource::StartGG(event_slug) => {
-                        let (races, _) = startgg::races_to_import(&mut transaction, &http_client, &config, &event, event_slug).await?;
-                        for race in races {
-                            transaction = import_race(transaction, &*discord_ctx.read().await, race).await?;
-                        }
-                    }
+/// Minimum time between error reports about failed auto-imports for the same event.
+const AUTO_IMPORT_ERROR_REPORT_COOLDOWN: Duration = Duration::from_secs(60 * 60);
+
+/// Races that became available longer ago than this are marked as notified without pinging anyone,
+/// so that existing races don't all get notifications when this feature is first deployed.
+const NEXT_ROUND_NOTIFICATION_WINDOW: TimeDelta = TimeDelta::days(1);
+
+/// Notifies the entrants of unscheduled races that became available early because the previous round finished.
+///
+/// A race only counts as available once every other race in the same phase involving any of its teams has ended
+/// and every round those races belong to has ended in its entirety, so races that depend on other parts of the bracket are skipped.
+async fn notify_next_round_races<'a>(mut transaction: Transaction<'a, Postgres>, discord_ctx: &DiscordCtx, event: &event::Data<'_>, races: &[Race]) -> Result<Transaction<'a, Postgres>, event::Error> {
+    for race in races {
+        if race.ignored || race.scheduling_thread.is_none() || race.game.is_some_and(|game| game > 1) || !matches!(race.schedule, RaceSchedule::Unscheduled) { continue }
+        let Some(ref round) = race.round else { continue };
+        let team_ids = race.teams().map(|team| team.id).collect::<HashSet<_>>();
+        if team_ids.is_empty() { continue }
+        let previous_races = races.iter()
+            .filter(|other| !other.ignored && other.phase == race.phase && other.round.as_ref().is_some_and(|other_round| other_round != round))
+            .filter(|other| other.teams().any(|team| team_ids.contains(&team.id)))
+            .collect_vec();
+        if previous_races.is_empty() { continue } // first round of this phase
+        if !previous_races.iter().all(|previous_race| previous_race.is_ended()) { continue }
+        let previous_rounds = previous_races.iter().filter_map(|previous_race| previous_race.round.as_ref()).collect::<HashSet<_>>();
+        let Some(available_since) = races.iter()
+            .filter(|other| !other.ignored && other.phase == race.phase && other.round.as_ref().is_some_and(|other_round| previous_rounds.contains(other_round)))
+            .map(|other| other.schedule.end_time(&other.entrants))
+            .collect::<Option<Vec<_>>>() // previous round still ongoing
+            .and_then(|ends| ends.into_iter().max())
+        else { continue };
+        if sqlx::query!("UPDATE races SET next_round_notified = TRUE WHERE id = $1 AND NOT next_round_notified", race.id as _).execute(&mut *transaction).await?.rows_affected() > 0 {
+            if Utc::now() - available_since < NEXT_ROUND_NOTIFICATION_WINDOW {
+                transaction = discord_bot::notify_next_round(discord_ctx, transaction, event, race).await?;
+            }
+        }
+    }
+    Ok(transaction)
+}
+
+/// Sends scheduling reminders for matches that still aren't fully scheduled once the event's scheduling reminder threshold has passed since they became available.
+///
+/// A match becomes available when the event starts or, for later rounds, once every race of the previous rounds its teams played in has ended.
+/// Matches with multiple games only count as scheduled once every game has a start time.
+async fn send_scheduling_reminders<'a>(mut transaction: Transaction<'a, Postgres>, discord_ctx: &DiscordCtx, event: &event::Data<'_>, races: &[Race], threshold: Duration) -> Result<Transaction<'a, Postgres>, event::Error> {
+    let Some(event_start) = event.start(&mut transaction).await? else { return Ok(transaction) };
+    let threshold = TimeDelta::from_std(threshold).expect("scheduling reminder threshold too long");
+    let now = Utc::now();
+    'matches: for (_, games) in races.iter().filter(|race| !race.ignored && race.scheduling_thread.is_some()).into_group_map_by(|race| race.scheduling_thread) {
+        let is_scheduled = |race: &Race| match race.schedule {
+            RaceSchedule::Unscheduled => false,
+            RaceSchedule::Live { .. } => true,
+            RaceSchedule::Async { start1, start2, start3, .. } => start1.is_some() && start2.is_some() && (start3.is_some() || !matches!(race.entrants, Entrants::Three(_))),
+        };
+        if games.iter().all(|&race| is_scheduled(race)) { continue }
+        let race = games.into_iter().min_by_key(|race| race.game).expect("grouped races are nonempty");
+        let mut available_since = event_start;
+        if let Some(ref round) = race.round {
+            let team_ids = race.teams().map(|team| team.id).collect::<HashSet<_>>();
+            let previous_rounds = races.iter()
+                .filter(|other| !other.ignored && other.phase == race.phase && other.round.as_ref().is_some_and(|other_round| other_round != round))
+                .filter(|other| other.teams().any(|team| team_ids.contains(&team.id)))
+                .filter_map(|previous_race| previous_race.round.as_ref())
+                .collect::<HashSet<_>>();
+            for other in races.iter().filter(|other| !other.ignored && other.phase == race.phase && other.round.as_ref().is_some_and(|other_round| previous_rounds.contains(other_round))) {
+                let Some(end) = other.schedule.end_time(&other.entrants) else { continue 'matches }; // previous round still ongoing
+                available_since = available_since.max(end);
+            }
+        }
+        if now < available_since + threshold { continue }
+        if sqlx::query_scalar!(r#"SELECT scheduling_reminder_sent AS "scheduling_reminder_sent!" FROM races WHERE id = $1"#, race.id as _).fetch_one(&mut *transaction).await? { continue }
+        let sent;
+        (transaction, sent) = discord_bot::remind_scheduling(discord_ctx, transaction, event, race).await?;
+        // only mark the reminder as sent if it actually was, e.g. an organizer channel might be added later
+        if sent {
+            sqlx::query!("UPDATE races SET scheduling_reminder_sent = TRUE WHERE id = $1", race.id as _).execute(&mut *transaction).await?;
+        }
+    }
+    Ok(transaction)
+}
+
+async fn auto_import_event<'a>(mut transaction: Transaction<'a, Postgres>, http_client: &reqwest::Client, config: &Config, discord_ctx: &RwFuture<DiscordCtx>, series: Series, event: &str) -> Result<Transaction<'a, Postgres>, event::Error> {
+    let Some(event) = event::Data::new(&mut transaction, series, event).await? else { return Ok(transaction) }; // event deleted since the list of events was queried
+    let is_started = event.is_started(&mut transaction).await?;
+    if event.auto_import && is_started {
+        match event.match_source() {
+            MatchSource::Manual => {}
+            MatchSource::Challonge { .. } => {} // Challonge's API doesn't provide enough data to automate race imports
+            MatchSource::League => {}
+            MatchSource::StartGG(event_slug) => {
+                let (races, _) = startgg::races_to_import(&mut transaction, &http_client, &config, &event, event_slug).await?;
+                for race in races {
+                    transaction = import_race(transaction, &*discord_ctx.read().await, race).await?;
                 }
             }
-            if let Some(ref speedgaming_slug) = event.speedgaming_slug {
-                let schedule = sgl::schedule(&http_client, speedgaming_slug).await?;
-                let races = Race::for_event(&mut transaction, &http_client, &event).await?;
-                let (mut existing_races, mut unassigned_races) = races.into_iter().partition::<Vec<_>, _>(|race| matches!(race.source, Source::SpeedGaming { .. }));
-                existing_races.sort_unstable_by_key(|race| {
+        }
+    }
+    if let (true, Some(schedule_sheet_url)) = (event.auto_import, &event.schedule_sheet_url) {
+        let races = sheets::races_to_import(&mut transaction, http_client, &event, schedule_sheet_url).await?;
+        for race in races {
+            transaction = import_race(transaction, &*discord_ctx.read().await, race).await?;
+        }
+    }
+    if let Some(ref speedgaming_slug) = event.speedgaming_slug {
+        let schedule = sgl::schedule(&http_client, speedgaming_slug).await?;
+        let races = Race::for_event(&mut transaction, &http_client, &event).await?;
+        let (mut existing_races, mut unassigned_races) = races.into_iter().partition::<Vec<_>, _>(|race| matches!(race.source, Source::SpeedGaming { .. }));
+        existing_races.sort_unstable_by_key(|race| {
+            let Source::SpeedGaming { id } = race.source else { unreachable!("partitioned above") };
+            id
+        });
+        let disambiguation_messages = sqlx::query_scalar!(
+            "SELECT speedgaming_id FROM speedgaming_disambiguation_messages WHERE speedgaming_id = ANY($1) ORDER BY speedgaming_id ASC",
+            &schedule.iter().flat_map(|restream| restream.matches()).map(|restream_match| restream_match.id).collect_vec(),
+        ).fetch_all(&mut *transaction).await?;
+        for restream in schedule {
+            for restream_match in restream.matches() {
+                if let Ok(idx) = existing_races.binary_search_by_key(&restream_match.id, |race| {
                     let Source::SpeedGaming { id } = race.source else { unreachable!("partitioned above") };
                     id
-                });
-                let disambiguation_messages = sqlx::query_scalar!(
-                    "SELECT speedgaming_id FROM speedgaming_disambiguation_messages WHERE speedgaming_id = ANY($1) ORDER BY speedgaming_id ASC",
-                    &schedule.iter().flat_map(|restream| restream.matches()).map(|restream_match| restream_match.id).collect_vec(),
-                ).fetch_all(&mut *transaction).await?;
-                for restream in schedule {
-                    for restream_match in restream.matches() {
-                        if let Ok(idx) = existing_races.binary_search_by_key(&restream_match.id, |race| {
-                            let Source::SpeedGaming { id } = race.source else { unreachable!("partitioned above") };
-                            id
-                        }) {
-                            // this match is already assigned to a race, update it in case it got rescheduled or its restream info got changed
-                            let race = &mut existing_races[idx];
-                            restream.update_race(race, restream_match.id)?;
-                            race.save(&mut transaction).await?;
-                        } else if disambiguation_messages.binary_search(&restream_match.id).is_ok() {
-                            // this match is pending manual assignment, ignore it for now
-                        } else {
-                            let mut matching_races = Vec::default();
-                            for (idx, race) in unassigned_races.iter().enumerate() {
-                                if restream_match.matches(&mut transaction, &http_client, race).await? {
-                                    matching_races.push((idx, race));
-                                }
+                }) {
+                    // this match is already assigned to a race, update it in case it got rescheduled or its restream info got changed
+                    let race = &mut existing_races[idx];
+                    restream.update_race(race, restream_match.id)?;
+                    race.save(&mut transaction).await?;
+                } else if disambiguation_messages.binary_search(&restream_match.id).is_ok() {
+                    // this match is pending manual assignment, ignore it for now
+                } else {
+                    let mut matching_races = Vec::default();
+                    for (idx, race) in unassigned_races.iter().enumerate() {
+                        if restream_match.matches(&mut transaction, &http_client, race).await? {
+                            matching_races.push((idx, race));
+                        }
+                    }
+                    match matching_races.into_iter().at_most_one() {
+                        Ok(None) => {
+                            if let Some(organizer_channel) = event.discord_organizer_channel {
+                                let msg = MessageBuilder::default()
+                                    .push("could not find any races matching SpeedGaming match ")
+                                    .push_mono(restream_match.id.to_string())
+                                    //TODO describe match
+                                    //TODO instructions for how to fix?
+                                    .build();
+                                let notification = organizer_channel.say(&*discord_ctx.read().await, msg).await?;
+                                sqlx::query!(
+                                    "INSERT INTO speedgaming_disambiguation_messages (speedgaming_id, message_id) VALUES ($1, $2)",
+                                    restream_match.id, PgSnowflake(notification.id) as _,
+                                ).execute(&mut *transaction).await?;
                             }
-                            match matching_races.into_iter().at_most_one() {
-                                Ok(None) => {
-                                    if let Some(organizer_channel) = event.discord_organizer_channel {
-                                        let msg = MessageBuilder::default()
-                                            .push("could not find any races matching SpeedGaming match ")
-                                            .push_mono(restream_match.id.to_string())
-                                            //TODO describe match
-                                            //TODO instructions for how to fix?
-                                            .build();
-                                        let notification = organizer_channel.say(&*discord_ctx.read().await, msg).await?;
-                                        sqlx::query!(
-                                            "INSERT INTO speedgaming_disambiguation_messages (speedgaming_id, message_id) VALUES ($1, $2)",
-                                            restream_match.id, PgSnowflake(notification.id) as _,
-                                        ).execute(&mut *transaction).await?;
-                                    }
-                                }
-                                Ok(Some((idx, _))) => {
-                                    let mut race = unassigned_races.swap_remove(idx);
-                                    restream.update_race(&mut race, restream_match.id)?;
-                                    race.save(&mut transaction).await?;
-                                }
-                                Err(races) => {
-                                    if let Some(organizer_channel) = event.discord_organizer_channel {
-                                        let msg = MessageBuilder::default()
-                                            .push("found multiple races matching SpeedGaming match ")
-                                            .push_mono(restream_match.id.to_string())
-                                            //TODO describe match
-                                            .push(", please select one to assign it to:")
-                                            .build();
-                                        let mut options = Vec::with_capacity(races.size_hint().0);
-                                        for (_, race) in races {
-                                            let info_prefix = format!("{}{}{}",
-                                                race.phase.as_deref().unwrap_or(""),
-                                                if race.phase.is_none() || race.round.is_none() { "" } else { " " },
-                                                race.round.as_deref().unwrap_or(""),
-                                            );
-                                            let summary = match race.entrants {
-                                                Entrants::Open | Entrants::Count { .. } => if info_prefix.is_empty() { format!("Untitled Race") } else { info_prefix },
-                                                Entrants::Named(ref entrants) => format!("{info_prefix}{}{entrants}", if info_prefix.is_empty() { "" } else { ": " }),
-                                                Entrants::Two([ref team1, ref team2]) => format!(
-                                                    "{info_prefix}{}{} vs {}",
-                                                    if info_prefix.is_empty() { "" } else { ": " },
-                                                    team1.name(&mut transaction, &*discord_ctx.read().await).await?.unwrap_or(Cow::Borrowed("(unnamed)")),
-                                                    team2.name(&mut transaction, &*discord_ctx.read().await).await?.unwrap_or(Cow::Borrowed("(unnamed)")),
-                                                ),
-                                                Entrants::Three([ref team1, ref team2, ref team3]) => format!(
-                                                    "{info_prefix}{}{} vs {} vs {}",
-                                                    if info_prefix.is_empty() { "" } else { ": " },
-                                                    team1.name(&mut transaction, &*discord_ctx.read().await).await?.unwrap_or(Cow::Borrowed("(unnamed)")),
-                                                    team2.name(&mut transaction, &*discord_ctx.read().await).await?.unwrap_or(Cow::Borrowed("(unnamed)")),
-                                                    team3.name(&mut transaction, &*discord_ctx.read().await).await?.unwrap_or(Cow::Borrowed("(unnamed)")),
-                                                ),
-                                            };
-                                            options.push(CreateSelectMenuOption::new(if let Some(game) = race.game {
-                                                format!("{summary}, game {game}")
-                                            } else {
-                                                summary
-                                            }, race.id.to_string()));
-                                        }
-                                        let notification = organizer_channel.send_message(&*discord_ctx.read().await, CreateMessage::default()
-                                            .content(msg)
-                                            .select_menu(
-                                                CreateSelectMenu::new(format!("sgdisambig_{}", restream_match.id), CreateSelectMenuKind::String { options })
-                                                    .placeholder("Select Race")
-                                            )
-                                        ).await?;
-                                        sqlx::query!(
-                                            "INSERT INTO speedgaming_disambiguation_messages (speedgaming_id, message_id) VALUES ($1, $2)",
-                                            restream_match.id, PgSnowflake(notification.id) as _,
-                                        ).execute(&mut *transaction).await?;
-                                    }
+                        }
+                        Ok(Some((idx, _))) => {
+                            let mut race = unassigned_races.swap_remove(idx);
+                            restream.update_race(&mut race, restream_match.id)?;
+                            race.save(&mut transaction).await?;
+                        }
+                        Err(races) => {
+                            if let Some(organizer_channel) = event.discord_organizer_channel {
+                                let msg = MessageBuilder::default()
+                                    .push("found multiple races matching SpeedGaming match ")
+                                    .push_mono(restream_match.id.to_string())
+                                    //TODO describe match
+                                    .push(", please select one to assign it to:")
+                                    .build();
+                                let mut options = Vec::with_capacity(races.size_hint().0);
+                                for (_, race) in races {
+                                    let info_prefix = format!("{}{}{}",
+                                        race.phase.as_deref().unwrap_or(""),
+                                        if race.phase.is_none() || race.round.is_none() { "" } else { " " },
+                                        race.round.as_deref().unwrap_or(""),
+                                    );
+                                    let summary = match race.entrants {
+                                        Entrants::Open | Entrants::Count { .. } => if info_prefix.is_empty() { format!("Untitled Race") } else { info_prefix },
+                                        Entrants::Named(ref entrants) => format!("{info_prefix}{}{entrants}", if info_prefix.is_empty() { "" } else { ": " }),
+                                        Entrants::Two([ref team1, ref team2]) => format!(
+                                            "{info_prefix}{}{} vs {}",
+                                            if info_prefix.is_empty() { "" } else { ": " },
+                                            team1.name(&mut transaction, &*discord_ctx.read().await).await?.unwrap_or(Cow::Borrowed("(unnamed)")),
+                                            team2.name(&mut transaction, &*discord_ctx.read().await).await?.unwrap_or(Cow::Borrowed("(unnamed)")),
+                                        ),
+                                        Entrants::Three([ref team1, ref team2, ref team3]) => format!(
+                                            "{info_prefix}{}{} vs {} vs {}",
+                                            if info_prefix.is_empty() { "" } else { ": " },
+                                            team1.name(&mut transaction, &*discord_ctx.read().await).await?.unwrap_or(Cow::Borrowed("(unnamed)")),
+                                            team2.name(&mut transaction, &*discord_ctx.read().await).await?.unwrap_or(Cow::Borrowed("(unnamed)")),
+                                            team3.name(&mut transaction, &*discord_ctx.read().await).await?.unwrap_or(Cow::Borrowed("(unnamed)")),
+                                        ),
+                                    };
+                                    options.push(CreateSelectMenuOption::new(if let Some(game) = race.game {
+                                        format!("{summary}, game {game}")
+                                    } else {
+                                        summary
+                                    }, race.id.to_string()));
                                 }
+                                let notification = organizer_channel.send_message(&*discord_ctx.read().await, CreateMessage::default()
+                                    .content(msg)
+                                    .select_menu(
+                                        CreateSelectMenu::new(format!("sgdisambig_{}", restream_match.id), CreateSelectMenuKind::String { options })
+                                            .placeholder("Select Race")
+                                    )
+                                ).await?;
+                                sqlx::query!(
+                                    "INSERT INTO speedgaming_disambiguation_messages (speedgaming_id, message_id) VALUES ($1, $2)",
+                                    restream_match.id, PgSnowflake(notification.id) as _,
+                                ).execute(&mut *transaction).await?;
                             }
                         }
                     }
                 }
             }
         }
+    }
+    Ok(transaction)
+}
+
+/// The races of each event as of the last auto-import, along with a fingerprint of their database rows, so that they're only reloaded once something changed.
+type RacesCache = HashMap<(Series, String), (Option<String>, Vec<Race>)>;
+
+/// Sends notifications about an event's races that depend on the state of other races, like the next round becoming available or scheduling reminders.
+///
+/// Errors are logged rather than returned so they don't stop auto-imports for other events.
+async fn notify_event_races(db_pool: &PgPool, http_client: &reqwest::Client, discord_ctx: &RwFuture<DiscordCtx>, races_cache: &mut RacesCache, series: Series, event: &str) {
+    if let Err(e) = notify_event_races_inner(db_pool, http_client, discord_ctx, races_cache, series, event).await {
+        // make sure the notifications are retried next time
+        races_cache.remove(&(series, event.to_owned()));
+        eprintln!("failed to send race notifications for {series}/{event}: {e} ({e:?})");
+    }
+}
+
+async fn notify_event_races_inner(db_pool: &PgPool, http_client: &reqwest::Client, discord_ctx: &RwFuture<DiscordCtx>, races_cache: &mut RacesCache, series: Series, event: &str) -> Result<(), event::Error> {
+    let mut transaction = db_pool.begin().await?;
+    let Some(event) = event::Data::new(&mut transaction, series, event).await? else { return Ok(()) }; // event deleted since the list of events was queried
+    if !event.is_started(&mut transaction).await? || (event.discord_scheduling_channel.is_none() && event.scheduling_reminder_threshold.is_none()) { return Ok(()) }
+    let fingerprint = sqlx::query_scalar!(r#"SELECT md5(string_agg(races::text, ',' ORDER BY id)) AS "fingerprint" FROM races WHERE series = $1 AND event = $2"#, series as _, &event.event).fetch_one(&mut *transaction).await?;
+    let key = (series, event.event.clone().into_owned());
+    let races_changed = races_cache.get(&key).is_none_or(|(cached, _)| *cached != fingerprint);
+    if races_changed {
+        let races = Race::for_event(&mut transaction, http_client, &event).await?;
+        races_cache.insert(key.clone(), (fingerprint, races));
+    }
+    let (_, races) = &races_cache[&key];
+    if races_changed && event.discord_scheduling_channel.is_some() {
+        transaction = notify_next_round_races(transaction, &*discord_ctx.read().await, &event, races).await?;
+    }
+    // reminders depend on the current time, so they're checked even if nothing changed
+    if let Some(threshold) = event.scheduling_reminder_threshold {
+        transaction = send_scheduling_reminders(transaction, &*discord_ctx.read().await, &event, races, threshold).await?;
+    }
+    transaction.commit().await?;
+    Ok(())
+}
+
+async fn auto_import_races_inner(db_pool: PgPool, http_client: reqwest::Client, config: Config, mut shutdown: rocket::Shutdown, discord_ctx: RwFuture<DiscordCtx>) -> Result<(), event::Error> {
+    let mut races_cache = RacesCache::default();
+    let mut error_reports = HashMap::<(Series, String), Instant>::default();
+    loop {
+        let mut transaction = db_pool.begin().await?;
+        let events = sqlx::query!(r#"SELECT series AS "series: Series", event FROM events WHERE end_time IS NULL OR end_time > NOW()"#).fetch_all(&mut *transaction).await?;
         transaction.commit().await?;
+        for row in events {
+            // each event is imported in its own transaction so that an error in one event (e.g. a malformed start.gg bracket) doesn't stop imports for the others
+            let transaction = db_pool.begin().await?;
+            match auto_import_event(transaction, &http_client, &config, &discord_ctx, row.series, &row.event).await {
+                Ok(transaction) => transaction.commit().await?,
+                Err(e) if e.is_network_error() => return Err(e),
+                Err(e) => {
+                    eprintln!("failed to auto-import races for {}/{}: {e} ({e:?})", row.series, row.event);
+                    // imports are retried every minute, so only report the same event's errors occasionally
+                    let now = Instant::now();
+                    let key = (row.series, row.event.clone());
+                    if error_reports.get(&key).is_none_or(|last_reported| now.duration_since(*last_reported) >= AUTO_IMPORT_ERROR_REPORT_COOLDOWN) {
+                        error_reports.insert(key, now);
+                        wheel::night_report(&format!("{}/error", night_path()), Some(&format!("failed to auto-import races for {}/{}: {e} ({e:?})", row.series, row.event))).await?;
+                    }
+                }
+            }
+            notify_event_races(&db_pool, &http_client, &discord_ctx, &mut races_cache, row.series, &row.event).await;
+        }
         select! {
             () = &mut shutdown => break,
             () = sleep(Duration::from_secs(60)) => {}
@@ -2715,6 +2958,22 @@ pub(crate) async fn edit_race_form(mut transaction: Transaction<'_, Postgres>, d
                     }
                 }
             }
+            : form_field("allow_prerace_chat", &mut errors, html! {
+                label(for = "allow_prerace_chat") : "Pre-race chat:";
+                select(name = "allow_prerace_chat") {
+                    option(value = "", selected? = ctx.as_ref().and_then(|ctx| ctx.field_value("allow_prerace_chat")).map_or(race.allow_prerace_chat.is_none(), |value| value.is_empty())) : "Event default";
+                    option(value = "on", selected? = ctx.as_ref().and_then(|ctx| ctx.field_value("allow_prerace_chat")).map_or(race.allow_prerace_chat == Some(true), |value| value == "on")) : "Allowed";
+                    option(value = "off", selected? = ctx.as_ref().and_then(|ctx| ctx.field_value("allow_prerace_chat")).map_or(race.allow_prerace_chat == Some(false), |value| value == "off")) : "Not allowed";
+                }
+            });
+            : form_field("allow_midrace_chat", &mut errors, html! {
+                label(for = "allow_midrace_chat") : "Mid-race chat:";
+                select(name = "allow_midrace_chat") {
+                    option(value = "", selected? = ctx.as_ref().and_then(|ctx| ctx.field_value("allow_midrace_chat")).map_or(race.allow_midrace_chat.is_none(), |value| value.is_empty())) : "Event default";
+                    option(value = "on", selected? = ctx.as_ref().and_then(|ctx| ctx.field_value("allow_midrace_chat")).map_or(race.allow_midrace_chat == Some(true), |value| value == "on")) : "Allowed";
+                    option(value = "off", selected? = ctx.as_ref().and_then(|ctx| ctx.field_value("allow_midrace_chat")).map_or(race.allow_midrace_chat == Some(false), |value| value == "off")) : "Not allowed";
+                }
+            });
             table {
                 thead {
                     tr {
@@ -2730,6 +2989,11 @@ pub(crate) async fn edit_race_form(mut transaction: Transaction<'_, Postgres>, d
                             br;
                             small(style = "font-weight: normal;") : "racetime.gg profile URL, racetime.gg user ID, or Mido's House user ID. Enter “me” to assign yourself.";
                         }
+                        th {
+                            : "Required";
+                            br;
+                            small(style = "font-weight: normal;") : "Auto-start is unlocked once all required restreams are ready. If none are checked, all restreams are required.";
+                        }
                     }
                 }
                 tbody {
@@ -2755,6 +3019,14 @@ pub(crate) async fn edit_race_form(mut transaction: Transaction<'_, Postgres>, d
                                     race.restreamers.get(&language).map(|restreamer| restreamer.as_str()) //TODO display as racetime.gg profile URL
                                 });
                             });
+                            @let field_name = format!("required_restream_languages.{}", language.short_code());
+                            : form_table_cell(&field_name, &mut errors, html! {
+                                input(type = "checkbox", name = &field_name, checked? = if let Some(ref ctx) = ctx {
+                                    ctx.field_value(&*field_name) == Some("on")
+                                } else {
+                                    race.required_restream_languages.contains(&language)
+                                });
+                            });
                         }
                     }
                 }
@@ -2954,6 +3226,10 @@ pub(crate) struct EditRaceForm {
     video_urls: HashMap<Language, String>,
     #[field(default = HashMap::new())]
     restreamers: HashMap<Language, String>,
+    #[field(default = HashMap::new())]
+    required_restream_languages: HashMap<Language, bool>,
+    allow_prerace_chat: Option<bool>,
+    allow_midrace_chat: Option<bool>,
 }
 
 #[rocket::post("/event/<series>/<event>/races/<id>/edit?<redirect_to>", data = "<form>")]
@@ -3226,9 +3502,15 @@ pub(crate) async fn edit_race_post(discord_ctx: &State<RwFuture<DiscordCtx>>, po
                     restreamer_de = $10,
                     video_url_pt = $11,
                     restreamer_pt = $12,
-                    last_edited_by = $13,
+                    restream_required = $13,
+                    restream_required_fr = $14,
+                    restream_required_de = $15,
+                    restream_required_pt = $16,
+                    allow_prerace_chat = $17,
+                    allow_midrace_chat = $18,
+                    last_edited_by = $19,
                     last_edited_at = NOW()
-                WHERE id = $14",
+                WHERE id = $20",
                 (!value.room.is_empty()).then(|| &value.room),
                 (!value.async_room1.is_empty()).then(|| &value.async_room1),
                 (!value.async_room2.is_empty()).then(|| &value.async_room2),
@@ -3241,6 +3523,12 @@ pub(crate) async fn edit_race_post(discord_ctx: &State<RwFuture<DiscordCtx>>, po
                 restreamers.get(&German),
                 value.video_urls.get(&Portuguese).filter(|video_url| !video_url.is_empty()),
                 restreamers.get(&Portuguese),
+                value.required_restream_languages.get(&English).copied().unwrap_or_default(),
+                value.required_restream_languages.get(&French).copied().unwrap_or_default(),
+                value.required_restream_languages.get(&German).copied().unwrap_or_default(),
+                value.required_restream_languages.get(&Portuguese).copied().unwrap_or_default(),
+                value.allow_prerace_chat,
+                value.allow_midrace_chat,
                 me.id as _,
                 id as _,
             ).execute(&mut *transaction).await?;
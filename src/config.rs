@@ -1,4 +1,5 @@
 use {
+    std::num::NonZeroUsize,
     anyhow::Result,
     crate::prelude::*,
 };
@@ -22,6 +23,20 @@ pub(crate) struct Config {
     pub(crate) racetime_oauth_production: ConfigRaceTime,
     #[serde(rename = "racetimeOAuthDev")]
     pub(crate) racetime_oauth_dev: ConfigRaceTime,
+    #[serde(default)]
+    pub(crate) racetime_reconnect: ReconnectBehavior,
+    /// The maximum number of seeds rolled locally at the same time, across all rooms. Must be nonzero since local rolls would otherwise wait forever.
+    #[serde(default = "default_local_seed_rollers")]
+    pub(crate) local_seed_rollers: NonZeroUsize,
+    /// How many plandos the random settings script generates when rolling a random settings seed on ootrandomizer.com before giving up.
+    #[serde(default = "default_rsl_web_plando_attempts")]
+    pub(crate) rsl_web_plando_attempts: u8,
+    /// When handling an official race room whose status no longer says whether it's an open or invitational race, fair play agreement is assumed to be active if the room has fewer than this many entrants.
+    #[serde(default = "default_fpa_entrant_threshold")]
+    pub(crate) fpa_entrant_threshold: usize,
+    /// Whether Standard Ruleset race rooms opened by users rather than the bot are handled, with only a limited set of commands available.
+    #[serde(default)]
+    pub(crate) handle_user_opened_standard_rooms: bool,
     pub(crate) startgg_production: String,
     pub(crate) startgg_dev: String,
     #[serde(rename = "startggOAuth")]
@@ -45,6 +60,10 @@ impl Config {
     }
 }
 
+fn default_local_seed_rollers() -> NonZeroUsize { NonZeroUsize::MIN }
+fn default_rsl_web_plando_attempts() -> u8 { 5 }
+fn default_fpa_entrant_threshold() -> usize { 10 }
+
 #[derive(Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ConfigRaceTime {
@@ -57,6 +76,17 @@ impl TypeMapKey for ConfigRaceTime {
     type Value = Self;
 }
 
+/// What the racetime.gg bot should do when it has to reconnect to a room it was already handling.
+#[derive(Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum ReconnectBehavior {
+    /// Restore the handler's state from before the connection dropped and reconcile it with the current race data.
+    #[default]
+    Resync,
+    /// Handle the room as if the bot had just restarted.
+    Restart,
+}
+
 #[derive(Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ConfigDiscord {
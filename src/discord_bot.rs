@@ -1197,10 +1197,13 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, db_poo
                                             scheduling_thread: race.scheduling_thread,
                                             video_urls: race.video_urls,
                                             restreamers: race.restreamers,
+                                            required_restream_languages: race.required_restream_languages,
                                             last_edited_by: race.last_edited_by,
                                             last_edited_at: race.last_edited_at,
                                             ignored: race.ignored,
                                             schedule_locked: race.schedule_locked,
+                                            allow_prerace_chat: race.allow_prerace_chat,
+                                            allow_midrace_chat: race.allow_midrace_chat,
                                             notified: race.notified && !reset_schedule,
                                         };
                                         race.save(&mut transaction).await?;
@@ -2049,6 +2052,50 @@ pub(crate) enum Error {
     UnregisteredDiscordGuild(GuildId),
 }
 
+/// Lets the entrants of a race know that the previous round has finished, so they can schedule their race earlier than planned.
+pub(crate) async fn notify_next_round<'a>(ctx: &DiscordCtx, mut transaction: Transaction<'a, Postgres>, event: &event::Data<'_>, race: &Race) -> Result<Transaction<'a, Postgres>, Error> {
+    let Some(scheduling_thread) = race.scheduling_thread else { return Ok(transaction) };
+    let mut content = MessageBuilder::default();
+    for team in race.teams() {
+        content.mention_team(&mut transaction, event.discord_guild, team).await?;
+        content.push(' ');
+    }
+    if let French = event.language {
+        content.push("Toutes les races du tour précédent sont terminées, vous pouvez donc dès maintenant schedule votre race.");
+    } else {
+        content.push("All races of the previous round have finished, so you can now schedule your race.");
+    }
+    scheduling_thread.say(ctx, content.build()).await?;
+    Ok(transaction)
+}
+
+/// Returns whether a reminder was sent.
+pub(crate) async fn remind_scheduling<'a>(ctx: &DiscordCtx, mut transaction: Transaction<'a, Postgres>, event: &event::Data<'_>, race: &Race) -> Result<(Transaction<'a, Postgres>, bool), Error> {
+    let Some(scheduling_thread) = race.scheduling_thread else { return Ok((transaction, false)) };
+    if event.scheduling_reminder_to_organizers {
+        let Some(organizer_channel) = event.discord_organizer_channel else { return Ok((transaction, false)) };
+        organizer_channel.say(ctx, MessageBuilder::default()
+            .push("the match in ")
+            .mention(&scheduling_thread)
+            .push(" still hasn't been fully scheduled")
+            .build()
+        ).await?;
+    } else {
+        let mut content = MessageBuilder::default();
+        for team in race.teams() {
+            content.mention_team(&mut transaction, event.discord_guild, team).await?;
+            content.push(' ');
+        }
+        if let French = event.language {
+            content.push("Rappel : votre race n'a pas encore été entièrement planifiée. Merci de vous mettre d'accord sur un horaire dès que possible.");
+        } else {
+            content.push("Reminder: your race hasn't been fully scheduled yet. Please agree on a time as soon as possible.");
+        }
+        scheduling_thread.say(ctx, content.build()).await?;
+    }
+    Ok((transaction, true))
+}
+
 pub(crate) async fn create_scheduling_thread<'a>(ctx: &DiscordCtx, mut transaction: Transaction<'a, Postgres>, race: &mut Race, game_count: i16) -> Result<Transaction<'a, Postgres>, Error> {
     let event = race.event(&mut transaction).await?;
     let (Some(guild_id), Some(scheduling_channel)) = (event.discord_guild, event.discord_scheduling_channel) else { return Ok(transaction) };
@@ -15,7 +15,8 @@ use {
 
 pub(crate) type Picks = HashMap<Cow<'static, str>, Cow<'static, str>>;
 
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub(crate) enum Team {
     HighSeed,
     LowSeed,
@@ -61,6 +62,22 @@ impl Kind {
                 => French,
         }
     }
+
+    /// Whether race room messages for this draft kind are posted in both English and French, see [`crate::racetime_bot::Goal::is_bilingual`].
+    pub(crate) fn is_bilingual(&self) -> bool {
+        matches!(self, Self::TournoiFrancoS4)
+    }
+
+    /// Selects the text to post in a race room, combining both translations for bilingual draft kinds.
+    fn racetime_message(&self, english: String, french: String) -> String {
+        if self.is_bilingual() {
+            format!("{english} / {french}")
+        } else if let French = self.language() {
+            french
+        } else {
+            english
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -148,6 +165,13 @@ pub(crate) enum StepKind {
     Done(serde_json::Map<String, Json>), //TODO use ootr_utils::Settings instead?
 }
 
+#[derive(Clone, Copy)]
+pub(crate) enum TurnKind {
+    Ban,
+    Pick,
+    BooleanChoice,
+}
+
 pub(crate) struct Step {
     pub(crate) kind: StepKind,
     pub(crate) message: String,
@@ -196,6 +220,9 @@ pub(crate) struct Draft {
     pub(crate) went_first: Option<bool>,
     #[serde(default)]
     pub(crate) skipped_bans: u8,
+    /// The team which banned or picked each setting in `settings`. Not recorded for drafts from before this was tracked.
+    #[serde(default)]
+    pub(crate) chosen_by: HashMap<Cow<'static, str>, Team>,
     #[serde(flatten)]
     pub(crate) settings: Picks,
 }
@@ -257,6 +284,7 @@ impl Draft {
             high_seed: loser,
             went_first: None,
             skipped_bans: 0,
+            chosen_by: HashMap::default(),
             settings: match kind {
                 Kind::S7 => HashMap::default(),
                 // accessibility accommodation for The Aussie Boiiz in mw/4 to default to CSMC
@@ -287,6 +315,24 @@ impl Draft {
         }
     }
 
+    /// If picking this value would effectively undo a previous ban due to interactions between settings, returns the name of the banned setting.
+    fn overridden_ban(&self, kind: Kind, setting: &str, value: &str) -> Option<&'static str> {
+        match kind {
+            // the settings of these drafts don't interact with each other
+            Kind::S7 | Kind::MultiworldS3 | Kind::MultiworldS4 => None,
+            Kind::TournoiFrancoS3 | Kind::TournoiFrancoS4 => {
+                let overridden = fr::overridden_setting(setting, value)?;
+                // checking the recorded ban rather than the value since picking the default value is also allowed in these drafts
+                self.settings.contains_key(&*fr::ban_key(overridden)).then_some(overridden)
+            }
+        }
+    }
+
+    /// Whether the Tournoi Francophone mixed dungeons choice is pending, which is made by the team that chose the previous setting.
+    fn select_mixed_dungeons(&self) -> bool {
+        !self.settings.contains_key("mixed-dungeons") && fr::can_mix_dungeons(&self.settings)
+    }
+
     pub(crate) async fn next_step(&self, kind: Kind, game: Option<i16>, msg_ctx: &mut MessageContext<'_>) -> sqlx::Result<Step> {
         Ok(match kind {
             Kind::S7 => {
@@ -789,7 +835,7 @@ impl Draft {
                 };
                 if let Some(went_first) = self.went_first {
                     let mut pick_count = self.pick_count(kind);
-                    let select_mixed_dungeons = !self.settings.contains_key("mixed-dungeons") && self.settings.get("dungeon-er").map(|dungeon_er| &**dungeon_er).unwrap_or("off") == "on" && self.settings.get("mixed-er").map(|mixed_er| &**mixed_er).unwrap_or("off") == "on";
+                    let select_mixed_dungeons = self.select_mixed_dungeons();
                     if select_mixed_dungeons {
                         // chosen by the same team that chose the previous setting
                         pick_count -= 1;
@@ -810,7 +856,10 @@ impl Draft {
                                 } else {
                                     format!("Settings draft completed. You will be playing with {}.", fr::display_draft_picks(kind.language(), all_settings, &self.settings))
                                 },
-                                MessageContext::RaceTime { .. } => fr::display_draft_picks(kind.language(), all_settings, &self.settings),
+                                MessageContext::RaceTime { .. } => kind.racetime_message(
+                                    fr::display_draft_picks(English, all_settings, &self.settings),
+                                    fr::display_draft_picks(French, all_settings, &self.settings),
+                                ),
                             },
                         }),
                         (Kind::MultiworldS3 | Kind::MultiworldS4 | Kind::S7, _, _) => unreachable!(),
@@ -833,17 +882,16 @@ impl Draft {
                                         .push('.')
                                         .build()
                                 }
-                                MessageContext::RaceTime { high_seed_name, low_seed_name, .. } => if let French = kind.language() {
+                                MessageContext::RaceTime { high_seed_name, low_seed_name, .. } => kind.racetime_message(
                                     format!(
-                                        "{}, est-ce que les donjons seront mixés avec les intérieurs et les grottos ? Répondez en utilisant !yes ou !no",
+                                        "{}, should dungeon entrances be mixed with interiors and grottos? Use !yes or !no",
                                         team.choose(high_seed_name, low_seed_name),
-                                    )
-                                } else {
+                                    ),
                                     format!(
-                                        "{}, should dungeon entrances be mixed with interiors and grottos? Use !yes or !no",
+                                        "{}, est-ce que les donjons seront mixés avec les intérieurs et les grottos ? Répondez en utilisant !yes ou !no",
                                         team.choose(high_seed_name, low_seed_name),
-                                    )
-                                },
+                                    ),
+                                ),
                             },
                         }
                     } else {
@@ -898,19 +946,18 @@ impl Draft {
                                                 .push('.')
                                                 .build()
                                         }
-                                        MessageContext::RaceTime { high_seed_name, low_seed_name, .. } => if let French = kind.language() {
-                                            format!(
-                                                "{}, veuillez ban un setting en utilisant “!ban <setting>”.{}",
-                                                team.choose(high_seed_name, low_seed_name),
-                                                if prev_bans == 0 { " Utilisez “!settings” pour la liste des settings." } else { "" },
-                                            )
-                                        } else {
+                                        MessageContext::RaceTime { high_seed_name, low_seed_name, .. } => kind.racetime_message(
                                             format!(
                                                 "{}, lock a setting to its default using “!ban <setting>”.{}",
                                                 team.choose(high_seed_name, low_seed_name),
                                                 if prev_bans == 0 { " Use “!settings” for a list of available settings." } else { "" },
-                                            )
-                                        },
+                                            ),
+                                            format!(
+                                                "{}, veuillez ban un setting en utilisant “!ban <setting>”.{}",
+                                                team.choose(high_seed_name, low_seed_name),
+                                                if prev_bans == 0 { " Utilisez “!settings” pour la liste des settings." } else { "" },
+                                            ),
+                                        ),
                                     },
                                 }
                             }
@@ -1033,36 +1080,30 @@ impl Draft {
                                             }
                                         }
                                         MessageContext::RaceTime { high_seed_name, low_seed_name, .. } => match (kind, n) {
-                                            (Kind::TournoiFrancoS4, 7) | (_, 9) if skippable => if let French = kind.language() {
-                                                format!("{}, choisissez le dernier setting. Vous pouvez également utiliser “!skip” si vous voulez laisser les settings comme ils sont.", team.choose(high_seed_name, low_seed_name))
-                                            } else {
-                                                format!("{},  pick the final setting. You can also use “!skip” if you want to leave the settings as they are.", team.choose(high_seed_name, low_seed_name))
-                                            },
-                                            (Kind::TournoiFrancoS4, 7) | (_, 9) => if let French = kind.language() {
-                                                format!("{}, choisissez votre dernier setting.", team.choose(high_seed_name, low_seed_name))
-                                            } else {
-                                                format!("{}, pick the final setting.", team.choose(high_seed_name, low_seed_name))
-                                            },
-                                            (_, 2) => if let French = kind.language() {
-                                                format!("{}, choisissez un setting avec “!draft <setting> <configuration>”. <configuration> signifie la valeur du setting. Par exemple pour tokensanity, la configuration peut être {{all, dungeon, overworld}}.", team.choose(high_seed_name, low_seed_name))
-                                            } else {
-                                                format!("{}, pick a setting using “!draft <setting> <value>”", team.choose(high_seed_name, low_seed_name))
-                                            },
-                                            (_, 3 | 5) => if let French = kind.language() {
-                                                format!("{}, choisissez deux settings. Quel est votre premier ?", team.choose(high_seed_name, low_seed_name))
-                                            } else {
-                                                format!("{}, pick two settings.", team.choose(high_seed_name, low_seed_name))
-                                            },
-                                            (_, 4 | 6) => if let French = kind.language() {
-                                                format!("Et votre second ?")
-                                            } else {
-                                                format!("And your second pick?")
-                                            },
-                                            (_, 7 | 8) => if let French = kind.language() {
-                                                format!("{}, choisissez un setting.", team.choose(high_seed_name, low_seed_name))
-                                            } else {
-                                                format!("{}, pick a setting.", team.choose(high_seed_name, low_seed_name))
-                                            },
+                                            (Kind::TournoiFrancoS4, 7) | (_, 9) if skippable => kind.racetime_message(
+                                                format!("{},  pick the final setting. You can also use “!skip” if you want to leave the settings as they are.", team.choose(high_seed_name, low_seed_name)),
+                                                format!("{}, choisissez le dernier setting. Vous pouvez également utiliser “!skip” si vous voulez laisser les settings comme ils sont.", team.choose(high_seed_name, low_seed_name)),
+                                            ),
+                                            (Kind::TournoiFrancoS4, 7) | (_, 9) => kind.racetime_message(
+                                                format!("{}, pick the final setting.", team.choose(high_seed_name, low_seed_name)),
+                                                format!("{}, choisissez votre dernier setting.", team.choose(high_seed_name, low_seed_name)),
+                                            ),
+                                            (_, 2) => kind.racetime_message(
+                                                format!("{}, pick a setting using “!draft <setting> <value>”", team.choose(high_seed_name, low_seed_name)),
+                                                format!("{}, choisissez un setting avec “!draft <setting> <configuration>”. <configuration> signifie la valeur du setting. Par exemple pour tokensanity, la configuration peut être {{all, dungeon, overworld}}.", team.choose(high_seed_name, low_seed_name)),
+                                            ),
+                                            (_, 3 | 5) => kind.racetime_message(
+                                                format!("{}, pick two settings.", team.choose(high_seed_name, low_seed_name)),
+                                                format!("{}, choisissez deux settings. Quel est votre premier ?", team.choose(high_seed_name, low_seed_name)),
+                                            ),
+                                            (_, 4 | 6) => kind.racetime_message(
+                                                format!("And your second pick?"),
+                                                format!("Et votre second ?"),
+                                            ),
+                                            (_, 7 | 8) => kind.racetime_message(
+                                                format!("{}, pick a setting.", team.choose(high_seed_name, low_seed_name)),
+                                                format!("{}, choisissez un setting.", team.choose(high_seed_name, low_seed_name)),
+                                            ),
                                             (_, 0..=1 | 10..) => unreachable!(),
                                         },
                                     },
@@ -1105,11 +1146,10 @@ impl Draft {
                                 }
                                 builder.build()
                             },
-                            MessageContext::RaceTime { high_seed_name, .. } => if let French = kind.language() {
-                                format!("{high_seed_name}, vous avez été sélectionné pour décider qui commencera le draft en premier. Si vous voulez commencer, veuillez entrer “!first”. Autrement, entrez “!second”.")
-                            } else {
-                                format!("{high_seed_name}, you have won the coin flip. Choose whether you want to go !first or !second in the settings draft.")
-                            },
+                            MessageContext::RaceTime { high_seed_name, .. } => kind.racetime_message(
+                                format!("{high_seed_name}, you have won the coin flip. Choose whether you want to go !first or !second in the settings draft."),
+                                format!("{high_seed_name}, vous avez été sélectionné pour décider qui commencera le draft en premier. Si vous voulez commencer, veuillez entrer “!first”. Autrement, entrez “!second”."),
+                            ),
                         },
                     }
                 }
@@ -1125,6 +1165,34 @@ impl Draft {
         })
     }
 
+    /// The turns remaining in the draft, in order, and which team takes each of them.
+    ///
+    /// Empty if the high seed hasn't chosen whether to go first yet.
+    pub(crate) async fn remaining_turns(&self, kind: Kind, game: Option<i16>) -> sqlx::Result<Vec<(TurnKind, Team)>> {
+        if self.went_first.is_none() { return Ok(Vec::default()) }
+        // walk through the remaining steps on a copy of the draft, advancing it without recording any actual picks
+        let mut draft = self.clone();
+        let mut turns = Vec::default();
+        loop {
+            match draft.next_step(kind, game, &mut MessageContext::None).await?.kind {
+                StepKind::GoFirst | StepKind::Done(_) => break,
+                StepKind::Ban { team, .. } => {
+                    turns.push((TurnKind::Ban, team));
+                    draft.skipped_bans += 1;
+                }
+                StepKind::Pick { team, .. } => {
+                    turns.push((TurnKind::Pick, team));
+                    draft.skipped_bans += 1;
+                }
+                StepKind::BooleanChoice { team } => {
+                    turns.push((TurnKind::BooleanChoice, team));
+                    draft.settings.insert(Cow::Borrowed("mixed-dungeons"), Cow::Borrowed("separate"));
+                }
+            }
+        }
+        Ok(turns)
+    }
+
     /// Assumes that the caller has checked that the team is part of the race in the first place.
     pub(crate) async fn is_active_team(&self, kind: Kind, game: Option<i16>, team: Id<Teams>) -> sqlx::Result<bool> {
         Ok(match self.active_team(kind, game).await? {
@@ -1135,6 +1203,20 @@ impl Draft {
     }
 
     pub(crate) async fn apply(&mut self, kind: Kind, game: Option<i16>, msg_ctx: &mut MessageContext<'_>, action: Action) -> sqlx::Result<Result<String, String>> {
+        let active_team = self.active_team(kind, game).await?;
+        let previous_settings = self.settings.keys().cloned().collect::<HashSet<_>>();
+        let result = self.apply_inner(kind, game, msg_ctx, action).await?;
+        if let Some(team) = active_team {
+            for setting in self.settings.keys() {
+                if !previous_settings.contains(setting) {
+                    self.chosen_by.insert(setting.clone(), team);
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    async fn apply_inner(&mut self, kind: Kind, game: Option<i16>, msg_ctx: &mut MessageContext<'_>, action: Action) -> sqlx::Result<Result<String, String>> {
         Ok(match kind {
             Kind::S7 => {
                 let resolved_action = match action {
@@ -1972,11 +2054,10 @@ impl Draft {
                             } else {
                                 format!("Sorry, first pick has already been chosen.")
                             },
-                            MessageContext::RaceTime { reply_to, .. } => if let French = kind.language() {
-                                format!("Désolé {reply_to}, le premier pick a déjà été sélectionné.")
-                            } else {
-                                format!("Sorry {reply_to}, first pick has already been chosen.")
-                            },
+                            MessageContext::RaceTime { reply_to, .. } => kind.racetime_message(
+                                format!("Sorry {reply_to}, first pick has already been chosen."),
+                                format!("Désolé {reply_to}, le premier pick a déjà été sélectionné."),
+                            ),
                         }),
                         StepKind::BooleanChoice { .. } => Err(match msg_ctx {
                             MessageContext::None => String::default(),
@@ -1997,11 +2078,10 @@ impl Draft {
                                     .push('.')
                                     .build()
                             },
-                            MessageContext::RaceTime { reply_to, .. } => if let French = kind.language() {
-                                format!("Désolé {reply_to}, avant que le draft ne puisse continuer, vous devez d'abord choisir si les donjons seront mixés ou non avec le reste. Utilisez !yes ou !no")
-                            } else {
-                                format!("Sorry {reply_to}, before the settings draft can continue, you first have to choose whether dungeons entrances should be mixed. Use !yes or !no")
-                            },
+                            MessageContext::RaceTime { reply_to, .. } => kind.racetime_message(
+                                format!("Sorry {reply_to}, before the settings draft can continue, you first have to choose whether dungeons entrances should be mixed. Use !yes or !no"),
+                                format!("Désolé {reply_to}, avant que le draft ne puisse continuer, vous devez d'abord choisir si les donjons seront mixés ou non avec le reste. Utilisez !yes ou !no"),
+                            ),
                         }),
                         StepKind::Done(_) => Err(match msg_ctx {
                             MessageContext::None => String::default(),
@@ -2010,11 +2090,10 @@ impl Draft {
                             } else {
                                 format!("Sorry, this settings draft is already completed.")
                             },
-                            MessageContext::RaceTime { reply_to, .. } => if let French = kind.language() {
-                                format!("Désolé {reply_to}, ce draft est terminé.")
-                            } else {
-                                format!("Sorry {reply_to}, this settings draft is already completed.")
-                            },
+                            MessageContext::RaceTime { reply_to, .. } => kind.racetime_message(
+                                format!("Sorry {reply_to}, this settings draft is already completed."),
+                                format!("Désolé {reply_to}, ce draft est terminé."),
+                            ),
                         }),
                     },
                     Action::Ban { .. } => unreachable!("normalized to Action::Pick above"),
@@ -2033,6 +2112,7 @@ impl Draft {
                             if value == setting.default {
                                 let hard_settings_ok = self.settings.get("hard_settings_ok").map(|hard_settings_ok| &**hard_settings_ok).unwrap_or("no") == "ok";
                                 self.settings.insert(Cow::Borrowed(setting.name), Cow::Borrowed(setting.default));
+                                self.settings.insert(fr::ban_key(setting.name), Cow::Borrowed("yes"));
                                 Ok(match msg_ctx {
                                     MessageContext::None | MessageContext::RaceTime { .. } => String::default(),
                                     MessageContext::Discord { transaction, guild_id, team, .. } => if let French = kind.language() {
@@ -2099,6 +2179,21 @@ impl Draft {
                             if let Some(option) = setting.options.iter().find(|option| option.name == value) {
                                 let hard_settings_ok = self.settings.get("hard_settings_ok").map(|hard_settings_ok| &**hard_settings_ok).unwrap_or("no") == "ok";
                                 let is_default = value == all_settings.iter().find(|&&fr::Setting { name, .. }| setting.name == name).unwrap().default;
+                                if let Some(banned) = self.overridden_ban(kind, setting.name, option.name) {
+                                    let banned = all_settings.iter().find(|&&fr::Setting { name, .. }| name == banned).unwrap().display;
+                                    return Ok(Err(match msg_ctx {
+                                        MessageContext::None => String::default(),
+                                        MessageContext::Discord { .. } => if let French = kind.language() {
+                                            format!("Désolé, {} ne peut pas être choisi car {banned} a été banni.", option.display)
+                                        } else {
+                                            format!("Sorry, {} can't be picked since {banned} has been banned.", option.display)
+                                        },
+                                        MessageContext::RaceTime { reply_to, .. } => kind.racetime_message(
+                                            format!("Sorry {reply_to}, {} can't be picked since {banned} has been banned.", option.display),
+                                            format!("Désolé {reply_to}, {} ne peut pas être choisi car {banned} a été banni.", option.display),
+                                        ),
+                                    }))
+                                }
                                 if !is_default {
                                     self.settings.insert(Cow::Borrowed(self.active_team(kind, game).await?.unwrap().choose("high_seed_has_picked", "low_seed_has_picked")), Cow::Borrowed("yes"));
                                 }
@@ -2192,11 +2287,10 @@ impl Draft {
                                     .push('.')
                                     .build()
                             },
-                            MessageContext::RaceTime { reply_to, .. } => if let French = kind.language() {
-                                format!("Désolé {reply_to}, avant que le draft ne puisse continuer, vous devez d'abord choisir si les donjons seront mixés ou non avec le reste. Utilisez !yes ou !no")
-                            } else {
-                                format!("Sorry {reply_to}, before the settings draft can continue, you first have to choose whether dungeons entrances should be mixed. Use !yes or !no")
-                            },
+                            MessageContext::RaceTime { reply_to, .. } => kind.racetime_message(
+                                format!("Sorry {reply_to}, before the settings draft can continue, you first have to choose whether dungeons entrances should be mixed. Use !yes or !no"),
+                                format!("Désolé {reply_to}, avant que le draft ne puisse continuer, vous devez d'abord choisir si les donjons seront mixés ou non avec le reste. Utilisez !yes ou !no"),
+                            ),
                         }),
                         StepKind::Done(_) => Err(match msg_ctx {
                             MessageContext::None => String::default(),
@@ -2205,11 +2299,10 @@ impl Draft {
                             } else {
                                 format!("Sorry, this settings draft is already completed.")
                             },
-                            MessageContext::RaceTime { reply_to, .. } => if let French = kind.language() {
-                                format!("Désolé {reply_to}, ce draft est terminé.")
-                            } else {
-                                format!("Sorry {reply_to}, this settings draft is already completed.")
-                            },
+                            MessageContext::RaceTime { reply_to, .. } => kind.racetime_message(
+                                format!("Sorry {reply_to}, this settings draft is already completed."),
+                                format!("Désolé {reply_to}, ce draft est terminé."),
+                            ),
                         }),
                     },
                     Action::Skip => match self.next_step(kind, game, &mut MessageContext::None).await?.kind {
@@ -2265,11 +2358,10 @@ impl Draft {
                                     .push('.')
                                     .build()
                             },
-                            MessageContext::RaceTime { reply_to, .. } => if let French = kind.language() {
-                                format!("Désolé {reply_to}, avant que le draft ne puisse continuer, vous devez d'abord choisir si les donjons seront mixés ou non avec le reste. Utilisez !yes ou !no")
-                            } else {
-                                format!("Sorry {reply_to}, before the settings draft can continue, you first have to choose whether dungeons entrances should be mixed. Use !yes or !no")
-                            },
+                            MessageContext::RaceTime { reply_to, .. } => kind.racetime_message(
+                                format!("Sorry {reply_to}, before the settings draft can continue, you first have to choose whether dungeons entrances should be mixed. Use !yes or !no"),
+                                format!("Désolé {reply_to}, avant que le draft ne puisse continuer, vous devez d'abord choisir si les donjons seront mixés ou non avec le reste. Utilisez !yes ou !no"),
+                            ),
                         }),
                         StepKind::Done(_) => Err(match msg_ctx {
                             MessageContext::None => String::default(),
@@ -2278,11 +2370,10 @@ impl Draft {
                             } else {
                                 format!("Sorry, this settings draft is already completed.")
                             },
-                            MessageContext::RaceTime { reply_to, .. } => if let French = kind.language() {
-                                format!("Désolé {reply_to}, ce draft est terminé.")
-                            } else {
-                                format!("Sorry {reply_to}, this settings draft is already completed.")
-                            },
+                            MessageContext::RaceTime { reply_to, .. } => kind.racetime_message(
+                                format!("Sorry {reply_to}, this settings draft is already completed."),
+                                format!("Désolé {reply_to}, ce draft est terminé."),
+                            ),
                         }),
                     },
                     Action::BooleanChoice(value) => match self.next_step(kind, game, &mut MessageContext::None).await?.kind {
@@ -2318,11 +2409,10 @@ impl Draft {
                             } else {
                                 format!("Sorry, this settings draft is already completed.")
                             },
-                            MessageContext::RaceTime { reply_to, .. } => if let French = kind.language() {
-                                format!("Désolé {reply_to}, ce draft est terminé.")
-                            } else {
-                                format!("Sorry {reply_to}, this settings draft is already completed.")
-                            },
+                            MessageContext::RaceTime { reply_to, .. } => kind.racetime_message(
+                                format!("Sorry {reply_to}, this settings draft is already completed."),
+                                format!("Désolé {reply_to}, ce draft est terminé."),
+                            ),
                         }),
                         _ => Err(match msg_ctx {
                             MessageContext::None => String::default(),
@@ -2331,11 +2421,10 @@ impl Draft {
                             } else {
                                 format!("Sorry, the current step is not a yes/no question.")
                             },
-                            MessageContext::RaceTime { reply_to, .. } => if let French = kind.language() {
-                                format!("Désolé {reply_to}, vous n'avez pas à répondre oui ou non.")
-                            } else {
-                                format!("Sorry {reply_to}, the current step is not a yes/no question.")
-                            },
+                            MessageContext::RaceTime { reply_to, .. } => kind.racetime_message(
+                                format!("Sorry {reply_to}, the current step is not a yes/no question."),
+                                format!("Désolé {reply_to}, vous n'avez pas à répondre oui ou non."),
+                            ),
                         }),
                     },
                 }
@@ -2343,7 +2432,52 @@ impl Draft {
         })
     }
 
-    pub(crate) async fn complete_randomly(mut self, kind: Kind) -> sqlx::Result<Picks> {
+    /// Describes the settings that have been banned or picked so far and by which team, in the order they're listed for this kind of draft.
+    pub(crate) fn picks_summary(&self, kind: Kind, high_seed_name: &str, low_seed_name: &str) -> Vec<String> {
+        let chosen_by = |name: &str| self.chosen_by.get(name).map_or_else(String::default, |team| format!(" ({})", team.choose(high_seed_name, low_seed_name)));
+        match kind {
+            Kind::S7 => s::S7_SETTINGS.into_iter().filter_map(|s::Setting { name, display, default_display, other, .. }| {
+                let value = self.settings.get(name)?;
+                Some(format!("{display}: {}{}", if value == "default" {
+                    default_display
+                } else {
+                    other.iter().find(|(other, _, _)| value == other).map_or(&**value, |(_, display, _)| *display)
+                }, chosen_by(name)))
+            }).collect(),
+            Kind::MultiworldS3 | Kind::MultiworldS4 => {
+                let settings: &[mw::Setting] = if let Kind::MultiworldS3 = kind { &mw::S3_SETTINGS } else { &mw::S4_SETTINGS };
+                settings.iter().filter_map(|mw::Setting { name, display, default, default_display, other, .. }| {
+                    let value = self.settings.get(*name)?;
+                    Some(format!("{display}: {}{}", if value == default {
+                        *default_display
+                    } else {
+                        other.iter().find(|(other, _)| value == other).map_or(&**value, |(_, display)| *display)
+                    }, chosen_by(name)))
+                }).collect()
+            }
+            Kind::TournoiFrancoS3 | Kind::TournoiFrancoS4 => {
+                let settings: &[fr::Setting] = if let Kind::TournoiFrancoS3 = kind { &fr::S3_SETTINGS } else { &fr::S4_SETTINGS };
+                settings.iter().filter_map(|fr::Setting { name, display, default, default_display, other, .. }| {
+                    let value = self.settings.get(*name)?;
+                    Some(format!("{display}: {}{}", if value == default {
+                        *default_display
+                    } else {
+                        other.iter().find(|(other, _, _)| value == other).map_or(&**value, |(_, _, display)| *display)
+                    }, chosen_by(name)))
+                }).collect()
+            }
+        }
+    }
+
+    pub(crate) async fn complete_randomly(self, kind: Kind) -> sqlx::Result<Picks> {
+        Ok(self.complete_randomly_verbose(kind).await?.0)
+    }
+
+    /// Like [`Draft::complete_randomly`], but also returns a description of each ban or pick, including the number of options considered and how likely the chosen one was.
+    ///
+    /// All available settings and values are equally likely.
+    pub(crate) async fn complete_randomly_verbose(mut self, kind: Kind) -> sqlx::Result<(Picks, Vec<String>)> {
+        let mut log = Vec::default();
         Ok(loop {
             let action = match self.next_step(kind, None, &mut MessageContext::None).await?.kind {
                 StepKind::GoFirst => Action::GoFirst(thread_rng().gen()),
@@ -2352,9 +2486,13 @@ impl Draft {
                     if skippable {
                         settings.push(None);
                     }
+                    let num_options = settings.len();
+                    let chance = 100.0 / num_options as f64;
                     if let Some(setting) = settings.into_iter().choose(&mut thread_rng()).expect("no available settings") {
+                        log.push(format!("Ban: {} (1 of {num_options} options, {chance:.1}% each)", setting.display));
                         Action::Ban { setting: setting.name.to_owned() }
                     } else {
+                        log.push(format!("Ban: skipped (1 of {num_options} options, {chance:.1}% each)"));
                         Action::Skip
                     }
                 }
@@ -2363,14 +2501,23 @@ impl Draft {
                     if skippable {
                         settings.push(None);
                     }
+                    let num_options = settings.len();
+                    let chance = 100.0 / num_options as f64;
                     if let Some(setting) = settings.into_iter().choose(&mut thread_rng()).expect("no available settings") {
-                        Action::Pick { setting: setting.name.to_owned(), value: setting.options.choose(&mut thread_rng()).expect("no available values").name.to_owned() }
+                        let num_values = setting.options.len();
+                        let value = setting.options.choose(&mut thread_rng()).expect("no available values");
+                        log.push(format!(
+                            "Pick: {} (setting 1 of {num_options} options, {chance:.1}% each; value 1 of {num_values}, {:.1}% each; {:.1}% overall)",
+                            value.display, 100.0 / num_values as f64, chance / num_values as f64,
+                        ));
+                        Action::Pick { setting: setting.name.to_owned(), value: value.name.to_owned() }
                     } else {
+                        log.push(format!("Pick: skipped (1 of {num_options} options, {chance:.1}% each)"));
                         Action::Skip
                     }
                 }
                 StepKind::BooleanChoice { .. } => Action::BooleanChoice(thread_rng().gen()),
-                StepKind::Done(_) => break self.settings,
+                StepKind::Done(_) => break (self.settings, log),
             };
             self.apply(kind, None, &mut MessageContext::None, action).await?.expect("random draft made illegal action");
         })
@@ -1,11 +1,29 @@
-use crate::{
-    event::{
-        Data,
-        Tab,
+use {
+    ootr_utils as rando,
+    crate::{
+        event::{
+            Data,
+            Tab,
+        },
+        prelude::*,
+        racetime_bot::{
+            PrerollMode,
+            UnlockSpoilerLog,
+        },
     },
-    prelude::*,
 };
 
+/// The shortest auto-start delay accepted by racetime.gg, in seconds.
+pub(crate) const MIN_START_DELAY: u8 = 10;
+/// The longest auto-start delay accepted by racetime.gg, in seconds.
+pub(crate) const MAX_START_DELAY: u8 = 60;
+/// The shortest race time limit accepted by racetime.gg, in hours.
+pub(crate) const MIN_TIME_LIMIT: u8 = 1;
+/// The longest race time limit accepted by racetime.gg, in hours.
+pub(crate) const MAX_TIME_LIMIT: u8 = 72;
+/// The time limit used for race rooms of events that don't override it, in hours.
+pub(crate) const DEFAULT_TIME_LIMIT: u8 = 24;
+
 async fn configure_form(mut transaction: Transaction<'_, Postgres>, me: Option<User>, uri: Origin<'_>, csrf: Option<&CsrfToken>, event: Data<'_>, ctx: Context<'_>) -> Result<RawHtml<String>, event::Error> {
     let header = event.header(&mut transaction, me.as_ref(), Tab::Configure, true).await?;
     let form = if event.is_ended() {
@@ -25,11 +43,109 @@ async fn configure_form(mut transaction: Transaction<'_, Postgres>, me: Option<U
                         label(class = "help") : "(If this option is turned off, you can import races by clicking the Import button on the Races tab.)";
                     });
                 }
+                : form_field("schedule_sheet_url", &mut errors, html! {
+                    label(for = "schedule_sheet_url") : "Schedule sheet:";
+                    input(type = "url", name = "schedule_sheet_url", value = ctx.field_value("schedule_sheet_url").map(Cow::Borrowed).or_else(|| event.schedule_sheet_url.as_ref().map(|url| Cow::Owned(url.to_string()))).unwrap_or_default());
+                    label(class = "help") : "(The URL of a schedule sheet published as CSV, e.g. via “File → Share → Publish to web” in Google Sheets. If set and automatic imports are enabled, new races are imported from it whenever it changes. The first row must label the columns: “start” for the start time in UTC formatted like “2024-12-31 18:00”, optionally “phase” and “round”, and one column starting with “entrant” for each entrant. Incomplete rows and races that already exist are skipped. Leave empty to disable.)";
+                });
+                : form_field("auto_cancel_empty_rooms", &mut errors, html! {
+                    input(type = "checkbox", id = "auto_cancel_empty_rooms", name = "auto_cancel_empty_rooms", checked? = ctx.field_value("auto_cancel_empty_rooms").map_or(event.auto_cancel_empty_rooms, |value| value == "on"));
+                    label(for = "auto_cancel_empty_rooms") : "Automatically cancel race rooms nobody has joined";
+                    label(class = "help") : "(If nobody has joined a race room by the scheduled start time, a warning is posted in the room. If it's still empty 5 minutes later, the room is cancelled and the organizer channel on Discord is notified.)";
+                });
                 : form_field("min_schedule_notice", &mut errors, html! {
                     label(for = "min_schedule_notice") : "Minimum scheduling notice:";
                     input(type = "text", name = "min_schedule_notice", value = ctx.field_value("min_schedule_notice").map(Cow::Borrowed).unwrap_or_else(|| Cow::Owned(unparse_duration(event.min_schedule_notice)))); //TODO h:m:s fields?
                     label(class = "help") : "(Races must be scheduled at least this far in advance. Can be configured to be as low as 0 seconds, but note that if a race is scheduled less than 30 minutes in advance, the room is opened immediately, and if a race is scheduled less than 15 minutes in advance, the seed is posted immediately.)";
                 });
+                : form_field("scheduling_reminder_threshold", &mut errors, html! {
+                    label(for = "scheduling_reminder_threshold") : "Scheduling reminder:";
+                    input(type = "text", name = "scheduling_reminder_threshold", value = ctx.field_value("scheduling_reminder_threshold").map(Cow::Borrowed).or_else(|| event.scheduling_reminder_threshold.map(|threshold| Cow::Owned(unparse_duration(threshold)))).unwrap_or_default());
+                    label(class = "help") : "(If a match with a scheduling thread still isn't fully scheduled this long after it became available — when the event started, or when all races of the previous round ended — a reminder is sent. Matches with multiple games only count as scheduled once every game has a time. Leave empty to disable.)";
+                });
+                : form_field("scheduling_reminder_to_organizers", &mut errors, html! {
+                    input(type = "checkbox", id = "scheduling_reminder_to_organizers", name = "scheduling_reminder_to_organizers", checked? = ctx.field_value("scheduling_reminder_to_organizers").map_or(event.scheduling_reminder_to_organizers, |value| value == "on"));
+                    label(for = "scheduling_reminder_to_organizers") : "Send scheduling reminders to the organizer channel";
+                    label(class = "help") : "(If this option is turned off, the entrants are pinged in their scheduling thread instead.)";
+                });
+                : form_field("start_delay", &mut errors, html! {
+                    label(for = "start_delay") : "Auto-start delay:";
+                    input(type = "number", name = "start_delay", min = MIN_START_DELAY.to_string(), max = MAX_START_DELAY.to_string(), value = ctx.field_value("start_delay").map(Cow::Borrowed).or_else(|| event.start_delay.map(|start_delay| Cow::Owned(start_delay.to_string()))).unwrap_or_default());
+                    label(class = "help") : format!("(How many seconds the race room counts down after all entrants are ready, between {MIN_START_DELAY} and {MAX_START_DELAY}. Leave empty to use the default of 15 seconds, or 30 seconds for open Standard races.)");
+                });
+                : form_field("time_limit", &mut errors, html! {
+                    label(for = "time_limit") : "Time limit:";
+                    input(type = "number", name = "time_limit", min = MIN_TIME_LIMIT.to_string(), max = MAX_TIME_LIMIT.to_string(), value = ctx.field_value("time_limit").map(Cow::Borrowed).or_else(|| event.time_limit.map(|time_limit| Cow::Owned(time_limit.to_string()))).unwrap_or_default());
+                    label(class = "help") : format!("(How many hours race rooms stay open after the race starts before unfinished entrants are marked as did not finish, between {MIN_TIME_LIMIT} and {MAX_TIME_LIMIT}. Leave empty to use the default of {DEFAULT_TIME_LIMIT} hours. Changes only apply to race rooms opened afterwards.)");
+                });
+                : form_field("score_wait", &mut errors, html! {
+                    label(for = "score_wait") : "Score reporting window:";
+                    input(type = "text", name = "score_wait", value = ctx.field_value("score_wait").map(Cow::Borrowed).or_else(|| event.score_wait.map(|score_wait| Cow::Owned(unparse_duration(score_wait)))).unwrap_or_default());
+                    label(class = "help") : "(How long race rooms are kept open after the race ends so entrants can report their scores, e.g. for Triforce Blitz. Once this has passed, the race bot stops handling the room. Leave empty to use the default of 1 hour for Triforce Blitz races.)";
+                });
+                : form_field("allow_prerace_chat", &mut errors, html! {
+                    input(type = "checkbox", id = "allow_prerace_chat", name = "allow_prerace_chat", checked? = ctx.field_value("allow_prerace_chat").map_or(event.allow_prerace_chat, |value| value == "on"));
+                    label(for = "allow_prerace_chat") : "Allow chat in race rooms before the race starts";
+                });
+                : form_field("allow_midrace_chat", &mut errors, html! {
+                    input(type = "checkbox", id = "allow_midrace_chat", name = "allow_midrace_chat", checked? = ctx.field_value("allow_midrace_chat").map_or(event.allow_midrace_chat, |value| value == "on"));
+                    label(for = "allow_midrace_chat") : "Allow chat in race rooms during the race";
+                    label(class = "help") : "(Changes only apply to race rooms opened afterwards.)";
+                });
+                : form_field("streaming_required", &mut errors, html! {
+                    input(type = "checkbox", id = "streaming_required", name = "streaming_required", checked? = ctx.field_value("streaming_required").map_or(event.streaming_required, |value| value == "on"));
+                    label(for = "streaming_required") : "Require entrants to stream";
+                    label(class = "help") : "(Never applies to the private parts of async races. Changes only apply to race rooms opened afterwards.)";
+                });
+                : form_field("seed_message_languages", &mut errors, html! {
+                    label(for = "seed_message_languages") : "Seed message languages:";
+                    input(type = "text", name = "seed_message_languages", value = ctx.field_value("seed_message_languages").map(Cow::Borrowed).unwrap_or_else(|| Cow::Owned(event.seed_message_languages.iter().map(Language::short_code).join(", "))));
+                    label(class = "help") : "(Comma-separated language codes, e.g. “en, fr”. The seed link and spoiler log info are posted in each of these languages in order. Supported: en, fr, de, pt. Leave empty to use the default language for the event's goal.)";
+                });
+                : form_field("pre_race_checklist", &mut errors, html! {
+                    label(for = "pre_race_checklist") : "Pre-race checklist:";
+                    textarea(name = "pre_race_checklist") : ctx.field_value("pre_race_checklist").or(event.pre_race_checklist.as_deref());
+                    label(class = "help") : "(One item per line, e.g. emulator settings, audio, or stream delay. The checklist is posted in the race room 5 minutes before the start. Leave empty to disable.)";
+                });
+                : form_field("charity_donation_url", &mut errors, html! {
+                    label(for = "charity_donation_url") : "Charity donation link:";
+                    input(type = "url", name = "charity_donation_url", value = ctx.field_value("charity_donation_url").map(Cow::Borrowed).or_else(|| event.charity_donation_url.as_ref().map(|url| Cow::Owned(url.to_string()))).unwrap_or_default());
+                    label(class = "help") : "(If set, the !charity command can be used in race rooms to show the donation total along with this link. The total can be updated via the API. Leave empty to disable.)";
+                });
+                : form_field("unlock_spoiler_log", &mut errors, html! {
+                    label(for = "unlock_spoiler_log") : "Spoiler log unlock:";
+                    select(name = "unlock_spoiler_log") {
+                        option(value = "", selected? = ctx.field_value("unlock_spoiler_log").map_or(event.unlock_spoiler_log.is_none(), |value| value.is_empty())) : "Default";
+                        @for unlock_spoiler_log in [UnlockSpoilerLog::Now, UnlockSpoilerLog::After, UnlockSpoilerLog::Never] {
+                            option(value = unlock_spoiler_log.as_str(), selected? = ctx.field_value("unlock_spoiler_log").map_or(event.unlock_spoiler_log == Some(unlock_spoiler_log), |value| value == unlock_spoiler_log.as_str())) : unlock_spoiler_log.as_str();
+                        }
+                    }
+                    label(class = "help") : "(When the spoiler logs of official race seeds are made available: “now” as soon as the seed is rolled, “after” once the race has finished, or “never”. Leave on default to use the goal's behavior.)";
+                });
+                @if let (Series::Standard, "w") = (event.series, &*event.event) {
+                    : form_field("preroll_mode", &mut errors, html! {
+                        label(for = "preroll_mode") : "Seed preroll mode:";
+                        select(name = "preroll_mode") {
+                            option(value = "", selected? = ctx.field_value("preroll_mode").map_or(event.preroll_mode.is_none(), |value| value.is_empty())) : "Default";
+                            @for preroll_mode in all::<PrerollMode>() {
+                                option(value = preroll_mode.as_str(), selected? = ctx.field_value("preroll_mode").map_or(event.preroll_mode == Some(preroll_mode), |value| value == preroll_mode.as_str())) : preroll_mode.as_str();
+                            }
+                        }
+                        label(class = "help") : "(How early seeds are rolled on ootrandomizer.com: “none” rolls at the scheduled start, “short” within the 5 minutes before, “medium” between the room opening and 15 minutes before, and “long” keeps a seed in reserve. Rolling early reduces waiting but makes it easier to find the seed on the website ahead of time.)";
+                    });
+                    : form_field("rando_version", &mut errors, html! {
+                        label(for = "rando_version") : "Randomizer version:";
+                        input(type = "text", name = "rando_version", value = ctx.field_value("rando_version").map(Cow::Borrowed).or_else(|| event.rando_version.as_ref().map(|version| Cow::Owned(version.to_string()))).unwrap_or_default());
+                        label(class = "help") : "(The randomizer version used to roll seeds, e.g. “8.2.0” or “8.2.1 Fenhl-1”. Leave empty to use the default version for the current settings.)";
+                    });
+                }
+                @if let Series::Rsl = event.series {
+                    : form_field("rsl_ref", &mut errors, html! {
+                        label(for = "rsl_ref") : "RSL script version:";
+                        input(type = "text", name = "rsl_ref", value = ctx.field_value("rsl_ref").or(event.rsl_ref.as_deref()).unwrap_or_default());
+                        label(class = "help") : "(A commit, tag, or branch of the RSL script to roll seeds with. Leave empty to use the latest version.)";
+                    });
+                }
             }, errors, "Save")
         } else {
             html! {
@@ -67,7 +183,34 @@ pub(crate) struct ConfigureForm {
     csrf: String,
     auto_import: Option<bool>,
     #[field(default = String::new())]
+    schedule_sheet_url: String,
+    auto_cancel_empty_rooms: bool,
+    allow_prerace_chat: bool,
+    allow_midrace_chat: bool,
+    streaming_required: bool,
+    #[field(default = String::new())]
     min_schedule_notice: String,
+    #[field(default = String::new())]
+    scheduling_reminder_threshold: String,
+    scheduling_reminder_to_organizers: bool,
+    #[field(default = String::new())]
+    start_delay: String,
+    #[field(default = String::new())]
+    time_limit: String,
+    #[field(default = String::new())]
+    score_wait: String,
+    #[field(default = String::new())]
+    seed_message_languages: String,
+    #[field(default = String::new())]
+    pre_race_checklist: String,
+    #[field(default = String::new())]
+    charity_donation_url: String,
+    unlock_spoiler_log: Option<UnlockSpoilerLog>,
+    preroll_mode: Option<PrerollMode>,
+    #[field(default = String::new())]
+    rando_version: String,
+    #[field(default = String::new())]
+    rsl_ref: String,
 }
 
 #[rocket::post("/event/<series>/<event>/configure", data = "<form>")]
@@ -83,21 +226,109 @@ pub(crate) async fn post(pool: &State<PgPool>, me: User, uri: Origin<'_>, csrf:
         if !data.organizers(&mut transaction).await?.contains(&me) {
             form.context.push_error(form::Error::validation("You must be an organizer to configure this event."));
         }
+        let schedule_sheet_url = if value.schedule_sheet_url.trim().is_empty() {
+            None
+        } else if let Some(schedule_sheet_url) = value.schedule_sheet_url.trim().parse::<Url>().ok().filter(|url| url.scheme() == "https") {
+            Some(schedule_sheet_url)
+        } else {
+            form.context.push_error(form::Error::validation("Schedule sheet must be an https URL.").with_name("schedule_sheet_url"));
+            None
+        };
+        let charity_donation_url = if value.charity_donation_url.trim().is_empty() {
+            None
+        } else if let Some(charity_donation_url) = value.charity_donation_url.trim().parse::<Url>().ok().filter(|url| url.scheme() == "https") {
+            Some(charity_donation_url)
+        } else {
+            form.context.push_error(form::Error::validation("Charity donation link must be an https URL.").with_name("charity_donation_url"));
+            None
+        };
         let min_schedule_notice = if let Some(time) = parse_duration(&value.min_schedule_notice, DurationUnit::Hours) {
             Some(time)
         } else {
             form.context.push_error(form::Error::validation("Duration must be formatted like “1:23:45” or “1h 23m 45s”.").with_name("min_schedule_notice"));
             None
         };
+        let score_wait = if value.score_wait.trim().is_empty() {
+            None
+        } else if let Some(score_wait) = parse_duration(&value.score_wait, DurationUnit::Minutes) {
+            Some(score_wait)
+        } else {
+            form.context.push_error(form::Error::validation("Duration must be formatted like “1:23:45” or “1h 23m 45s”.").with_name("score_wait"));
+            None
+        };
+        let scheduling_reminder_threshold = if value.scheduling_reminder_threshold.trim().is_empty() {
+            None
+        } else if let Some(threshold) = parse_duration(&value.scheduling_reminder_threshold, DurationUnit::Hours) {
+            Some(threshold)
+        } else {
+            form.context.push_error(form::Error::validation("Duration must be formatted like “1:23:45” or “1h 23m 45s”.").with_name("scheduling_reminder_threshold"));
+            None
+        };
+        let start_delay = if value.start_delay.trim().is_empty() {
+            None
+        } else if let Some(start_delay) = value.start_delay.trim().parse::<u8>().ok().filter(|start_delay| (MIN_START_DELAY..=MAX_START_DELAY).contains(start_delay)) {
+            Some(start_delay)
+        } else {
+            form.context.push_error(form::Error::validation(format!("Auto-start delay must be a whole number of seconds between {MIN_START_DELAY} and {MAX_START_DELAY}.")).with_name("start_delay"));
+            None
+        };
+        let time_limit = if value.time_limit.trim().is_empty() {
+            None
+        } else if let Some(time_limit) = value.time_limit.trim().parse::<u8>().ok().filter(|time_limit| (MIN_TIME_LIMIT..=MAX_TIME_LIMIT).contains(time_limit)) {
+            Some(time_limit)
+        } else {
+            form.context.push_error(form::Error::validation(format!("Time limit must be a whole number of hours between {MIN_TIME_LIMIT} and {MAX_TIME_LIMIT}.")).with_name("time_limit"));
+            None
+        };
+        if let Some(UnlockSpoilerLog::Progression | UnlockSpoilerLog::Organizers) = value.unlock_spoiler_log {
+            form.context.push_error(form::Error::validation("Spoiler log unlock must be “now”, “after”, or “never”.").with_name("unlock_spoiler_log"));
+        }
+        let seed_message_languages = value.seed_message_languages.split(',').map(str::trim).filter(|code| !code.is_empty()).map(|code| all::<Language>().find(|language| language.short_code() == code.to_ascii_lowercase())).collect::<Option<Vec<_>>>().unwrap_or_else(|| {
+            form.context.push_error(form::Error::validation("Seed message languages must be a comma-separated list of language codes, e.g. “en, fr”.").with_name("seed_message_languages"));
+            Vec::default()
+        });
+        let rando_version = if value.rando_version.trim().is_empty() {
+            None
+        } else if let Some(rando_version) = value.rando_version.trim().parse::<rando::Version>().ok().filter(|rando_version| rando_version.to_string().parse().ok().as_ref() == Some(rando_version)) {
+            // only accept versions that survive being stored as text, so reading the event back can't fail
+            Some(rando_version)
+        } else {
+            form.context.push_error(form::Error::validation("Randomizer version must be formatted like “8.2.0” or “8.2.1 Fenhl-1”.").with_name("rando_version"));
+            None
+        };
+        if value.rsl_ref.trim().contains(char::is_whitespace) {
+            form.context.push_error(form::Error::validation("RSL script version must be a single commit, tag, or branch name.").with_name("rsl_ref"));
+        }
         if form.context.errors().next().is_some() {
             RedirectOrContent::Content(configure_form(transaction, Some(me), uri, csrf.as_ref(), data, form.context).await?)
         } else {
             if let Some(auto_import) = value.auto_import {
                 sqlx::query!("UPDATE events SET auto_import = $1 WHERE series = $2 AND event = $3", auto_import, data.series as _, &data.event).execute(&mut *transaction).await?;
             }
+            sqlx::query!("UPDATE events SET schedule_sheet_url = $1 WHERE series = $2 AND event = $3", schedule_sheet_url.map(|url| url.to_string()), data.series as _, &data.event).execute(&mut *transaction).await?;
+            sqlx::query!("UPDATE events SET auto_cancel_empty_rooms = $1 WHERE series = $2 AND event = $3", value.auto_cancel_empty_rooms, data.series as _, &data.event).execute(&mut *transaction).await?;
             if let Some(min_schedule_notice) = min_schedule_notice {
                 sqlx::query!("UPDATE events SET min_schedule_notice = $1 WHERE series = $2 AND event = $3", min_schedule_notice as _, data.series as _, &data.event).execute(&mut *transaction).await?;
             }
+            sqlx::query!("UPDATE events SET allow_prerace_chat = $1, allow_midrace_chat = $2 WHERE series = $3 AND event = $4", value.allow_prerace_chat, value.allow_midrace_chat, data.series as _, &data.event).execute(&mut *transaction).await?;
+            sqlx::query!("UPDATE events SET scheduling_reminder_threshold = $1, scheduling_reminder_to_organizers = $2 WHERE series = $3 AND event = $4", scheduling_reminder_threshold as _, value.scheduling_reminder_to_organizers, data.series as _, &data.event).execute(&mut *transaction).await?;
+            sqlx::query!("UPDATE events SET streaming_required = $1 WHERE series = $2 AND event = $3", value.streaming_required, data.series as _, &data.event).execute(&mut *transaction).await?;
+            sqlx::query!("UPDATE events SET start_delay = $1 WHERE series = $2 AND event = $3", start_delay.map(i16::from), data.series as _, &data.event).execute(&mut *transaction).await?;
+            sqlx::query!("UPDATE events SET time_limit = $1 WHERE series = $2 AND event = $3", time_limit.map(i16::from), data.series as _, &data.event).execute(&mut *transaction).await?;
+            sqlx::query!("UPDATE events SET score_wait = $1 WHERE series = $2 AND event = $3", score_wait as _, data.series as _, &data.event).execute(&mut *transaction).await?;
+            sqlx::query!("UPDATE events SET seed_message_languages = $1 WHERE series = $2 AND event = $3", &seed_message_languages as _, data.series as _, &data.event).execute(&mut *transaction).await?;
+            let pre_race_checklist = value.pre_race_checklist.lines().map(str::trim).filter(|item| !item.is_empty()).join("\n");
+            sqlx::query!("UPDATE events SET pre_race_checklist = $1 WHERE series = $2 AND event = $3", (!pre_race_checklist.is_empty()).then_some(pre_race_checklist), data.series as _, &data.event).execute(&mut *transaction).await?;
+            sqlx::query!("UPDATE events SET charity_donation_url = $1 WHERE series = $2 AND event = $3", charity_donation_url.map(|url| url.to_string()), data.series as _, &data.event).execute(&mut *transaction).await?;
+            sqlx::query!("UPDATE events SET unlock_spoiler_log = $1 WHERE series = $2 AND event = $3", value.unlock_spoiler_log as _, data.series as _, &data.event).execute(&mut *transaction).await?;
+            if let (Series::Standard, "w") = (data.series, &*data.event) {
+                sqlx::query!("UPDATE events SET preroll_mode = $1 WHERE series = $2 AND event = $3", value.preroll_mode as _, data.series as _, &data.event).execute(&mut *transaction).await?;
+                sqlx::query!("UPDATE events SET rando_version = $1 WHERE series = $2 AND event = $3", rando_version.map(|rando_version| rando_version.to_string()), data.series as _, &data.event).execute(&mut *transaction).await?;
+            }
+            if let Series::Rsl = data.series {
+                let rsl_ref = value.rsl_ref.trim();
+                sqlx::query!("UPDATE events SET rsl_ref = $1 WHERE series = $2 AND event = $3", (!rsl_ref.is_empty()).then_some(rsl_ref), data.series as _, &data.event).execute(&mut *transaction).await?;
+            }
             transaction.commit().await?;
             RedirectOrContent::Redirect(Redirect::to(uri!(super::info(series, event))))
         }
@@ -1,4 +1,5 @@
 use {
+    ootr_utils as rando,
     serenity::all::{
         CreateMessage,
         EditMember,
@@ -11,6 +12,10 @@ use {
     crate::{
         notification::SimpleNotificationKind,
         prelude::*,
+        racetime_bot::{
+            PrerollMode,
+            UnlockSpoilerLog,
+        },
     },
 };
 
@@ -169,13 +174,50 @@ pub(crate) struct Data<'a> {
     pub(crate) invitational_stream_delay: Duration,
     pub(crate) retime_window: Duration,
     pub(crate) auto_import: bool,
+    pub(crate) auto_cancel_empty_rooms: bool,
+    pub(crate) spoiler_to_organizers: bool,
+    /// Overrides how long race rooms are kept open after the race ends so entrants can report their scores, see [`racetime_bot::Goal::default_score_wait`].
+    pub(crate) score_wait: Option<Duration>,
+    /// Items to remind entrants of shortly before the race starts, one per line.
+    pub(crate) pre_race_checklist: Option<String>,
+    /// How long before the scheduled start entrants must have joined the race room before their alternate (if any) is invited instead. Defaults to the start time.
+    pub(crate) alternate_deadline: Option<Duration>,
+    /// Overrides the goal's default preroll mode for this event, see [`racetime_bot::Goal::preroll_mode`].
+    pub(crate) preroll_mode: Option<PrerollMode>,
+    /// Overrides the randomizer version for events whose goal supports it, currently only the Standard weeklies.
+    pub(crate) rando_version: Option<rando::Version>,
+    /// A commit, tag, or branch of the RSL script to roll this event's seeds with instead of the latest version.
+    pub(crate) rsl_ref: Option<String>,
+    /// Overrides the default auto-start delay of race rooms, in seconds. Must be between [`configure::MIN_START_DELAY`] and [`configure::MAX_START_DELAY`].
+    pub(crate) start_delay: Option<u8>,
+    /// Overrides the default time limit of race rooms, in hours. Must be between [`configure::MIN_TIME_LIMIT`] and [`configure::MAX_TIME_LIMIT`].
+    pub(crate) time_limit: Option<u8>,
+    /// Whether entrants can chat in this event's race rooms before the race starts.
+    pub(crate) allow_prerace_chat: bool,
+    /// Whether entrants can chat in this event's race rooms while the race is ongoing.
+    pub(crate) allow_midrace_chat: bool,
+    /// Whether entrants must stream this event's races. Never applies to private async parts or in the dev environment.
+    pub(crate) streaming_required: bool,
+    /// Overrides when the spoiler logs of this event's seeds are unlocked, see [`racetime_bot::Goal::unlock_spoiler_log`]. Does not apply to spoiler seeds.
+    pub(crate) unlock_spoiler_log: Option<UnlockSpoilerLog>,
     pub(crate) language: Language,
+    /// If nonempty, the seed link and spoiler log info are posted in each of these languages in order, instead of the goal's language.
+    pub(crate) seed_message_languages: Vec<Language>,
+    /// If set, races are automatically imported from this CSV export of a schedule sheet, see [`sheets::races_to_import`](crate::sheets::races_to_import).
+    pub(crate) schedule_sheet_url: Option<Url>,
+    /// If set, a reminder is sent if a match hasn't been fully scheduled this long after it became available to schedule.
+    pub(crate) scheduling_reminder_threshold: Option<Duration>,
+    /// Whether scheduling reminders are sent to the organizer channel instead of the entrants' scheduling thread.
+    pub(crate) scheduling_reminder_to_organizers: bool,
+    /// If set, the `!charity` command is enabled in this event's race rooms, reporting the donation total along with this link.
+    pub(crate) charity_donation_url: Option<Url>,
 }
 
 #[derive(Debug, thiserror::Error, rocket_util::Error)]
 pub(crate) enum DataError {
     #[error(transparent)] PgInterval(#[from] PgIntervalDecodeError),
     #[error(transparent)] Sql(#[from] sqlx::Error),
+    #[error(transparent)] TryFromInt(#[from] std::num::TryFromIntError),
     #[error(transparent)] Url(#[from] url::ParseError),
     #[error("no event with this series and identifier")]
     Missing,
@@ -215,7 +257,26 @@ impl<'a> Data<'a> {
             invitational_stream_delay,
             retime_window,
             auto_import,
-            language AS "language: Language"
+            auto_cancel_empty_rooms,
+            spoiler_to_organizers,
+            score_wait,
+            pre_race_checklist,
+            alternate_deadline,
+            preroll_mode AS "preroll_mode: PrerollMode",
+            rando_version,
+            rsl_ref,
+            start_delay,
+            time_limit,
+            allow_prerace_chat,
+            allow_midrace_chat,
+            streaming_required,
+            unlock_spoiler_log AS "unlock_spoiler_log: UnlockSpoilerLog",
+            language AS "language: Language",
+            seed_message_languages AS "seed_message_languages: Vec<Language>",
+            schedule_sheet_url,
+            scheduling_reminder_threshold,
+            scheduling_reminder_to_organizers,
+            charity_donation_url
         FROM events WHERE series = $1 AND event = $2"#, series as _, &event).fetch_optional(&mut **transaction).await?
             .map(|row| Ok::<_, DataError>(Self {
                 display_name: row.display_name,
@@ -246,7 +307,27 @@ impl<'a> Data<'a> {
                 invitational_stream_delay: decode_pginterval(row.invitational_stream_delay)?,
                 retime_window: decode_pginterval(row.retime_window)?,
                 auto_import: row.auto_import,
+                auto_cancel_empty_rooms: row.auto_cancel_empty_rooms,
+                spoiler_to_organizers: row.spoiler_to_organizers,
+                score_wait: row.score_wait.map(decode_pginterval).transpose()?,
+                pre_race_checklist: row.pre_race_checklist,
+                alternate_deadline: row.alternate_deadline.map(decode_pginterval).transpose()?,
+                preroll_mode: row.preroll_mode,
+                // validated in the configure form, so a value that doesn't parse was edited by hand and is ignored rather than making the event inaccessible
+                rando_version: row.rando_version.and_then(|version| version.parse().ok()),
+                rsl_ref: row.rsl_ref,
+                start_delay: row.start_delay.map(u8::try_from).transpose()?,
+                time_limit: row.time_limit.map(u8::try_from).transpose()?,
+                allow_prerace_chat: row.allow_prerace_chat,
+                allow_midrace_chat: row.allow_midrace_chat,
+                streaming_required: row.streaming_required,
+                unlock_spoiler_log: row.unlock_spoiler_log,
                 language: row.language,
+                seed_message_languages: row.seed_message_languages,
+                schedule_sheet_url: row.schedule_sheet_url.map(|url| url.parse()).transpose()?,
+                scheduling_reminder_threshold: row.scheduling_reminder_threshold.map(decode_pginterval).transpose()?,
+                scheduling_reminder_to_organizers: row.scheduling_reminder_to_organizers,
+                charity_donation_url: row.charity_donation_url.map(|url| url.parse()).transpose()?,
                 series, event,
             }))
             .transpose()
@@ -283,6 +364,7 @@ impl<'a> Data<'a> {
                 high_seed: Id::dummy(), // Draft::complete_randomly doesn't check for active team
                 went_first: None,
                 skipped_bans: 0,
+                chosen_by: HashMap::default(),
                 settings: HashMap::default(),
             }.complete_randomly(draft::Kind::MultiworldS3).await.unwrap()),
             (Series::Multiworld, "4") => from_file!("../../assets/event/mw/chests-4-7.1.198.json"),
@@ -1293,6 +1375,8 @@ pub(crate) enum AcceptError {
     NotInTeam,
     #[error("a racetime.gg account is required to enter as runner")]
     RaceTimeAccountRequired,
+    #[error("you are already on another team for this event")]
+    AlreadyInTeam,
 }
 
 impl<E: Into<AcceptError>> From<E> for StatusOrError<AcceptError> {
@@ -1313,6 +1397,9 @@ pub(crate) async fn confirm_signup(pool: &State<PgPool>, discord_ctx: &State<RwF
         if role == Role::Sheikah && me.racetime.is_none() {
             return Err(AcceptError::RaceTimeAccountRequired.into())
         }
+        if sqlx::query_scalar!(r#"SELECT EXISTS (SELECT 1 FROM teams, team_members WHERE id = team AND series = $1 AND event = $2 AND NOT resigned AND member = $3 AND team <> $4 AND status <> 'unconfirmed') AS "exists!""#, series as _, event, me.id as _, team as _).fetch_one(&mut *transaction).await? {
+            return Err(AcceptError::AlreadyInTeam.into())
+        }
         for member in sqlx::query_scalar!(r#"SELECT member AS "id: Id<Users>" FROM team_members WHERE team = $1 AND (status = 'created' OR status = 'confirmed')"#, team as _).fetch_all(&mut *transaction).await? {
             let id = Id::<Notifications>::new(&mut transaction).await?;
             sqlx::query!("INSERT INTO notifications (id, rcpt, kind, series, event, sender) VALUES ($1, $2, 'accept', $3, $4, $5)", id as _, member as _, series as _, event, me.id as _).execute(&mut *transaction).await?;
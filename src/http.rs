@@ -20,7 +20,10 @@ use {
             self,
             Notification,
         },
-        racetime_bot::SeedMetadata,
+        racetime_bot::{
+            CleanShutdown,
+            SeedMetadata,
+        },
         prelude::*,
     },
 };
@@ -471,7 +474,7 @@ async fn fallback_catcher(status: Status, request: &Request<'_>) -> PageResult {
     }).await
 }
 
-pub(crate) async fn rocket(pool: PgPool, discord_ctx: RwFuture<DiscordCtx>, http_client: reqwest::Client, config: Config, port: u16, seed_metadata: Arc<RwLock<HashMap<String, SeedMetadata>>>) -> Result<Rocket<rocket::Ignite>, crate::Error> {
+pub(crate) async fn rocket(pool: PgPool, discord_ctx: RwFuture<DiscordCtx>, http_client: reqwest::Client, config: Config, port: u16, seed_metadata: Arc<RwLock<HashMap<String, SeedMetadata>>>, clean_shutdown: Arc<Mutex<CleanShutdown>>) -> Result<Rocket<rocket::Ignite>, crate::Error> {
     let discord_config = if Environment::default().is_dev() { &config.discord_dev } else { &config.discord_production };
     let racetime_config = if Environment::default().is_dev() { &config.racetime_oauth_dev } else { &config.racetime_oauth_production };
     Ok(rocket::custom(rocket::Config::figment().merge(rocket::Config {
@@ -488,6 +491,15 @@ pub(crate) async fn rocket(pool: PgPool, discord_ctx: RwFuture<DiscordCtx>, http
         api::graphql_query,
         api::graphql_playground,
         api::entrants_csv,
+        api::validate_settings,
+        api::goals,
+        api::rando_versions,
+        api::mw_compat,
+        api::open_rooms,
+        api::pending_spoiler_unlocks,
+        api::race_result_summary,
+        api::verify_seed_password,
+        api::set_charity_total,
         auth::racetime_callback,
         auth::discord_callback,
         auth::challonge_callback,
@@ -504,6 +516,7 @@ pub(crate) async fn rocket(pool: PgPool, discord_ctx: RwFuture<DiscordCtx>, http
         cal::index_help,
         cal::index,
         cal::for_series,
+        cal::for_user,
         cal::for_event,
         cal::create_race,
         cal::create_race_post,
@@ -540,6 +553,8 @@ pub(crate) async fn rocket(pool: PgPool, discord_ctx: RwFuture<DiscordCtx>, http
         notification::notifications,
         notification::dismiss,
         seed::get,
+        seed::settings,
+        seed::spoiler,
         user::profile,
     ])
     .mount("/static", FileServer::without_index("assets/static"))
@@ -605,5 +620,6 @@ pub(crate) async fn rocket(pool: PgPool, discord_ctx: RwFuture<DiscordCtx>, http
     .manage(http_client)
     .manage(api::schema(pool))
     .manage(seed_metadata)
+    .manage(clean_shutdown)
     .ignite().await?)
 }
@@ -129,14 +129,22 @@ async fn main(Args { port, subcommand }: Args) -> Result<(), Error> {
             #[cfg(unix)] Subcommand::CleanupRoles { .. } => {
                 u8::read(&mut sock).await?;
             }
+            #[cfg(unix)] Subcommand::ForceStopRoom { .. } => if u8::read(&mut sock).await? == 0 {
+                println!("{} Mido's House: race handler aborted", Utc::now().format("%Y-%m-%d %H:%M:%S"));
+            } else {
+                eprintln!("{} Mido's House: no race handler running for this room", Utc::now().format("%Y-%m-%d %H:%M:%S"));
+            },
             #[cfg(unix)] Subcommand::PrepareStop { .. } => {
                 println!("{} preparing to stop Mido's House: waiting for reply", Utc::now().format("%Y-%m-%d %H:%M:%S"));
                 u8::read(&mut sock).await?;
                 println!("{} preparing to stop Mido's House: done", Utc::now().format("%Y-%m-%d %H:%M:%S"));
             }
-            #[cfg(unix)] Subcommand::Roll { .. } | Subcommand::RollRsl { .. } | Subcommand::Seed { .. } => while let Some(update) = Option::<SeedRollUpdate>::read(&mut sock).await? {
+            #[cfg(unix)] Subcommand::Roll { .. } | Subcommand::RollRsl { .. } | Subcommand::RollTfb { .. } | Subcommand::Seed { .. } => while let Some(update) = Option::<SeedRollUpdate>::read(&mut sock).await? {
                 println!("{} {update:#?}", Utc::now().format("%Y-%m-%d %H:%M:%S"));
             },
+            #[cfg(unix)] Subcommand::SimulateRace { .. } => while let Some(update) = Option::<String>::read(&mut sock).await? {
+                println!("{} {update}", Utc::now().format("%Y-%m-%d %H:%M:%S"));
+            },
             #[cfg(unix)] Subcommand::UpdateRegionalVc { .. } => {
                 println!("{} Mido's House: updating regional voice chat", Utc::now().format("%Y-%m-%d %H:%M:%S"));
                 u8::read(&mut sock).await?;
@@ -168,6 +176,7 @@ async fn main(Args { port, subcommand }: Args) -> Result<(), Error> {
             .log_slow_statements(log::LevelFilter::Warn, Duration::from_secs(10))
         ).await?;
         let seed_metadata = Arc::default();
+        let clean_shutdown = Arc::default();
         let rocket = http::rocket(
             db_pool.clone(),
             discord_builder.ctx_fut.clone(),
@@ -175,17 +184,22 @@ async fn main(Args { port, subcommand }: Args) -> Result<(), Error> {
             config.clone(),
             port.unwrap_or_else(|| if Environment::default().is_dev() { 24814 } else { 24812 }),
             Arc::clone(&seed_metadata),
+            Arc::clone(&clean_shutdown),
         ).await?;
         let new_room_lock = Arc::default();
         let extra_room_tx = Arc::new(RwLock::new(mpsc::channel(1).0));
         let discord_builder = discord_bot::configure_builder(discord_builder, db_pool.clone(), http_client.clone(), config.clone(), Arc::clone(&new_room_lock), Arc::clone(&extra_room_tx), rocket.shutdown());
-        let clean_shutdown = Arc::default();
         let racetime_config = if Environment::default().is_dev() { &config.racetime_bot_dev } else { &config.racetime_bot_production }.clone();
         let startgg_token = if Environment::default().is_dev() { &config.startgg_dev } else { &config.startgg_production };
         let (seed_cache_tx, seed_cache_rx) = watch::channel(());
         let global_state = Arc::new(racetime_bot::GlobalState::new(
             new_room_lock,
             racetime_config,
+            config.racetime_reconnect,
+            config.local_seed_rollers,
+            config.rsl_web_plando_attempts,
+            config.fpa_entrant_threshold,
+            config.handle_user_opened_standard_rooms,
             extra_room_tx,
             db_pool.clone(),
             http_client.clone(),
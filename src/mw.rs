@@ -1,205 +1,245 @@
-use crate::prelude::*;
-
-#[rocket::get("/mw")]
-pub(crate) async fn index(pool: &State<PgPool>, me: Option<User>, uri: Origin<'_>) -> PageResult {
-    let transaction = pool.begin().await?;
-    page(transaction, &me, &uri, PageStyle { kind: PageKind::Center, ..PageStyle::default() }, "Mido's House Multiworld", html! {
-        h1 : "Mido's House Multiworld";
-        img(class = "banner icon", src = static_url!("mw.png"));
-        p {
-            : "Mido's House Multiworld is a tool that can be used to play ";
-            a(href = "https://wiki.ootrandomizer.com/index.php?title=Multiworld") : "multiworld";
-            : " seeds of the ";
-            a(href = "https://ootrandomizer.com/") : "Ocarina of Time randomizer";
-            : ". It supports cross-platform play between ";
-            a(href = uri!(platforms).to_string()) : "different platforms";
-            : ", and does not require port forwarding.";
-        }
-        div(class = "button-row large-button-row") {
-            a(class = "button", href = "https://github.com/midoshouse/ootr-multiworld/releases/latest/download/multiworld-installer.exe") {
-                : "Download for Windows";
-                br;
-                small : "supports EverDrive*, BizHawk, and Project64";
-            }
-            a(class = "button", href = uri!(install_macos).to_string()) {
-                : "Install instructions for macOS";
-                br;
-                small : "supports EverDrive*";
-            }
-            a(class = "button", href = "https://github.com/midoshouse/ootr-multiworld/releases/latest/download/multiworld-installer-linux") {
-                : "Download for Linux";
-                br;
-                small : "supports EverDrive* and BizHawk";
-            }
-        }
-        p {
-            : "*EverDrive support is currently experimental and requires ";
-            a(href = "https://ootrandomizer.com/generatorDev?version=devFenhl_") : "Fenhl's branch of the randomizer";
-            : ".";
-        }
-        p {
-            : "If you need help, please ask in ";
-            a(href = "https://discord.gg/BGRrKKn") : "#setup-support on the OoTR Discord";
-            : " (feel free to ping @fenhl) or ";
-            a(href = "https://github.com/midoshouse/ootr-multiworld/issues/new") : "open an issue";
-            : ".";
-        }
-        p {
-            a(href = "https://github.com/midoshouse/ootr-multiworld") : "The source code for Mido's House Multiworld";
-            : " is available on GitHub.";
-        }
-    }).await
-}
-
-#[rocket::get("/mw/platforms")]
-pub(crate) async fn platforms(pool: &State<PgPool>, me: Option<User>, uri: Origin<'_>) -> PageResult {
-    let transaction = pool.begin().await?;
-    page(transaction, &me, &uri, PageStyle { kind: PageKind::Center, ..PageStyle::default() }, "platform support — Mido's House Multiworld", html! {
-        h1 {
-            a(href = uri!(index).to_string()) : "Mido's House Multiworld";
-            : " platform support status";
-        }
-        table {
-            tr {
-                th;
-                th : "Windows";
-                th : "Linux";
-                th : "macOS";
-            }
-            tr {
-                th : "EverDrive";
-                td {
-                    : "✓ (";
-                    a(href = "https://github.com/midoshouse/ootr-multiworld/releases/latest/download/multiworld-installer.exe") : "download";
-                    : ") *";
-                }
-                td {
-                    : "✓ (";
-                    a(href = "https://github.com/midoshouse/ootr-multiworld/releases/latest/download/multiworld-installer-linux") : "download";
-                    : ") *";
-                }
-                td {
-                    : "✓ (";
-                    a(href = uri!(install_macos).to_string()) : "install instructions";
-                    : ") *";
-                }
-            }
-            tr {
-                th : "Wii Virtual Console";
-                td(colspan = "3") : "Would require a modification to Virtual Console itself. The “Multiworld 2.0” project claims to have solved this issue but has not shared any details out of concerns for competitive integrity.";
-            }
-            tr {
-                th : "BizHawk";
-                td {
-                    : "✓ (";
-                    a(href = "https://github.com/midoshouse/ootr-multiworld/releases/latest/download/multiworld-installer.exe") : "download";
-                    : ")";
-                }
-                td {
-                    : "✓ (";
-                    a(href = "https://github.com/midoshouse/ootr-multiworld/releases/latest/download/multiworld-installer-linux") : "download";
-                    : ")";
-                }
-                td {
-                    a(href = "https://github.com/tasemulators/bizHawk#macos-legacy-bizhawk") : "Not supported by BizHawk itself";
-                }
-            }
-            tr {
-                th : "Project64";
-                td {
-                    : "✓ (";
-                    a(href = "https://github.com/midoshouse/ootr-multiworld/releases/latest/download/multiworld-installer.exe") : "download";
-                    : ")";
-                }
-                td(colspan = "2") : "Not supported by Project64 itself";
-            }
-            tr {
-                th : "Project64-EM";
-                td(colspan = "3") {
-                    : "Not planned. Project64-EM is a modified version of Project64 created by the ";
-                    a(href = "https://ootmm.com/") : "OoTMM";
-                    : " community which removes the plugin system used by Mido's House Multiworld and replaces it with a different one. Note that Mido's House Multiworld does not support OoTMM — please follow ";
-                    a(href = "https://ootmm.com/multiplayer") : "the OoTMM multiplayer setup guide";
-                    : " instead.";
-                }
-            }
-            tr {
-                th : "RetroArch";
-                td(colspan = "3") {
-                    a(href = "https://github.com/midoshouse/ootr-multiworld/issues/25") : "Planned";
-                }
-            }
-        }
-        p {
-            : "*EverDrive support is currently experimental and requires ";
-            a(href = "https://ootrandomizer.com/generatorDev?version=devFenhl_") : "Fenhl's branch of the randomizer";
-            : ".";
-        }
-        p {
-            : "If your operating system, console, or emulator is not listed here, please ";
-            a(href = "https://github.com/midoshouse/ootr-multiworld/issues/new") : "open an issue";
-            : " to request support.";
-        }
-    }).await
-}
-
-#[rocket::get("/mw/install/macos")]
-pub(crate) async fn install_macos(pool: &State<PgPool>, me: Option<User>, uri: Origin<'_>) -> PageResult {
-    let transaction = pool.begin().await?;
-    page(transaction, &me, &uri, PageStyle::default(), "macOS install instructions — Mido's House Multiworld", html! {
-        h1 {
-            a(href = uri!(index).to_string()) : "Mido's House Multiworld";
-            : " install instructions for macOS";
-        }
-        p : "You will need administrator permissions.";
-        h2 : "Using Homebrew (recommended)";
-        ol {
-            li {
-                : "Install ";
-                a(href = "https://brew.sh/") : "Homebrew";
-                : ".";
-            }
-            li {
-                : "In Terminal, run the following command:";
-                br;
-                code : "brew install --no-quarantine midoshouse/tap/mhmw";
-            }
-        }
-        h2 {
-            : "Using ";
-            a(href = "https://github.com/LnL7/nix-darwin") : "nix-darwin";
-        }
-        ol {
-            li {
-                : "Edit your configuration.nix to include the following:";
-                pre : "{ config, pkgs, ... }: {
-    homebrew = {
-        enable = true;
-        casks = [
-            {
-                name = \"midoshouse/tap/mhmw\";
-                args.no_quarantine = true;
-            }
-        ];
-        onActivation = {
-            autoUpdate = true;
-            upgrade = true;
-        };
-    };
-}";
-            }
-            li {
-                : "Run ";
-                code : "darwin-rebuild switch";
-            }
-        }
-        h2 : "Support";
-        p {
-            : "If you need help, please ask in ";
-            a(href = "https://discord.gg/BGRrKKn") : "#setup-support on the OoTR Discord";
-            : " (feel free to ping @fenhl) or ";
-            a(href = "https://github.com/midoshouse/ootr-multiworld/issues/new") : "open an issue";
-            : ".";
-        }
-    }).await
-}
+use {
+    semver::Version,
+    crate::prelude::*,
+};
+
+/// Stays well within GitHub's rate limit for unauthenticated API requests.
+const RATE_LIMIT: Duration = Duration::from_secs(10 * 60);
+
+static LATEST_APP_VERSION: LazyLock<Mutex<Option<(Instant, Version)>>> = LazyLock::new(Mutex::default);
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum LatestAppVersionError {
+    #[error(transparent)] Reqwest(#[from] reqwest::Error),
+    #[error(transparent)] SemVer(#[from] semver::Error),
+    #[error(transparent)] Wheel(#[from] wheel::Error),
+}
+
+/// The version of the latest release of Mido's House Multiworld, according to the app's GitHub releases.
+///
+/// The app doesn't publish which randomizer versions each release supports, so this is the version required for playing multiworld seeds.
+pub(crate) async fn latest_app_version(http_client: &reqwest::Client) -> Result<Version, LatestAppVersionError> {
+    #[derive(Deserialize)]
+    struct Release {
+        tag_name: String,
+    }
+
+    lock!(cache = LATEST_APP_VERSION; {
+        if let Some((next_request, ref version)) = *cache {
+            if Instant::now() < next_request {
+                return Ok(version.clone())
+            }
+        }
+        let Release { tag_name } = http_client.get("https://api.github.com/repos/midoshouse/ootr-multiworld/releases/latest")
+            .send().await?
+            .detailed_error_for_status().await?
+            .json_with_text_in_error().await?;
+        let version = tag_name.trim_start_matches('v').parse::<Version>()?;
+        *cache = Some((Instant::now() + RATE_LIMIT, version.clone()));
+        Ok(version)
+    })
+}
+
+#[rocket::get("/mw")]
+pub(crate) async fn index(pool: &State<PgPool>, me: Option<User>, uri: Origin<'_>) -> PageResult {
+    let transaction = pool.begin().await?;
+    page(transaction, &me, &uri, PageStyle { kind: PageKind::Center, ..PageStyle::default() }, "Mido's House Multiworld", html! {
+        h1 : "Mido's House Multiworld";
+        img(class = "banner icon", src = static_url!("mw.png"));
+        p {
+            : "Mido's House Multiworld is a tool that can be used to play ";
+            a(href = "https://wiki.ootrandomizer.com/index.php?title=Multiworld") : "multiworld";
+            : " seeds of the ";
+            a(href = "https://ootrandomizer.com/") : "Ocarina of Time randomizer";
+            : ". It supports cross-platform play between ";
+            a(href = uri!(platforms).to_string()) : "different platforms";
+            : ", and does not require port forwarding.";
+        }
+        div(class = "button-row large-button-row") {
+            a(class = "button", href = "https://github.com/midoshouse/ootr-multiworld/releases/latest/download/multiworld-installer.exe") {
+                : "Download for Windows";
+                br;
+                small : "supports EverDrive*, BizHawk, and Project64";
+            }
+            a(class = "button", href = uri!(install_macos).to_string()) {
+                : "Install instructions for macOS";
+                br;
+                small : "supports EverDrive*";
+            }
+            a(class = "button", href = "https://github.com/midoshouse/ootr-multiworld/releases/latest/download/multiworld-installer-linux") {
+                : "Download for Linux";
+                br;
+                small : "supports EverDrive* and BizHawk";
+            }
+        }
+        p {
+            : "*EverDrive support is currently experimental and requires ";
+            a(href = "https://ootrandomizer.com/generatorDev?version=devFenhl_") : "Fenhl's branch of the randomizer";
+            : ".";
+        }
+        p {
+            : "If you need help, please ask in ";
+            a(href = "https://discord.gg/BGRrKKn") : "#setup-support on the OoTR Discord";
+            : " (feel free to ping @fenhl) or ";
+            a(href = "https://github.com/midoshouse/ootr-multiworld/issues/new") : "open an issue";
+            : ".";
+        }
+        p {
+            a(href = "https://github.com/midoshouse/ootr-multiworld") : "The source code for Mido's House Multiworld";
+            : " is available on GitHub.";
+        }
+    }).await
+}
+
+#[rocket::get("/mw/platforms")]
+pub(crate) async fn platforms(pool: &State<PgPool>, me: Option<User>, uri: Origin<'_>) -> PageResult {
+    let transaction = pool.begin().await?;
+    page(transaction, &me, &uri, PageStyle { kind: PageKind::Center, ..PageStyle::default() }, "platform support — Mido's House Multiworld", html! {
+        h1 {
+            a(href = uri!(index).to_string()) : "Mido's House Multiworld";
+            : " platform support status";
+        }
+        table {
+            tr {
+                th;
+                th : "Windows";
+                th : "Linux";
+                th : "macOS";
+            }
+            tr {
+                th : "EverDrive";
+                td {
+                    : "✓ (";
+                    a(href = "https://github.com/midoshouse/ootr-multiworld/releases/latest/download/multiworld-installer.exe") : "download";
+                    : ") *";
+                }
+                td {
+                    : "✓ (";
+                    a(href = "https://github.com/midoshouse/ootr-multiworld/releases/latest/download/multiworld-installer-linux") : "download";
+                    : ") *";
+                }
+                td {
+                    : "✓ (";
+                    a(href = uri!(install_macos).to_string()) : "install instructions";
+                    : ") *";
+                }
+            }
+            tr {
+                th : "Wii Virtual Console";
+                td(colspan = "3") : "Would require a modification to Virtual Console itself. The “Multiworld 2.0” project claims to have solved this issue but has not shared any details out of concerns for competitive integrity.";
+            }
+            tr {
+                th : "BizHawk";
+                td {
+                    : "✓ (";
+                    a(href = "https://github.com/midoshouse/ootr-multiworld/releases/latest/download/multiworld-installer.exe") : "download";
+                    : ")";
+                }
+                td {
+                    : "✓ (";
+                    a(href = "https://github.com/midoshouse/ootr-multiworld/releases/latest/download/multiworld-installer-linux") : "download";
+                    : ")";
+                }
+                td {
+                    a(href = "https://github.com/tasemulators/bizHawk#macos-legacy-bizhawk") : "Not supported by BizHawk itself";
+                }
+            }
+            tr {
+                th : "Project64";
+                td {
+                    : "✓ (";
+                    a(href = "https://github.com/midoshouse/ootr-multiworld/releases/latest/download/multiworld-installer.exe") : "download";
+                    : ")";
+                }
+                td(colspan = "2") : "Not supported by Project64 itself";
+            }
+            tr {
+                th : "Project64-EM";
+                td(colspan = "3") {
+                    : "Not planned. Project64-EM is a modified version of Project64 created by the ";
+                    a(href = "https://ootmm.com/") : "OoTMM";
+                    : " community which removes the plugin system used by Mido's House Multiworld and replaces it with a different one. Note that Mido's House Multiworld does not support OoTMM — please follow ";
+                    a(href = "https://ootmm.com/multiplayer") : "the OoTMM multiplayer setup guide";
+                    : " instead.";
+                }
+            }
+            tr {
+                th : "RetroArch";
+                td(colspan = "3") {
+                    a(href = "https://github.com/midoshouse/ootr-multiworld/issues/25") : "Planned";
+                }
+            }
+        }
+        p {
+            : "*EverDrive support is currently experimental and requires ";
+            a(href = "https://ootrandomizer.com/generatorDev?version=devFenhl_") : "Fenhl's branch of the randomizer";
+            : ".";
+        }
+        p {
+            : "If your operating system, console, or emulator is not listed here, please ";
+            a(href = "https://github.com/midoshouse/ootr-multiworld/issues/new") : "open an issue";
+            : " to request support.";
+        }
+    }).await
+}
+
+#[rocket::get("/mw/install/macos")]
+pub(crate) async fn install_macos(pool: &State<PgPool>, me: Option<User>, uri: Origin<'_>) -> PageResult {
+    let transaction = pool.begin().await?;
+    page(transaction, &me, &uri, PageStyle::default(), "macOS install instructions — Mido's House Multiworld", html! {
+        h1 {
+            a(href = uri!(index).to_string()) : "Mido's House Multiworld";
+            : " install instructions for macOS";
+        }
+        p : "You will need administrator permissions.";
+        h2 : "Using Homebrew (recommended)";
+        ol {
+            li {
+                : "Install ";
+                a(href = "https://brew.sh/") : "Homebrew";
+                : ".";
+            }
+            li {
+                : "In Terminal, run the following command:";
+                br;
+                code : "brew install --no-quarantine midoshouse/tap/mhmw";
+            }
+        }
+        h2 {
+            : "Using ";
+            a(href = "https://github.com/LnL7/nix-darwin") : "nix-darwin";
+        }
+        ol {
+            li {
+                : "Edit your configuration.nix to include the following:";
+                pre : "{ config, pkgs, ... }: {
+    homebrew = {
+        enable = true;
+        casks = [
+            {
+                name = \"midoshouse/tap/mhmw\";
+                args.no_quarantine = true;
+            }
+        ];
+        onActivation = {
+            autoUpdate = true;
+            upgrade = true;
+        };
+    };
+}";
+            }
+            li {
+                : "Run ";
+                code : "darwin-rebuild switch";
+            }
+        }
+        h2 : "Support";
+        p {
+            : "If you need help, please ask in ";
+            a(href = "https://discord.gg/BGRrKKn") : "#setup-support on the OoTR Discord";
+            : " (feel free to ping @fenhl) or ";
+            a(href = "https://github.com/midoshouse/ootr-multiworld/issues/new") : "open an issue";
+            : ".";
+        }
+    }).await
+}
@@ -1,6 +1,7 @@
 //! A client for the ootrandomizer.com API, documented at <https://ootrandomizer.com/api/docs>
 
 use {
+    std::collections::VecDeque,
     reqwest::{
         IntoUrl,
         StatusCode,
@@ -35,6 +36,10 @@ const KNOWN_GOOD_VERSIONS: [ootr_utils::Version; 4] = [
 ];
 
 const MULTIWORLD_RATE_LIMIT: Duration = Duration::from_secs(20);
+/// How many recent multiworld seed generation times are used to estimate wait times in the queue.
+const MULTIWORLD_GEN_TIME_HISTORY: usize = 10;
+/// How many times a seed for an official race may move ahead of the same practice seed in the multiworld queue, to avoid starving practice rooms on busy days.
+const MULTIWORLD_MAX_QUEUE_JUMPS: usize = 3;
 
 #[derive(Debug, thiserror::Error)]
 pub(crate) enum Error {
@@ -54,6 +59,24 @@ pub(crate) enum Error {
     },
     #[error("seed status API endpoint returned unknown value {0}")]
     UnexpectedSeedStatus(u8),
+    #[error("ootrandomizer.com failed to generate a seed uploaded from Mido's House")]
+    Upload,
+}
+
+impl IsNetworkError for Error {
+    fn is_network_error(&self) -> bool {
+        match self {
+            Self::Reqwest(e) => e.is_network_error(),
+            Self::Wheel(e) => e.is_network_error(),
+            Self::HeaderToStr(_) => false,
+            Self::ChannelClosed => false,
+            Self::PatchPathHeader => false,
+            Self::RandomSettings => false,
+            Self::Retries { .. } => false,
+            Self::UnexpectedSeedStatus(_) => false,
+            Self::Upload => false,
+        }
+    }
 }
 
 impl From<mpsc::error::SendError<SeedRollUpdate>> for Error {
@@ -73,6 +96,8 @@ pub(crate) struct SeedInfo {
     pub(crate) file_hash: [HashIcon; 5],
     pub(crate) file_stem: String,
     pub(crate) password: Option<[OcarinaNote; 6]>,
+    /// How many times the seed was submitted to ootrandomizer.com before generation succeeded.
+    pub(crate) attempts: u8,
 }
 
 #[derive(Deserialize)]
@@ -81,13 +106,54 @@ pub(crate) struct SeedDetailsResponse {
     pub(crate) spoiler_log: String,
 }
 
+struct QueueEntry {
+    official: bool,
+    /// How many seeds for official races have been moved ahead of this one.
+    jumped: usize,
+    pos_tx: mpsc::UnboundedSender<usize>,
+}
+
+/// Removes a seed from the multiworld queue if it stops waiting before reaching the front, e.g. because the roll was cancelled.
+///
+/// Without this, the abandoned entry would eventually block every seed behind it.
+struct QueueGuard {
+    waiting: Arc<Mutex<Vec<QueueEntry>>>,
+    pos_tx: Option<mpsc::UnboundedSender<usize>>,
+}
+
+impl QueueGuard {
+    /// Called once the seed has left the queue normally.
+    fn disarm(&mut self) {
+        self.pos_tx = None;
+    }
+}
+
+impl Drop for QueueGuard {
+    fn drop(&mut self) {
+        if let Some(pos_tx) = self.pos_tx.take() {
+            let waiting = self.waiting.clone();
+            tokio::spawn(async move {
+                lock!(waiting = waiting; {
+                    if let Some(pos) = waiting.iter().position(|entry| entry.pos_tx.same_channel(&pos_tx)) {
+                        waiting.remove(pos);
+                        for (new_pos, entry) in waiting.iter().enumerate().skip(pos) {
+                            let _ = entry.pos_tx.send(new_pos);
+                        }
+                    }
+                });
+            });
+        }
+    }
+}
+
 pub(crate) struct ApiClient {
     http_client: reqwest::Client,
     api_key: String,
     api_key_encryption: String,
     next_request: Mutex<Instant>,
     mw_seed_rollers: Semaphore,
-    waiting: Mutex<Vec<mpsc::UnboundedSender<()>>>,
+    waiting: Arc<Mutex<Vec<QueueEntry>>>,
+    mw_gen_times: Mutex<VecDeque<Duration>>,
 }
 
 impl ApiClient {
@@ -95,7 +161,8 @@ impl ApiClient {
         Self {
             next_request: Mutex::new(Instant::now() + MULTIWORLD_RATE_LIMIT),
             mw_seed_rollers: Semaphore::new(2), // we're allowed to roll a maximum of 2 multiworld seeds at the same time
-            waiting: Mutex::default(),
+            waiting: Arc::default(),
+            mw_gen_times: Mutex::default(),
             http_client, api_key, api_key_encryption,
         }
     }
@@ -209,7 +276,7 @@ impl ApiClient {
     /// Checks if the given randomizer branch/version is available on web, and if so, which version to use.
     pub(crate) async fn can_roll_on_web(&self, rsl_preset: Option<&VersionedRslPreset>, version: &VersionedBranch, world_count: u8, unlock_spoiler_log: UnlockSpoilerLog) -> Option<ootr_utils::Version> {
         if world_count > 3 { return None }
-        if let UnlockSpoilerLog::Progression = unlock_spoiler_log { return None }
+        if let UnlockSpoilerLog::Progression | UnlockSpoilerLog::Organizers = unlock_spoiler_log { return None } // spoiler log needs to be available locally
         if rsl_preset.is_some() && version.branch().map_or(true, |branch| branch.latest_web_name_random_settings().is_none()) { return None }
         match version {
             VersionedBranch::Pinned(version) => {
@@ -233,7 +300,7 @@ impl ApiClient {
         }
     }
 
-    pub(crate) async fn roll_seed_web(&self, update_tx: mpsc::Sender<SeedRollUpdate>, delay_until: Option<DateTime<Utc>>, version: ootr_utils::Version, random_settings: bool, unlock_spoiler_log: UnlockSpoilerLog, mut settings: serde_json::Map<String, Json>) -> Result<SeedInfo, Error> {
+    pub(crate) async fn roll_seed_web(&self, update_tx: mpsc::Sender<SeedRollUpdate>, official: bool, delay_until: Option<DateTime<Utc>>, version: ootr_utils::Version, random_settings: bool, unlock_spoiler_log: UnlockSpoilerLog, mut settings: serde_json::Map<String, Json>) -> Result<SeedInfo, Error> {
         #[serde_as]
         #[derive(Deserialize)]
         #[serde(rename_all = "camelCase")]
@@ -275,23 +342,46 @@ impl ApiClient {
                 Ok(permit) => permit,
                 Err(TryAcquireError::Closed) => unreachable!(),
                 Err(TryAcquireError::NoPermits) => {
-                    let (mut pos, mut pos_rx) = lock!(waiting = self.waiting; {
-                        let pos = waiting.len();
+                    let (mut pos, mut pos_rx, pos_tx) = lock!(waiting = self.waiting; {
+                        let mut pos = waiting.len();
+                        if official {
+                            // The seed at the front of the queue may already be waiting for a roller, so it is never moved back.
+                            while pos > 1 && !waiting[pos - 1].official && waiting[pos - 1].jumped < MULTIWORLD_MAX_QUEUE_JUMPS {
+                                pos -= 1;
+                            }
+                        }
                         let (pos_tx, pos_rx) = mpsc::unbounded_channel();
-                        waiting.push(pos_tx);
-                        (pos, pos_rx)
+                        waiting.insert(pos, QueueEntry { jumped: 0, official, pos_tx: pos_tx.clone() });
+                        for (new_pos, entry) in waiting.iter_mut().enumerate().skip(pos + 1) {
+                            entry.jumped += 1;
+                            let _ = entry.pos_tx.send(new_pos);
+                        }
+                        (pos, pos_rx, pos_tx)
                     });
-                    update_tx.send(SeedRollUpdate::Queued(pos.try_into().unwrap())).await?;
+                    let mut queue_guard = QueueGuard { waiting: self.waiting.clone(), pos_tx: Some(pos_tx) };
+                    let eta = lock!(mw_gen_times = self.mw_gen_times; {
+                        (!mw_gen_times.is_empty()).then(|| {
+                            let avg = mw_gen_times.iter().sum::<Duration>() / u32::try_from(mw_gen_times.len()).unwrap();
+                            // 2 seeds are rolled in parallel and both rollers are currently busy
+                            avg * (u32::try_from(pos / 2).unwrap() + 2)
+                        })
+                    });
+                    update_tx.send(SeedRollUpdate::Queued(pos.try_into().unwrap(), eta)).await?;
                     while pos > 0 {
-                        let () = pos_rx.recv().await.expect("queue position notifier closed");
-                        pos -= 1;
-                        update_tx.send(SeedRollUpdate::MovedForward(pos.try_into().unwrap())).await?;
+                        let new_pos = pos_rx.recv().await.expect("queue position notifier closed");
+                        if new_pos < pos {
+                            update_tx.send(SeedRollUpdate::MovedForward(new_pos.try_into().unwrap())).await?;
+                        } else {
+                            update_tx.send(SeedRollUpdate::MovedBack(new_pos.try_into().unwrap())).await?;
+                        }
+                        pos = new_pos;
                     }
                     lock!(waiting = self.waiting; {
                         let permit = self.mw_seed_rollers.acquire().await.expect("seed queue semaphore closed");
                         waiting.remove(0);
-                        for tx in &*waiting {
-                            let _ = tx.send(());
+                        queue_guard.disarm();
+                        for (new_pos, entry) in waiting.iter().enumerate() {
+                            let _ = entry.pos_tx.send(new_pos);
                         }
                         permit
                     })
@@ -300,6 +390,7 @@ impl ApiClient {
         } else {
             None
         };
+        let mw_start = Instant::now();
         let mut last_id = None;
         for attempt in 0u8.. {
             if attempt >= 3 && delay_until.map_or(true, |delay_until| Utc::now() >= delay_until) {
@@ -336,6 +427,14 @@ impl ApiClient {
                 match resp.json_with_text_in_error::<SeedStatusResponse>().await?.status {
                     0 => continue, // still generating
                     1 => { // generated success
+                        if mw_permit.is_some() {
+                            lock!(mw_gen_times = self.mw_gen_times; {
+                                if mw_gen_times.len() >= MULTIWORLD_GEN_TIME_HISTORY {
+                                    mw_gen_times.pop_front();
+                                }
+                                mw_gen_times.push_back(mw_start.elapsed());
+                            });
+                        }
                         drop(mw_permit);
                         let SeedDetailsResponse { creation_timestamp, settings_log } = self.get("https://ootrandomizer.com/api/v2/seed/details", Some(&[("key", api_key), ("id", &*id.to_string())])).await?
                             .detailed_error_for_status().await?
@@ -359,6 +458,7 @@ impl ApiClient {
                             } else {
                                 None
                             },
+                            attempts: attempt + 1,
                             id,
                         })
                     }
@@ -377,6 +477,69 @@ impl ApiClient {
         })
     }
 
+    /// Generates a copy of a seed that was rolled locally on ootrandomizer.com, using the same settings including the RNG seed.
+    ///
+    /// Returns the web ID and file hash of the copy. The caller should compare the file hash against the original to make sure the copy is identical.
+    pub(crate) async fn upload_seed(&self, version: ootr_utils::Version, locked: bool, mut settings: serde_json::Map<String, Json>) -> Result<(i64, [HashIcon; 5]), Error> {
+        #[serde_as]
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CreateSeedResponse {
+            #[serde_as(as = "DisplayFromStr")]
+            id: i64,
+        }
+
+        #[derive(Deserialize)]
+        struct SeedStatusResponse {
+            status: u8,
+        }
+
+        #[derive(Deserialize)]
+        struct SettingsLog {
+            file_hash: [HashIcon; 5],
+        }
+
+        #[serde_as]
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct SeedDetailsResponse {
+            #[serde_as(as = "JsonString")]
+            settings_log: SettingsLog,
+        }
+
+        let is_mw = settings.get("world_count").map_or(1, |world_count| world_count.as_u64().expect("world_count setting wasn't valid u64")) > 1;
+        let password_lock = settings.remove("password_lock").map_or(false, |password_lock| password_lock.as_bool().expect("password_lock setting wasn't a Boolean"));
+        let _mw_permit = if is_mw { Some(self.mw_seed_rollers.acquire().await.expect("seed queue semaphore closed")) } else { None };
+        let CreateSeedResponse { id } = self.post("https://ootrandomizer.com/api/v2/seed/create", Some(&[
+            ("key", &*self.api_key),
+            ("version", &*version.to_string_web(false).ok_or(Error::RandomSettings)?),
+            ("locked", if locked { "true" } else { "false" }),
+            ("passwordLock", if password_lock { "true" } else { "false" }),
+        ]), Some(&settings), is_mw.then_some(MULTIWORLD_RATE_LIMIT)).await?
+            .detailed_error_for_status().await?
+            .json_with_text_in_error().await?;
+        loop {
+            sleep(Duration::from_secs(1)).await;
+            let resp = self.get(
+                "https://ootrandomizer.com/api/v2/seed/status",
+                Some(&[("key", &*self.api_key), ("id", &*id.to_string())]),
+            ).await?;
+            if resp.status() == StatusCode::NO_CONTENT { continue }
+            resp.error_for_status_ref()?;
+            match resp.json_with_text_in_error::<SeedStatusResponse>().await?.status {
+                0 => continue, // still generating
+                1 => break, // generated success
+                2 => unreachable!(), // generated with link (not possible from API)
+                3 => return Err(Error::Upload), // failed to generate
+                n => return Err(Error::UnexpectedSeedStatus(n)),
+            }
+        }
+        let SeedDetailsResponse { settings_log } = self.get("https://ootrandomizer.com/api/v2/seed/details", Some(&[("key", &*self.api_key), ("id", &*id.to_string())])).await?
+            .detailed_error_for_status().await?
+            .json_with_text_in_error().await?;
+        Ok((id, settings_log.file_hash))
+    }
+
     pub(crate) async fn patch_file_stem(&self, seed_id: i64) -> Result<String, Error> {
         let patch_response = self.head("https://ootrandomizer.com/api/v2/seed/patch", Some(&[("key", &self.api_key), ("id", &seed_id.to_string())])).await?
             .detailed_error_for_status().await?;
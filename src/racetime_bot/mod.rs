@@ -1,6 +1,7 @@
 use {
     std::{
         io::prelude::*,
+        num::NonZeroUsize,
         process::Stdio,
         sync::atomic::{
             self,
@@ -31,12 +32,15 @@ use {
     serde_json::Value as Json,
     serenity::all::{
         CreateAllowedMentions,
+        CreateAttachment,
         CreateMessage,
     },
     tokio::{
         io::AsyncWriteExt as _,
         sync::{
             Notify,
+            Semaphore,
+            SemaphorePermit,
             mpsc,
         },
         time::timeout,
@@ -44,7 +48,10 @@ use {
     wheel::traits::AsyncCommandOutputExt as _,
     crate::{
         cal::Entrant,
-        config::ConfigRaceTime,
+        config::{
+            ConfigRaceTime,
+            ReconnectBehavior,
+        },
         discord_bot::FENHL,
         prelude::*,
     },
@@ -61,6 +68,43 @@ pub(crate) const CATEGORY: &str = "ootr";
 
 const OOTR_DISCORD_GUILD: GuildId = GuildId::new(274180765816848384);
 
+/// Maximum length of a racetime.gg chat message, in bytes.
+const RACETIME_MESSAGE_LENGTH_LIMIT: usize = 1000;
+
+/// Upper limit for stream delays set using `!streamdelay`.
+const MAX_STREAM_DELAY: Duration = Duration::from_secs(30 * 60);
+
+/// How long to wait before retrying a Triforce Blitz seed generation request after a server error. Doubled for each further attempt.
+const TFB_RETRY_BASE_DELAY: Duration = Duration::from_secs(2);
+/// Random extra delay added to or subtracted from [`TFB_RETRY_BASE_DELAY`] so rooms rolling at the same time don't retry in lockstep.
+const TFB_RETRY_JITTER: Duration = Duration::from_secs(1);
+
+/// How many times `!reroll` can be used in a single practice room.
+const MAX_REROLLS: u8 = 5;
+
+/// How many times to try creating a Mido's House Multiworld room for a team before asking them to create one manually.
+const MW_ROOM_ATTEMPTS: u32 = 3;
+/// How long to wait before retrying to create a Mido's House Multiworld room. Doubled for each further attempt.
+const MW_ROOM_RETRY_BASE_DELAY: Duration = Duration::from_secs(5);
+/// Minimum time between organizer notifications about failed seed rolls in the same race room.
+const ROLL_FAILURE_NOTIFICATION_COOLDOWN: Duration = Duration::from_secs(30 * 60);
+/// Sample errors included in organizer notifications are truncated to this many characters to stay within Discord's message length limit.
+const ROLL_FAILURE_SAMPLE_ERROR_LENGTH: usize = 1500;
+
+/// Returns the next time at or after `now` when triforceblitz.com publishes a new seed of the day, which happens daily at 20:00 UTC.
+fn next_tfb_sotd_rollover(now: DateTime<Utc>) -> DateTime<Utc> {
+    let rollover = now.date_naive().and_hms_opt(20, 0, 0).expect("failed to construct naive datetime at 20:00:00").and_utc();
+    if now < rollover { rollover } else { rollover + TimeDelta::days(1) }
+}
+
+/// The date of the Triforce Blitz seed of the day that should be available at `now`, i.e. the UTC date of the most recent rollover.
+fn current_tfb_sotd_date(now: DateTime<Utc>) -> NaiveDate {
+    (next_tfb_sotd_rollover(now) - TimeDelta::days(1)).date_naive()
+}
+
+/// How long to cache the Triforce Blitz seed of the day if triforceblitz.com hasn't published the current one yet.
+const TFB_SOTD_RETRY_INTERVAL: TimeDelta = TimeDelta::minutes(5);
+
 static RSL_SEQUENCE_ID: AtomicUsize = AtomicUsize::new(0);
 
 #[derive(Debug, thiserror::Error)]
@@ -82,6 +126,24 @@ pub(crate) enum ParseUserError {
     UrlNotFound,
 }
 
+/// Sends the given items separated by bullets, split into multiple messages to stay below racetime.gg's message length limit.
+async fn say_joined(ctx: &RaceContext<GlobalState>, items: impl IntoIterator<Item = String>) -> Result<(), Error> {
+    let mut msg = String::default();
+    for item in items {
+        if !msg.is_empty() && msg.len() + item.len() + " • ".len() > RACETIME_MESSAGE_LENGTH_LIMIT {
+            ctx.say(mem::take(&mut msg)).await?;
+        }
+        if !msg.is_empty() {
+            msg.push_str(" • ");
+        }
+        msg.push_str(&item);
+    }
+    if !msg.is_empty() {
+        ctx.say(msg).await?;
+    }
+    Ok(())
+}
+
 pub(crate) async fn parse_user(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, id_or_url: &str) -> Result<String, ParseUserError> {
     if let Ok(id) = id_or_url.parse() {
         return if let Some(user) = User::from_id(&mut **transaction, id).await? {
@@ -145,9 +207,80 @@ impl VersionedBranch {
             Self::Custom { .. } => None,
         }
     }
+
+    /// The GitHub user and branch name of this randomizer branch, if known.
+    fn github_branch(&self) -> Option<(&'static str, &'static str)> {
+        match self {
+            Self::Pinned(version) => Self::Latest(version.branch()).github_branch(),
+            Self::Latest(branch) => match branch {
+                rando::Branch::Dev => Some(("OoTRandomizer", "Dev")),
+                rando::Branch::DevFenhl => Some(("fenhl", "dev-fenhl")),
+                rando::Branch::DevR => Some(("Roman971", "Dev-R")),
+                rando::Branch::DevRob => Some(("rrealmuto", "Dev-Rob")),
+                _ => None,
+            },
+            Self::Custom { github_username, branch } => Some((*github_username, *branch)),
+        }
+    }
+
+    /// A link to the release notes of this randomizer branch, if known.
+    ///
+    /// For pinned versions, this links the release notes as of the version's release tag rather than the latest ones.
+    fn changelog_url(&self) -> Option<String> {
+        match self {
+            Self::Pinned(version) => {
+                let (github_username, _) = self.github_branch()?;
+                let tag = if let Some(supplementary) = version.supplementary() {
+                    format!("v{}-{supplementary}", version.base())
+                } else {
+                    format!("v{}", version.base())
+                };
+                Some(format!("https://github.com/{github_username}/OoT-Randomizer/blob/{tag}/CHANGELOG.md"))
+            }
+            Self::Latest(_) => {
+                let (github_username, branch) = self.github_branch()?;
+                Some(format!("https://github.com/{github_username}/OoT-Randomizer/blob/{branch}/CHANGELOG.md"))
+            }
+            // custom branches don't have release notes, so link the commit history instead
+            Self::Custom { github_username, branch } => Some(format!("https://github.com/{github_username}/OoT-Randomizer/commits/{branch}")),
+        }
+    }
+
+    /// A machine-readable description of this randomizer version, for the `/api/v1/rando-versions` endpoint.
+    pub(crate) fn to_json(&self) -> Json {
+        let github = self.github_branch().map(|(github_username, branch)| json!({
+            "username": github_username,
+            "branch": branch,
+        }));
+        match self {
+            Self::Pinned(version) => json!({
+                "type": "pinned",
+                "version": version.to_string(),
+                "base": version.base().to_string(),
+                "supplementary": version.supplementary(),
+                "github": github,
+            }),
+            Self::Latest(_) => json!({
+                "type": "latest",
+                "github": github,
+            }),
+            Self::Custom { .. } => json!({
+                "type": "custom",
+                "github": github,
+            }),
+        }
+    }
+
+    /// Whether this version of the randomizer has the `password_lock` setting.
+    fn supports_password_lock(&self) -> bool {
+        match self {
+            Self::Pinned(version) => *version.base() >= Version::new(8, 0, 0),
+            Self::Latest(_) | Self::Custom { .. } => true,
+        }
+    }
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub(crate) enum RslDevFenhlPreset {
     #[default]
     Fenhl,
@@ -175,30 +308,35 @@ impl FromStr for RslDevFenhlPreset {
     }
 }
 
+#[derive(Clone)]
 pub(crate) enum VersionedRslPreset {
     Xopar {
         version: Option<Version>,
+        /// A commit, tag, or branch of the RSL script to check out instead of the latest version. Only used if `version` is `None`.
+        git_ref: Option<String>,
         preset: rsl::Preset,
     },
     Fenhl {
         version: Option<(Version, u8)>,
+        /// A commit, tag, or branch of the RSL script to check out instead of the latest version. Only used if `version` is `None`.
+        git_ref: Option<String>,
         preset: RslDevFenhlPreset,
     },
 }
 
 impl VersionedRslPreset {
-    #[cfg(unix)] pub(crate) fn new_unversioned(branch: &str, preset: Option<&str>) -> Result<Self, ()> {
+    #[cfg(unix)] pub(crate) fn new_unversioned(branch: &str, git_ref: Option<String>, preset: Option<&str>) -> Result<Self, ()> {
         Ok(match branch {
-            "xopar" => Self::Xopar { version: None, preset: preset.map(rsl::Preset::from_str).transpose()?.unwrap_or_default() },
-            "fenhl" => Self::Fenhl { version: None, preset: preset.map(RslDevFenhlPreset::from_str).transpose()?.unwrap_or_default() },
+            "xopar" => Self::Xopar { version: None, git_ref, preset: preset.map(rsl::Preset::from_str).transpose()?.unwrap_or_default() },
+            "fenhl" => Self::Fenhl { version: None, git_ref, preset: preset.map(RslDevFenhlPreset::from_str).transpose()?.unwrap_or_default() },
             _ => return Err(()),
         })
     }
 
     #[cfg(unix)] pub(crate) fn new_versioned(version: rando::Version, preset: Option<&str>) -> Result<Self, ()> {
         Ok(match version.branch() {
-            rando::Branch::DevR | rando::Branch::DevRob => Self::Xopar { version: Some(version.base().clone()), preset: preset.map(rsl::Preset::from_str).transpose()?.unwrap_or_default() },
-            rando::Branch::DevFenhl => Self::Fenhl { version: Some((version.base().clone(), version.supplementary().unwrap())), preset: preset.map(RslDevFenhlPreset::from_str).transpose()?.unwrap_or_default() },
+            rando::Branch::DevR | rando::Branch::DevRob => Self::Xopar { version: Some(version.base().clone()), git_ref: None, preset: preset.map(rsl::Preset::from_str).transpose()?.unwrap_or_default() },
+            rando::Branch::DevFenhl => Self::Fenhl { version: Some((version.base().clone(), version.supplementary().unwrap())), git_ref: None, preset: preset.map(RslDevFenhlPreset::from_str).transpose()?.unwrap_or_default() },
             _ => return Err(()),
         })
     }
@@ -224,6 +362,13 @@ impl VersionedRslPreset {
         }
     }
 
+    fn git_ref(&self) -> Option<&str> {
+        match self {
+            Self::Xopar { git_ref, .. } => git_ref.as_deref(),
+            Self::Fenhl { git_ref, .. } => git_ref.as_deref(),
+        }
+    }
+
     fn script_path(&self) -> Result<Cow<'static, Path>, RollError> {
         Ok({
             #[cfg(unix)] {
@@ -254,27 +399,89 @@ impl VersionedRslPreset {
 ///    This is not an issue for seeds rolled locally, so the local generator will always be started immediately after the room is opened.
 ///
 /// How early we should start rolling seeds therefore depends on how long seed generation is expected to take, which depends on the settings.
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Sequence, sqlx::Type, FromFormField)]
+#[sqlx(type_name = "preroll_mode", rename_all = "lowercase")]
 pub(crate) enum PrerollMode {
     /// Do not preroll seeds.
+    #[field(value = "none")]
     None,
     /// Preroll seeds within the 5 minutes before the deadline.
+    #[field(value = "short")]
     Short,
     /// Start prerolling seeds between the time the room is opened and 15 minutes before the deadline.
+    #[field(value = "medium")]
     Medium,
     /// Always keep one seed in reserve until the end of the event. Fetch that seed or start rolling a new one immediately as the room is opened.
+    #[field(value = "long")]
     Long,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+impl PrerollMode {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Short => "short",
+            Self::Medium => "medium",
+            Self::Long => "long",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type, FromFormField)]
 #[cfg_attr(unix, derive(Protocol))]
+#[sqlx(type_name = "unlock_spoiler_log", rename_all = "lowercase")]
 pub(crate) enum UnlockSpoilerLog {
+    #[field(value = "now")]
     Now,
+    #[field(value = "progression")]
     Progression,
+    #[field(value = "after")]
     After,
+    /// Like [`UnlockSpoilerLog::After`], but the spoiler log is also sent to the event's organizers as soon as the seed is rolled.
+    #[field(value = "organizers")]
+    Organizers,
+    #[field(value = "never")]
     Never,
 }
 
+impl UnlockSpoilerLog {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Now => "now",
+            Self::Progression => "progression",
+            Self::After => "after",
+            Self::Organizers => "organizers",
+            Self::Never => "never",
+        }
+    }
+}
+
+/// One way to invoke `!seed` for a [`Goal`], see [`Goal::seed_presets`].
+#[derive(Serialize)]
+pub(crate) struct SeedPreset {
+    /// The arguments to pass to `!seed`, e.g. `base` or `multiworld <worldcount>`. Empty if `!seed` is used without arguments.
+    pub(crate) args: Cow<'static, str>,
+    /// An example of a full `!seed` command for presets with placeholder arguments.
+    pub(crate) example: Option<&'static str>,
+    pub(crate) description: &'static str,
+}
+
+impl SeedPreset {
+    fn new(args: &'static str, description: &'static str) -> Self {
+        Self { args: Cow::Borrowed(args), example: None, description }
+    }
+
+    fn with_example(mut self, example: &'static str) -> Self {
+        self.example = Some(example);
+        self
+    }
+
+    /// The first `!seed` argument selecting this preset, unless it's empty or a placeholder.
+    fn keyword(&self) -> Option<&str> {
+        self.args.split(' ').next().filter(|arg| !arg.is_empty() && !arg.starts_with('<'))
+    }
+}
+
 #[derive(Clone, Copy, Sequence)]
 #[cfg_attr(unix, derive(Protocol))]
 pub(crate) enum Goal {
@@ -304,43 +511,72 @@ pub(crate) enum Goal {
 #[error("this racetime.gg goal is not handled by Mido")]
 pub(crate) struct GoalFromStrError;
 
+/// Which events of a series a [`Goal`] is used for.
+#[derive(Clone, Copy)]
+enum GoalEvents {
+    All,
+    AllExcept(&'static str),
+    AnyOf(&'static [&'static str]),
+    Only(&'static str),
+    Prefix(&'static str),
+}
+
 impl Goal {
     pub(crate) fn for_event(series: Series, event: &str) -> Option<Self> {
         all::<Self>().find(|goal| goal.matches_event(series, event))
     }
 
-    fn from_race_data(race_data: &RaceData) -> Option<Self> {
+    /// User-opened Standard Ruleset rooms are only handled if `handle_user_opened_standard_rooms` is set, see [`Config::handle_user_opened_standard_rooms`].
+    fn from_race_data(race_data: &RaceData, handle_user_opened_standard_rooms: bool) -> Option<Self> {
         let Ok(bot_goal) = race_data.goal.name.parse::<Self>() else { return None };
         if race_data.goal.custom != bot_goal.is_custom() { return None }
-        if let (Goal::StandardRuleset, Some(_)) = (bot_goal, &race_data.opened_by) { return None }
+        if let (Goal::StandardRuleset, Some(_), false) = (bot_goal, &race_data.opened_by, handle_user_opened_standard_rooms) { return None }
         Some(bot_goal)
     }
 
-    fn matches_event(&self, series: Series, event: &str) -> bool {
+    /// The series and the events within it that this goal is used for.
+    fn events(&self) -> (Series, GoalEvents) {
         match self {
-            Self::Cc7 => series == Series::Standard && event == "7cc",
-            Self::CoOpS3 => series == Series::CoOp && event == "3",
-            Self::CopaDoBrasil => series == Series::CopaDoBrasil && event == "1",
-            Self::MixedPoolsS2 => series == Series::MixedPools && event == "2",
-            Self::MixedPoolsS3 => series == Series::MixedPools && event == "3",
-            Self::MultiworldS3 => series == Series::Multiworld && event == "3",
-            Self::MultiworldS4 => series == Series::Multiworld && event == "4",
-            Self::NineDaysOfSaws => series == Series::NineDaysOfSaws,
-            Self::Pic7 => series == Series::Pictionary && event == "7",
-            Self::PicRs2 => series == Series::Pictionary && event == "rs2",
-            Self::Rsl => series == Series::Rsl,
-            Self::Sgl2023 => series == Series::SpeedGaming && event.starts_with("2023"),
-            Self::Sgl2024 => series == Series::SpeedGaming && event.starts_with("2024"),
-            Self::SongsOfHope => series == Series::SongsOfHope && event == "1",
-            Self::StandardRuleset => series == Series::Standard && matches!(event, "w" | "8" | "8cc"),
-            Self::TournoiFrancoS3 => series == Series::TournoiFrancophone && event == "3",
-            Self::TournoiFrancoS4 => series == Series::TournoiFrancophone && event == "4",
-            Self::TriforceBlitz => series == Series::TriforceBlitz,
-            Self::TriforceBlitzProgressionSpoiler => false, // possible future tournament but no concrete plans
-            Self::WeTryToBeBetter => series == Series::WeTryToBeBetter && event == "1",
+            Self::Cc7 => (Series::Standard, GoalEvents::Only("7cc")),
+            Self::CoOpS3 => (Series::CoOp, GoalEvents::Only("3")),
+            Self::CopaDoBrasil => (Series::CopaDoBrasil, GoalEvents::Only("1")),
+            Self::MixedPoolsS2 => (Series::MixedPools, GoalEvents::Only("2")),
+            Self::MixedPoolsS3 => (Series::MixedPools, GoalEvents::Only("3")),
+            Self::MultiworldS3 => (Series::Multiworld, GoalEvents::Only("3")),
+            Self::MultiworldS4 => (Series::Multiworld, GoalEvents::Only("4")),
+            Self::NineDaysOfSaws => (Series::NineDaysOfSaws, GoalEvents::All),
+            Self::Pic7 => (Series::Pictionary, GoalEvents::Only("7")),
+            Self::PicRs2 => (Series::Pictionary, GoalEvents::Only("rs2")),
+            Self::Rsl => (Series::Rsl, GoalEvents::All),
+            Self::Sgl2023 => (Series::SpeedGaming, GoalEvents::Prefix("2023")),
+            Self::Sgl2024 => (Series::SpeedGaming, GoalEvents::Prefix("2024")),
+            Self::SongsOfHope => (Series::SongsOfHope, GoalEvents::Only("1")),
+            Self::StandardRuleset => (Series::Standard, GoalEvents::AnyOf(&["w", "8", "8cc"])),
+            Self::TournoiFrancoS3 => (Series::TournoiFrancophone, GoalEvents::Only("3")),
+            Self::TournoiFrancoS4 => (Series::TournoiFrancophone, GoalEvents::Only("4")),
+            Self::TriforceBlitz => (Series::TriforceBlitz, GoalEvents::AllExcept("progression")),
+            Self::TriforceBlitzProgressionSpoiler => (Series::TriforceBlitz, GoalEvents::Only("progression")),
+            Self::WeTryToBeBetter => (Series::WeTryToBeBetter, GoalEvents::Only("1")),
         }
     }
 
+    fn matches_event(&self, series: Series, event: &str) -> bool {
+        let (goal_series, goal_events) = self.events();
+        series == goal_series && match goal_events {
+            GoalEvents::All => true,
+            GoalEvents::AllExcept(excluded) => event != excluded,
+            GoalEvents::AnyOf(events) => events.contains(&event),
+            GoalEvents::Only(goal_event) => event == goal_event,
+            GoalEvents::Prefix(prefix) => event.starts_with(prefix),
+        }
+    }
+
+    /// The series and, if this goal is specific to one event, the event this goal is used for.
+    fn event(&self) -> (Series, Option<&'static str>) {
+        let (series, events) = self.events();
+        (series, if let GoalEvents::Only(event) = events { Some(event) } else { None })
+    }
+
     pub(crate) fn is_custom(&self) -> bool {
         match self {
             | Self::Rsl
@@ -393,7 +629,7 @@ impl Goal {
         }
     }
 
-    fn language(&self) -> Language {
+    pub(crate) fn language(&self) -> Language {
         match self {
             | Self::Cc7
             | Self::CoOpS3
@@ -409,7 +645,7 @@ impl Goal {
             | Self::Sgl2024
             | Self::SongsOfHope
             | Self::StandardRuleset
-            | Self::TournoiFrancoS4 //TODO change to bilingual English/French
+            | Self::TournoiFrancoS4 // bilingual, see is_bilingual
             | Self::TriforceBlitz
             | Self::TriforceBlitzProgressionSpoiler
                 => English,
@@ -421,6 +657,12 @@ impl Goal {
         }
     }
 
+    /// Whether messages in this goal's race rooms are posted in both English and French, in which case [`Goal::language`] is English.
+    pub(crate) fn is_bilingual(&self) -> bool {
+        // currently only draft events are bilingual, so the draft kind is the single source of truth
+        self.draft_kind().is_some_and(|draft_kind| draft_kind.is_bilingual())
+    }
+
     fn draft_kind(&self) -> Option<draft::Kind> {
         match self {
             Self::Cc7 => Some(draft::Kind::S7),
@@ -447,6 +689,66 @@ impl Goal {
         }
     }
 
+    /// Breaks applied when an official race room for this goal is opened. Can still be changed or disabled using `!breaks`.
+    fn default_breaks(&self) -> Option<Breaks> {
+        match self {
+            | Self::MultiworldS3
+            | Self::MultiworldS4
+                => Some(Breaks {
+                    duration: Duration::from_secs(5 * 60),
+                    interval: Duration::from_secs(2 * 60 * 60 + 30 * 60),
+                }),
+            | Self::Cc7
+            | Self::CoOpS3
+            | Self::CopaDoBrasil
+            | Self::MixedPoolsS2
+            | Self::MixedPoolsS3
+            | Self::NineDaysOfSaws
+            | Self::Pic7
+            | Self::PicRs2
+            | Self::Rsl
+            | Self::Sgl2023
+            | Self::Sgl2024
+            | Self::SongsOfHope
+            | Self::StandardRuleset
+            | Self::TournoiFrancoS3
+            | Self::TournoiFrancoS4
+            | Self::TriforceBlitz
+            | Self::TriforceBlitzProgressionSpoiler
+            | Self::WeTryToBeBetter
+                => None,
+        }
+    }
+
+    /// How long the bot keeps handling a finished race room while entrants still need to report their scores.
+    /// Can be overridden per event on the event configuration page.
+    fn default_score_wait(&self) -> Duration {
+        match self {
+            | Self::TriforceBlitz
+            | Self::TriforceBlitzProgressionSpoiler
+                => Duration::from_secs(60 * 60),
+            | Self::Cc7
+            | Self::CoOpS3
+            | Self::CopaDoBrasil
+            | Self::MixedPoolsS2
+            | Self::MixedPoolsS3
+            | Self::MultiworldS3
+            | Self::MultiworldS4
+            | Self::NineDaysOfSaws
+            | Self::Pic7
+            | Self::PicRs2
+            | Self::Rsl
+            | Self::Sgl2023
+            | Self::Sgl2024
+            | Self::SongsOfHope
+            | Self::StandardRuleset
+            | Self::TournoiFrancoS3
+            | Self::TournoiFrancoS4
+            | Self::WeTryToBeBetter
+                => Duration::default(),
+        }
+    }
+
     /// See the [`PrerollMode`] docs.
     pub(crate) fn preroll_seeds(&self) -> PrerollMode {
         match self {
@@ -456,7 +758,7 @@ impl Goal {
                 => PrerollMode::None,
             | Self::Cc7
             | Self::CoOpS3
-            | Self::StandardRuleset //TODO allow organizers to configure this for weeklies
+            | Self::StandardRuleset // can be overridden by weekly organizers, see Goal::preroll_mode
                 => PrerollMode::Short,
             | Self::CopaDoBrasil
             | Self::MultiworldS3
@@ -477,9 +779,49 @@ impl Goal {
         }
     }
 
-    pub(crate) fn unlock_spoiler_log(&self, official_race: bool, spoiler_seed: bool) -> UnlockSpoilerLog {
+    /// A rough estimate of how long it takes to generate a seed for this goal, used to let players know what to expect.
+    pub(crate) fn expected_gen_time(&self) -> Duration {
+        match self {
+            | Self::Sgl2023
+            | Self::Sgl2024
+            | Self::TriforceBlitz
+            | Self::TriforceBlitzProgressionSpoiler
+                => Duration::from_secs(60),
+            | Self::Cc7
+            | Self::CoOpS3
+            | Self::CopaDoBrasil
+            | Self::NineDaysOfSaws
+            | Self::Pic7
+            | Self::SongsOfHope
+            | Self::StandardRuleset
+            | Self::TournoiFrancoS3
+            | Self::TournoiFrancoS4
+            | Self::WeTryToBeBetter
+                => Duration::from_secs(2 * 60),
+            | Self::MultiworldS3
+            | Self::MultiworldS4
+                => Duration::from_secs(5 * 60),
+            // the random settings script may need multiple attempts to find settings that generate
+            | Self::PicRs2
+            | Self::Rsl
+                => Duration::from_secs(10 * 60),
+            | Self::MixedPoolsS2
+            | Self::MixedPoolsS3
+                => Duration::from_secs(15 * 60),
+        }
+    }
+
+    /// Like [`Goal::preroll_seeds`], but respects the event's configuration if any.
+    pub(crate) fn preroll_mode(&self, event: Option<&event::Data<'_>>) -> PrerollMode {
+        event.and_then(|event| event.preroll_mode).unwrap_or_else(|| self.preroll_seeds())
+    }
+
+    /// Respects the event's configuration if any, see [`event::Data::unlock_spoiler_log`].
+    pub(crate) fn unlock_spoiler_log(&self, event: Option<&event::Data<'_>>, official_race: bool, spoiler_seed: bool) -> UnlockSpoilerLog {
         if spoiler_seed {
             UnlockSpoilerLog::Now
+        } else if let Some(unlock_spoiler_log) = event.and_then(|event| event.unlock_spoiler_log) {
+            unlock_spoiler_log
         } else {
             match self {
                 | Self::Pic7
@@ -510,8 +852,13 @@ impl Goal {
         }
     }
 
-    pub(crate) fn rando_version(&self, #[allow(unused)] /* will be useful once weeklies are no longer on S8 settings */ event: Option<(Series, &str)>) -> VersionedBranch {
-        match self {
+    pub(crate) fn rando_version(&self, event: Option<&event::Data<'_>>) -> VersionedBranch {
+        self.try_rando_version(event).expect("randomizer version for this goal must be parsed from RSL script")
+    }
+
+    /// Returns `None` for random settings goals, whose randomizer version is determined by the RSL script.
+    pub(crate) fn try_rando_version(&self, event: Option<&event::Data<'_>>) -> Option<VersionedBranch> {
+        Some(match self {
             Self::Cc7 => VersionedBranch::Pinned(rando::Version::from_dev(8, 1, 0)),
             Self::CoOpS3 => VersionedBranch::Pinned(rando::Version::from_dev(8, 1, 0)),
             Self::CopaDoBrasil => VersionedBranch::Pinned(rando::Version::from_dev(7, 1, 143)),
@@ -524,14 +871,14 @@ impl Goal {
             Self::Sgl2023 => VersionedBranch::Latest(rando::Branch::Sgl2023),
             Self::Sgl2024 => VersionedBranch::Latest(rando::Branch::Sgl2024),
             Self::SongsOfHope => VersionedBranch::Pinned(rando::Version::from_dev(8, 1, 0)),
-            Self::StandardRuleset => VersionedBranch::Pinned(rando::Version::from_dev(8, 2, 0)), //TODO allow weekly organizers to configure this
+            Self::StandardRuleset => VersionedBranch::Pinned(event.and_then(|event| event.rando_version.clone()).unwrap_or_else(|| rando::Version::from_dev(8, 2, 0))),
             Self::TournoiFrancoS3 => VersionedBranch::Pinned(rando::Version::from_branch(rando::Branch::DevR, 7, 1, 143, 1)),
             Self::TournoiFrancoS4 => VersionedBranch::Pinned(rando::Version::from_branch(rando::Branch::DevRob, 8, 1, 45, 105)),
             Self::TriforceBlitz => VersionedBranch::Latest(rando::Branch::DevBlitz),
             Self::TriforceBlitzProgressionSpoiler => VersionedBranch::Latest(rando::Branch::DevBlitz),
             Self::WeTryToBeBetter => VersionedBranch::Latest(rando::Branch::Dev),
-            Self::PicRs2 | Self::Rsl => panic!("randomizer version for this goal must be parsed from RSL script"),
-        }
+            Self::PicRs2 | Self::Rsl => return None,
+        })
     }
 
     /// Only returns a value for goals that only have one possible set of settings.
@@ -561,6 +908,18 @@ impl Goal {
         }
     }
 
+    /// The settings that `!seed preview` compares the resolved settings against.
+    fn base_settings(&self) -> Option<serde_json::Map<String, Json>> {
+        match self.draft_kind() {
+            Some(draft::Kind::S7) => Some(s::resolve_s7_draft_settings(&draft::Picks::default())),
+            Some(draft::Kind::MultiworldS3) => Some(mw::resolve_s3_draft_settings(&draft::Picks::default())),
+            Some(draft::Kind::MultiworldS4) => Some(mw::resolve_s4_draft_settings(&draft::Picks::default())),
+            Some(draft::Kind::TournoiFrancoS3) => Some(fr::resolve_s3_draft_settings(&draft::Picks::default())),
+            Some(draft::Kind::TournoiFrancoS4) => Some(fr::resolve_s4_draft_settings(&draft::Picks::default())),
+            None => self.single_settings().ok(),
+        }
+    }
+
     pub(crate) fn should_create_rooms(&self) -> bool {
         match self {
             | Self::MixedPoolsS2
@@ -588,12 +947,13 @@ impl Goal {
         }
     }
 
-    async fn send_presets(&self, ctx: &RaceContext<GlobalState>) -> Result<(), Error> {
+    /// The ways `!seed` can be used for this goal, as listed by `!presets` and [`crate::api::goals`].
+    pub(crate) fn seed_presets(&self) -> Vec<SeedPreset> {
         match self {
             | Self::Pic7
-                => ctx.say("!seed: The settings used for the race").await?,
+                => vec![SeedPreset::new("", "The settings used for the race")],
             | Self::PicRs2
-                => ctx.say("!seed: The weights used for the race").await?,
+                => vec![SeedPreset::new("", "The weights used for the race")],
             | Self::CoOpS3
             | Self::CopaDoBrasil
             | Self::MixedPoolsS2
@@ -601,82 +961,117 @@ impl Goal {
             | Self::Sgl2023
             | Self::Sgl2024
             | Self::SongsOfHope
-                => ctx.say("!seed: The settings used for the tournament").await?,
+                => vec![SeedPreset::new("", "The settings used for the tournament")],
             | Self::WeTryToBeBetter
-                => ctx.say("!seed : Les settings utilisés pour le tournoi").await?,
-            Self::Cc7 => {
-                ctx.say("!seed base: The tournament's base settings.").await?;
-                ctx.say("!seed random: Simulate a settings draft with both players picking randomly. The settings are posted along with the seed.").await?;
-                ctx.say("!seed draft: Pick the settings here in the chat.").await?;
-                ctx.say("!seed <setting> <value> <setting> <value>... (e.g. !seed deku open camc off): Pick a set of draftable settings without doing a full draft. Use “!settings” for a list of available settings.").await?;
-            }
-            Self::MultiworldS3 => {
-                ctx.say("!seed base: The settings used for the qualifier and tiebreaker asyncs.").await?;
-                ctx.say("!seed random: Simulate a settings draft with both teams picking randomly. The settings are posted along with the seed.").await?;
-                ctx.say("!seed draft: Pick the settings here in the chat.").await?;
-                ctx.say("!seed <setting> <value> <setting> <value>... (e.g. !seed trials 2 wincon scrubs): Pick a set of draftable settings without doing a full draft. Use “!settings” for a list of available settings.").await?;
-            }
-            Self::MultiworldS4 => {
-                ctx.say("!seed base: The settings used for the qualifier and tiebreaker asyncs.").await?;
-                ctx.say("!seed random: Simulate a settings draft with both teams picking randomly. The settings are posted along with the seed.").await?;
-                ctx.say("!seed draft: Pick the settings here in the chat.").await?;
-                ctx.say("!seed <setting> <value> <setting> <value>... (e.g. !seed trials 2 gbk stones): Pick a set of draftable settings without doing a full draft. Use “!settings” for a list of available settings.").await?;
-            }
-            Self::NineDaysOfSaws => {
-                ctx.say("!seed day1: S6").await?;
-                ctx.say("!seed day2: Beginner").await?;
-                ctx.say("!seed day3: Advanced").await?;
-                ctx.say("!seed day4: S5 + one bonk KO").await?;
-                ctx.say("!seed day5: Beginner + mixed pools").await?;
-                ctx.say("!seed day6: Beginner 3-player multiworld").await?;
-                ctx.say("!seed day7: Beginner").await?;
-                ctx.say("!seed day8: S6 + dungeon ER").await?;
-                ctx.say("!seed day9: S6").await?;
-            }
-            Self::Rsl => for preset in all::<rsl::Preset>() {
-                ctx.say(format!("!seed{}: {}", match preset {
-                    rsl::Preset::League => String::default(),
-                    rsl::Preset::Multiworld => format!(" {} <worldcount>", preset.name()),
-                    _ => format!(" {}", preset.name()),
-                }, match preset {
+                => vec![SeedPreset::new("", "Les settings utilisés pour le tournoi")],
+            Self::Cc7 => vec![
+                SeedPreset::new("base", "The tournament's base settings."),
+                SeedPreset::new("random", "Simulate a settings draft with both players picking randomly. The settings are posted along with the seed."),
+                SeedPreset::new("random verbose", "Same as “random”, but also says how many options were considered for each ban and pick and how likely the chosen one was."),
+                SeedPreset::new("draft", "Pick the settings here in the chat."),
+                SeedPreset::new("<setting> <value> <setting> <value>...", "Pick a set of draftable settings without doing a full draft. Use “!settings” for a list of available settings.").with_example("!seed deku open camc off"),
+                SeedPreset::new("... +<setting> <value>", "Also change hint settings that aren't part of the draft: +hint_dist (balanced, strong, tournament, useless, very_strong, weekly) or +clearer_hints (on, off). Not available in official races.").with_example("!seed base +hint_dist strong"),
+            ],
+            Self::MultiworldS3 => vec![
+                SeedPreset::new("base", "The settings used for the qualifier and tiebreaker asyncs."),
+                SeedPreset::new("random", "Simulate a settings draft with both teams picking randomly. The settings are posted along with the seed."),
+                SeedPreset::new("draft", "Pick the settings here in the chat."),
+                SeedPreset::new("<setting> <value> <setting> <value>...", "Pick a set of draftable settings without doing a full draft. Use “!settings” for a list of available settings.").with_example("!seed trials 2 wincon scrubs"),
+                SeedPreset::new("... +<setting> <value>", "Also change hint settings that aren't part of the draft: +hint_dist (balanced, strong, tournament, useless, very_strong, weekly) or +clearer_hints (on, off). Not available in official races.").with_example("!seed base +hint_dist strong"),
+            ],
+            Self::MultiworldS4 => vec![
+                SeedPreset::new("base", "The settings used for the qualifier and tiebreaker asyncs."),
+                SeedPreset::new("random", "Simulate a settings draft with both teams picking randomly. The settings are posted along with the seed."),
+                SeedPreset::new("draft", "Pick the settings here in the chat."),
+                SeedPreset::new("<setting> <value> <setting> <value>...", "Pick a set of draftable settings without doing a full draft. Use “!settings” for a list of available settings.").with_example("!seed trials 2 gbk stones"),
+                SeedPreset::new("... +<setting> <value>", "Also change hint settings that aren't part of the draft: +hint_dist (balanced, strong, tournament, useless, very_strong, weekly) or +clearer_hints (on, off). Not available in official races.").with_example("!seed base +hint_dist strong"),
+            ],
+            Self::NineDaysOfSaws => vec![
+                SeedPreset::new("day1", "S6"),
+                SeedPreset::new("day2", "Beginner"),
+                SeedPreset::new("day3", "Advanced"),
+                SeedPreset::new("day4", "S5 + one bonk KO"),
+                SeedPreset::new("day5", "Beginner + mixed pools"),
+                SeedPreset::new("day6", "Beginner 3-player multiworld"),
+                SeedPreset::new("day7", "Beginner"),
+                SeedPreset::new("day8", "S6 + dungeon ER"),
+                SeedPreset::new("day9", "S6"),
+            ],
+            Self::Rsl => all::<rsl::Preset>().map(|preset| SeedPreset {
+                args: match preset {
+                    rsl::Preset::League => Cow::Borrowed(""),
+                    rsl::Preset::Multiworld => Cow::Owned(format!("{} <worldcount>", preset.name())),
+                    _ => Cow::Borrowed(preset.name()),
+                },
+                example: None,
+                description: match preset {
                     rsl::Preset::League => "official Random Settings League weights",
                     rsl::Preset::Beginner => "random settings for beginners, see https://zsr.link/mKzPO for details",
                     rsl::Preset::Intermediate => "a step between Beginner and League",
                     rsl::Preset::Ddr => "League but always normal damage and with cutscenes useful for tricks in the DDR ruleset",
                     rsl::Preset::CoOp => "weights tuned for co-op play",
                     rsl::Preset::Multiworld => "weights tuned for multiworld",
-                })).await?;
-            },
-            Self::StandardRuleset => ctx.say("!seed: The settings for season 8 of the main tournament (which are also the current weekly settings)").await?, //TODO per-event settings
+                },
+            }).collect(),
+            Self::StandardRuleset => vec![SeedPreset::new("", "The settings for season 8 of the main tournament (which are also the current weekly settings)")], //TODO per-event settings
+            Self::TournoiFrancoS3 => vec![
+                SeedPreset::new("base", "Settings de base."),
+                SeedPreset::new("random", "Simule en draft en sélectionnant des settings au hasard pour les deux joueurs. Les settings seront affichés avec la seed."),
+                SeedPreset::new("draft", "Vous fait effectuer un draft dans le chat."),
+                SeedPreset::new("<setting> <configuration> <setting> <configuration>...", "Créé une seed avec les settings que vous définissez. Tapez “!settings” pour obtenir la liste des settings.").with_example("!seed trials random bridge ad"),
+            ],
+            Self::TournoiFrancoS4 => vec![
+                SeedPreset::new("base", "The tournament's base settings / Settings de base."),
+                SeedPreset::new("random", "Simulate a settings draft with both players picking randomly. The settings are posted along with the seed. / Simule en draft en sélectionnant des settings au hasard pour les deux joueurs. Les settings seront affichés avec la seed."),
+                SeedPreset::new("draft", "Pick the settings here in the chat. / Vous fait effectuer un draft dans le chat."),
+                SeedPreset::new("<setting> <value> <setting> <value>...", "Pick a set of draftable settings without doing a full draft. Use “!settings” for a list of available settings. / Créé une seed avec les settings que vous définissez. Tapez “!settings” pour obtenir la liste des settings.").with_example("!seed trials random bridge ad"),
+            ],
+            Self::TriforceBlitz => vec![
+                SeedPreset::new("s3", "Triforce Blitz season 3 settings"),
+                SeedPreset::new("jr", "Jabu's Revenge"),
+                SeedPreset::new("s2", "Triforce Blitz season 2 settings"),
+                SeedPreset::new("daily", "Triforce Blitz Seed of the Day"),
+            ],
+            Self::TriforceBlitzProgressionSpoiler => vec![SeedPreset::new("", "The current settings for the mode")],
+        }
+    }
+
+    async fn send_presets(&self, ctx: &RaceContext<GlobalState>) -> Result<(), Error> {
+        let french = self.language() == French && !self.is_bilingual();
+        let separator = if french { " :" } else { ":" };
+        for SeedPreset { args, example, description } in self.seed_presets() {
+            ctx.say(match (&*args, example) {
+                ("", _) => format!("!seed{separator} {description}"),
+                (_, None) => format!("!seed {args}{separator} {description}"),
+                (_, Some(example)) if french => format!("!seed {args} ex : {example}{separator} {description}"),
+                (_, Some(example)) => format!("!seed {args} (e.g. {example}){separator} {description}"),
+            }).await?;
+        }
+        match self {
             Self::TournoiFrancoS3 => {
-                ctx.say("!seed base : Settings de base.").await?;
-                ctx.say("!seed random : Simule en draft en sélectionnant des settings au hasard pour les deux joueurs. Les settings seront affichés avec la seed.").await?;
-                ctx.say("!seed draft : Vous fait effectuer un draft dans le chat.").await?;
-                ctx.say("!seed <setting> <configuration> <setting> <configuration>... ex : !seed trials random bridge ad : Créé une seed avec les settings que vous définissez. Tapez “!settings” pour obtenir la liste des settings.").await?;
                 ctx.say("Utilisez “!seed random advanced” ou “!seed draft advanced” pour autoriser les settings difficiles.").await?;
-                ctx.say("Activez les donjons Master Quest en utilisant par exemple : “!seed base 6mq” ou “!seed draft advanced 12mq”").await?;
+                ctx.say("Activez les donjons Master Quest en utilisant par exemple : “!seed base 6mq” ou “!seed draft advanced 12mq”").await?;
             }
             Self::TournoiFrancoS4 => {
-                ctx.say("!seed base: The tournament's base settings / Settings de base.").await?;
-                ctx.say("!seed random: Simulate a settings draft with both players picking randomly. The settings are posted along with the seed. / Simule en draft en sélectionnant des settings au hasard pour les deux joueurs. Les settings seront affichés avec la seed.").await?;
-                ctx.say("!seed draft: Pick the settings here in the chat. / Vous fait effectuer un draft dans le chat.").await?;
-                ctx.say("!seed <setting> <value> <setting> <value>... (e.g. !seed trials random bridge ad): Pick a set of draftable settings without doing a full draft. Use “!settings” for a list of available settings. / Créé une seed avec les settings que vous définissez. Tapez “!settings” pour obtenir la liste des settings.").await?;
                 ctx.say("Use “!seed random advanced” or “!seed draft advanced” to allow advanced settings. / Utilisez “!seed random advanced” ou “!seed draft advanced” pour autoriser les settings difficiles.").await?;
-                ctx.say("Enable Master Quest using e.g. “!seed base 6mq” or “!seed draft advanced 12mq” / Activez les donjons Master Quest en utilisant par exemple : “!seed base 6mq” ou “!seed draft advanced 12mq”").await?;
+                ctx.say("Enable Master Quest using e.g. “!seed base 6mq” or “!seed draft advanced 12mq” / Activez les donjons Master Quest en utilisant par exemple : “!seed base 6mq” ou “!seed draft advanced 12mq”").await?;
             }
-            Self::TriforceBlitz => {
-                ctx.say("!seed s3: Triforce Blitz season 3 settings").await?;
-                ctx.say("!seed jr: Jabu's Revenge").await?;
-                ctx.say("!seed s2: Triforce Blitz season 2 settings").await?;
-                ctx.say("!seed daily: Triforce Blitz Seed of the Day").await?;
-            }
-            Self::TriforceBlitzProgressionSpoiler => ctx.say("!seed: The current settings for the mode").await?,
+            _ => {}
         }
         Ok(())
     }
 
-    pub(crate) async fn parse_seed_command(&self, transaction: &mut Transaction<'_, Postgres>, global_state: &GlobalState, is_official: bool, spoiler_seed: bool, args: &[String]) -> Result<SeedCommandParseResult, Error> {
-        let unlock_spoiler_log = self.unlock_spoiler_log(is_official, spoiler_seed);
+    pub(crate) async fn parse_seed_command(&self, transaction: &mut Transaction<'_, Postgres>, global_state: &GlobalState, event: Option<&event::Data<'_>>, is_official: bool, spoiler_seed: bool, args: &[String]) -> Result<SeedCommandParseResult, Error> {
+        let unlock_spoiler_log = self.unlock_spoiler_log(event, is_official, spoiler_seed);
+        if let Some(arg) = args.first() {
+            // for goals whose presets are all selected by keyword, only accept the presets listed by !presets
+            let presets = self.seed_presets();
+            if presets.iter().any(|preset| preset.keyword().is_some())
+            && presets.iter().all(|preset| preset.keyword().is_some() || preset.args.is_empty())
+            && !presets.iter().any(|preset| preset.keyword() == Some(arg)) {
+                return Ok(SeedCommandParseResult::SendPresets { language: self.language(), msg: if let French = self.language() { "je ne reconnais pas ce preset" } else { "I don't recognize that preset" } })
+            }
+        }
         Ok(match self {
             | Self::CoOpS3
             | Self::CopaDoBrasil
@@ -730,24 +1125,43 @@ impl Goal {
                             article, description,
                         }
                     } else {
-                        SeedCommandParseResult::Regular { settings: self.single_settings().expect("goal has no single settings"), unlock_spoiler_log, language: self.language(), article, description }
+                        SeedCommandParseResult::Regular { settings: self.single_settings().expect("goal has no single settings"), unlock_spoiler_log, language: self.language(), article, description, notes: Vec::default() }
                     }
                 }
             Self::Cc7 => {
-                let settings = match args {
+                let mut notes = Vec::default();
+                let (extra_settings, args) = match ExtraSettings::extract(is_official, args) {
+                    Ok(extracted) => extracted,
+                    Err(result) => return Ok(result),
+                };
+                let settings = match &*args {
                     [] => return Ok(SeedCommandParseResult::SendPresets { language: English, msg: "the preset is required" }),
                     [arg] if arg == "base" => HashMap::default(),
                     [arg] if arg == "random" => Draft {
                         high_seed: Id::dummy(), // Draft::complete_randomly doesn't check for active team
                         went_first: None,
                         skipped_bans: 0,
+                        chosen_by: HashMap::default(),
                         settings: HashMap::default(),
                     }.complete_randomly(draft::Kind::S7).await.to_racetime()?,
+                    [arg, verbose] if arg == "random" && verbose == "verbose" => {
+                        let (settings, log) = Draft {
+                            high_seed: Id::dummy(), // Draft::complete_randomly doesn't check for active team
+                            went_first: None,
+                            skipped_bans: 0,
+                            chosen_by: HashMap::default(),
+                            settings: HashMap::default(),
+                        }.complete_randomly_verbose(draft::Kind::S7).await.to_racetime()?;
+                        notes = log;
+                        settings
+                    }
+                    [arg] if arg == "draft" && !extra_settings.is_empty() => return Ok(SeedCommandParseResult::Error { language: English, msg: "additional settings can't be combined with a settings draft.".into() }),
                     [arg] if arg == "draft" => return Ok(SeedCommandParseResult::StartDraft {
                         new_state: Draft {
                             high_seed: Id::dummy(), // racetime.gg bot doesn't check for active team
                             went_first: None,
                             skipped_bans: 0,
+                            chosen_by: HashMap::default(),
                             settings: HashMap::default(),
                         },
                         unlock_spoiler_log,
@@ -781,23 +1195,33 @@ impl Goal {
                         }
                     }
                 };
-                SeedCommandParseResult::Regular { settings: s::resolve_s7_draft_settings(&settings), unlock_spoiler_log, language: English, article: "a", description: format!("seed with {}", s::display_s7_draft_picks(&settings)) }
+                let mut resolved = s::resolve_s7_draft_settings(&settings);
+                let mut description = format!("seed with {}", s::display_s7_draft_picks(&settings));
+                extra_settings.apply(&mut resolved, &mut description);
+                SeedCommandParseResult::Regular { settings: resolved, unlock_spoiler_log, language: English, article: "a", description, notes }
             }
             Self::MultiworldS3 => {
-                let settings = match args {
+                let (extra_settings, args) = match ExtraSettings::extract(is_official, args) {
+                    Ok(extracted) => extracted,
+                    Err(result) => return Ok(result),
+                };
+                let settings = match &*args {
                     [] => return Ok(SeedCommandParseResult::SendPresets { language: English, msg: "the preset is required" }),
                     [arg] if arg == "base" => HashMap::default(),
                     [arg] if arg == "random" => Draft {
                         high_seed: Id::dummy(), // Draft::complete_randomly doesn't check for active team
                         went_first: None,
                         skipped_bans: 0,
+                        chosen_by: HashMap::default(),
                         settings: HashMap::default(),
                     }.complete_randomly(draft::Kind::MultiworldS3).await.to_racetime()?,
+                    [arg] if arg == "draft" && !extra_settings.is_empty() => return Ok(SeedCommandParseResult::Error { language: English, msg: "additional settings can't be combined with a settings draft.".into() }),
                     [arg] if arg == "draft" => return Ok(SeedCommandParseResult::StartDraft {
                         new_state: Draft {
                             high_seed: Id::dummy(), // racetime.gg bot doesn't check for active team
                             went_first: None,
                             skipped_bans: 0,
+                            chosen_by: HashMap::default(),
                             settings: HashMap::default(),
                         },
                         unlock_spoiler_log,
@@ -831,23 +1255,33 @@ impl Goal {
                         }
                     }
                 };
-                SeedCommandParseResult::Regular { settings: mw::resolve_s3_draft_settings(&settings), unlock_spoiler_log, language: English, article: "a", description: format!("seed with {}", mw::display_s3_draft_picks(&settings)) }
+                let mut resolved = mw::resolve_s3_draft_settings(&settings);
+                let mut description = format!("seed with {}", mw::display_s3_draft_picks(&settings));
+                extra_settings.apply(&mut resolved, &mut description);
+                SeedCommandParseResult::Regular { settings: resolved, unlock_spoiler_log, language: English, article: "a", description, notes: Vec::default() }
             }
             Self::MultiworldS4 => {
-                let settings = match args {
+                let (extra_settings, args) = match ExtraSettings::extract(is_official, args) {
+                    Ok(extracted) => extracted,
+                    Err(result) => return Ok(result),
+                };
+                let settings = match &*args {
                     [] => return Ok(SeedCommandParseResult::SendPresets { language: English, msg: "the preset is required" }),
                     [arg] if arg == "base" => HashMap::default(),
                     [arg] if arg == "random" => Draft {
                         high_seed: Id::dummy(), // Draft::complete_randomly doesn't check for active team
                         went_first: None,
                         skipped_bans: 0,
+                        chosen_by: HashMap::default(),
                         settings: HashMap::default(),
                     }.complete_randomly(draft::Kind::MultiworldS4).await.to_racetime()?,
+                    [arg] if arg == "draft" && !extra_settings.is_empty() => return Ok(SeedCommandParseResult::Error { language: English, msg: "additional settings can't be combined with a settings draft.".into() }),
                     [arg] if arg == "draft" => return Ok(SeedCommandParseResult::StartDraft {
                         new_state: Draft {
                             high_seed: Id::dummy(), // racetime.gg bot doesn't check for active team
                             went_first: None,
                             skipped_bans: 0,
+                            chosen_by: HashMap::default(),
                             settings: HashMap::default(),
                         },
                         unlock_spoiler_log,
@@ -881,7 +1315,10 @@ impl Goal {
                         }
                     }
                 };
-                SeedCommandParseResult::Regular { settings: mw::resolve_s4_draft_settings(&settings), unlock_spoiler_log, language: English, article: "a", description: format!("seed with {}", mw::display_s4_draft_picks(&settings)) }
+                let mut resolved = mw::resolve_s4_draft_settings(&settings);
+                let mut description = format!("seed with {}", mw::display_s4_draft_picks(&settings));
+                extra_settings.apply(&mut resolved, &mut description);
+                SeedCommandParseResult::Regular { settings: resolved, unlock_spoiler_log, language: English, article: "a", description, notes: Vec::default() }
             }
             Self::NineDaysOfSaws => match args {
                 [] => return Ok(SeedCommandParseResult::SendPresets { language: English, msg: "the preset is required" }),
@@ -973,7 +1410,7 @@ impl Goal {
                     _ => None,
                 } {
                     settings.insert(format!("user_message"), json!(format!("9 Days of SAWS: day {}", &arg[3..])));
-                    SeedCommandParseResult::Regular { settings, unlock_spoiler_log, language: English, article: "a", description: format!("{description} seed") }
+                    SeedCommandParseResult::Regular { settings, unlock_spoiler_log, language: English, article: "a", description: format!("{description} seed"), notes: Vec::default() }
                 } else {
                     SeedCommandParseResult::SendPresets { language: English, msg: "I don't recognize that preset" }
                 },
@@ -981,6 +1418,7 @@ impl Goal {
             }
             Self::PicRs2 => SeedCommandParseResult::Rsl { preset: VersionedRslPreset::Fenhl {
                 version: Some((Version::new(2, 3, 8), 10)),
+                git_ref: None,
                 preset: RslDevFenhlPreset::Pictionary,
             }, world_count: 1, unlock_spoiler_log, language: English, article: "a", description: format!("seed") },
             Self::Rsl => {
@@ -1024,7 +1462,7 @@ impl Goal {
                     rsl::Preset::CoOp => ("a", format!("random settings co-op seed")),
                     rsl::Preset::Multiworld => ("a", format!("random settings multiworld seed for {world_count} players")),
                 };
-                SeedCommandParseResult::Rsl { preset: VersionedRslPreset::Xopar { version: None, preset }, world_count, unlock_spoiler_log, language: English, article, description }
+                SeedCommandParseResult::Rsl { preset: VersionedRslPreset::Xopar { version: None, git_ref: event.and_then(|event| event.rsl_ref.clone()), preset }, world_count, unlock_spoiler_log, language: English, article, description }
             }
             Self::TournoiFrancoS3 | Self::TournoiFrancoS4 => {
                 let all_settings = match self {
@@ -1051,6 +1489,7 @@ impl Goal {
                         high_seed: Id::dummy(), // Draft::complete_randomly doesn't check for active team
                         went_first: None,
                         skipped_bans: 0,
+                        chosen_by: HashMap::default(),
                         settings: collect![as HashMap<_, _>:
                             Cow::Borrowed("hard_settings_ok") => Cow::Borrowed(if hard_settings_ok { "ok" } else { "no" }),
                             Cow::Borrowed("mq_ok") => Cow::Borrowed(if mq_dungeons_count.is_some() { "ok" } else { "no" }),
@@ -1062,6 +1501,7 @@ impl Goal {
                             high_seed: Id::dummy(), // racetime.gg bot doesn't check for active team
                             went_first: None,
                             skipped_bans: 0,
+                            chosen_by: HashMap::default(),
                             settings: collect![as HashMap<_, _>:
                                 Cow::Borrowed("hard_settings_ok") => Cow::Borrowed(if hard_settings_ok { "ok" } else { "no" }),
                                 Cow::Borrowed("mq_ok") => Cow::Borrowed(if mq_dungeons_count.is_some() { "ok" } else { "no" }),
@@ -1094,6 +1534,9 @@ impl Goal {
                         }
                         if tuples.into_buffer().next().is_some() {
                             return Ok(SeedCommandParseResult::SendSettings { language: French, msg: "vous devez associer un setting avec une configuration.".into() })
+                        } else if settings.get("mixed-dungeons").is_some_and(|mixed_dungeons| mixed_dungeons == "mixed") && !fr::can_mix_dungeons(&settings) {
+                            // consistent with live drafts, which only offer the mixed dungeons choice in this case
+                            return Ok(SeedCommandParseResult::Error { language: French, msg: "dungeon-er mixed nécessite aussi mixed-er on.".into() })
                         } else {
                             settings.insert(Cow::Borrowed("mq_dungeons_count"), Cow::Owned(mq_dungeons_count.unwrap_or_default().to_string()));
                             settings
@@ -1110,34 +1553,45 @@ impl Goal {
                     language: self.language(),
                     article: if let French = self.language() { "une" } else { "a" },
                     description: format!("seed {} {}", if let French = self.language() { "avec" } else { "with" }, fr::display_draft_picks(self.language(), all_settings, &settings)),
+                    notes: Vec::default(),
                 }
             }
             Self::TriforceBlitz => match args {
                 [] => SeedCommandParseResult::SendPresets { language: English, msg: "the preset is required" },
                 [arg] if arg == "daily" => {
-                    let (date, ordinal, file_hash) = {
-                        let response = global_state.http_client
-                            .get("https://www.triforceblitz.com/seed/daily/all")
-                            .send().await?
-                            .detailed_error_for_status().await.to_racetime()?;
-                        let response_body = response.text().await?;
-                        let latest = kuchiki::parse_html().one(response_body)
-                            .select_first("main > section > div > div").map_err(|()| RollError::TfbHtml).to_racetime()?;
-                        let latest = latest.as_node();
-                        let a = latest.select_first("a").map_err(|()| RollError::TfbHtml).to_racetime()?;
-                        let a_attrs = a.attributes.borrow();
-                        let href = a_attrs.get("href").ok_or(RollError::TfbHtml).to_racetime()?;
-                        let (_, ordinal) = regex_captures!("^/seed/daily/([0-9]+)$", href).ok_or(RollError::TfbHtml).to_racetime()?;
-                        let ordinal = ordinal.parse().to_racetime()?;
-                        let date = NaiveDate::parse_from_str(&a.text_contents(), "%B %-d, %Y").to_racetime()?;
-                        let file_hash = latest.select_first(".hash-icons").map_err(|()| RollError::TfbHtml).to_racetime()?
-                            .as_node()
-                            .children()
-                            .filter_map(NodeRef::into_element_ref)
-                            .filter_map(|elt| elt.attributes.borrow().get("title").and_then(|title| title.parse().ok()))
-                            .collect_vec()
-                            .try_into().map_err(|_| RollError::TfbHtml).to_racetime()?;
-                        (date, ordinal, file_hash)
+                    let cached = lock!(tfb_sotd_cache = global_state.tfb_sotd_cache; tfb_sotd_cache.filter(|&(expires_at, _)| Utc::now() < expires_at).map(|(_, sotd)| sotd));
+                    let (date, ordinal, file_hash) = if let Some(sotd) = cached {
+                        sotd
+                    } else {
+                        let sotd = {
+                            let response = global_state.http_client
+                                .get("https://www.triforceblitz.com/seed/daily/all")
+                                .send().await?
+                                .detailed_error_for_status().await.to_racetime()?;
+                            let response_body = response.text().await?;
+                            let latest = kuchiki::parse_html().one(response_body)
+                                .select_first("main > section > div > div").map_err(|()| RollError::TfbHtml).to_racetime()?;
+                            let latest = latest.as_node();
+                            let a = latest.select_first("a").map_err(|()| RollError::TfbHtml).to_racetime()?;
+                            let a_attrs = a.attributes.borrow();
+                            let href = a_attrs.get("href").ok_or(RollError::TfbHtml).to_racetime()?;
+                            let (_, ordinal) = regex_captures!("^/seed/daily/([0-9]+)$", href).ok_or(RollError::TfbHtml).to_racetime()?;
+                            let ordinal = ordinal.parse().to_racetime()?;
+                            let date = NaiveDate::parse_from_str(&a.text_contents(), "%B %-d, %Y").to_racetime()?;
+                            let file_hash = latest.select_first(".hash-icons").map_err(|()| RollError::TfbHtml).to_racetime()?
+                                .as_node()
+                                .children()
+                                .filter_map(NodeRef::into_element_ref)
+                                .filter_map(|elt| elt.attributes.borrow().get("title").and_then(|title| title.parse().ok()))
+                                .collect_vec()
+                                .try_into().map_err(|_| RollError::TfbHtml).to_racetime()?;
+                            (date, ordinal, file_hash)
+                        };
+                        let now = Utc::now();
+                        // right after the rollover, the website may still list yesterday's seed, so check again soon instead of keeping it until the next rollover
+                        let expires_at = if sotd.0 >= current_tfb_sotd_date(now) { next_tfb_sotd_rollover(now) } else { now + TFB_SOTD_RETRY_INTERVAL };
+                        lock!(tfb_sotd_cache = global_state.tfb_sotd_cache; *tfb_sotd_cache = Some((expires_at, sotd)));
+                        sotd
                     };
                     SeedCommandParseResult::QueueExisting { data: seed::Data {
                         file_hash: Some(file_hash),
@@ -1155,6 +1609,63 @@ impl Goal {
     }
 }
 
+/// Non-draft settings that can be changed on top of a draft in unofficial races, using `!seed` arguments prefixed with `+`, e.g. `+hint_dist strong`.
+///
+/// Only hint settings are allowed since they don't affect the logic of the seed.
+const EXTRA_SETTINGS: [(&str, &[&str]); 2] = [
+    ("clearer_hints", &["on", "off"]),
+    ("hint_dist", &["balanced", "strong", "tournament", "useless", "very_strong", "weekly"]),
+];
+
+/// Non-draft settings parsed from `+`-prefixed `!seed` arguments, see [`EXTRA_SETTINGS`].
+#[derive(Default)]
+struct ExtraSettings {
+    settings: serde_json::Map<String, Json>,
+    descriptions: Vec<String>,
+}
+
+impl ExtraSettings {
+    /// Removes the `+<setting> <value>` pairs from the `!seed` arguments and returns them along with the remaining arguments, which can then be matched against the presets.
+    fn extract(is_official: bool, args: &[String]) -> Result<(Self, Vec<String>), SeedCommandParseResult> {
+        let mut extra_settings = Self::default();
+        let mut remaining = Vec::with_capacity(args.len());
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if let Some(setting) = arg.strip_prefix('+') {
+                let Some(value) = args.next() else {
+                    return Err(SeedCommandParseResult::SendSettings { language: English, msg: "you need to pair each setting with a value.".into() })
+                };
+                if is_official {
+                    return Err(SeedCommandParseResult::Error { language: English, msg: "additional settings can't be changed in official races to keep the tournament fair.".into() })
+                }
+                let Some(&(name, values)) = EXTRA_SETTINGS.iter().find(|&&(name, _)| name == setting) else {
+                    return Err(SeedCommandParseResult::Error { language: English, msg: format!("I don't recognize that additional setting. Use one of the following: {}", EXTRA_SETTINGS.iter().map(|&(name, _)| format!("+{name}")).join(", ")).into() })
+                };
+                if !values.contains(&&**value) {
+                    return Err(SeedCommandParseResult::Error { language: English, msg: format!("I don't recognize that value for the +{name} setting. Use {}", values.join(" or ")).into() })
+                }
+                extra_settings.settings.insert(name.to_owned(), if name == "clearer_hints" { json!(value == "on") } else { json!(value) });
+                extra_settings.descriptions.push(format!("{arg} {value}"));
+            } else {
+                remaining.push(arg.clone());
+            }
+        }
+        Ok((extra_settings, remaining))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.settings.is_empty()
+    }
+
+    /// Merges these settings into the resolved settings of a preset or draft and lists them in the seed description.
+    fn apply(self, resolved: &mut serde_json::Map<String, Json>, description: &mut String) {
+        resolved.extend(self.settings);
+        if !self.descriptions.is_empty() {
+            description.push_str(&format!(" ({})", self.descriptions.join(", ")));
+        }
+    }
+}
+
 pub(crate) enum SeedCommandParseResult {
     Regular {
         settings: serde_json::Map<String, Json>,
@@ -1162,6 +1673,8 @@ pub(crate) enum SeedCommandParseResult {
         language: Language,
         article: &'static str,
         description: String,
+        /// Messages to post before rolling the seed, such as how randomly drafted settings were chosen.
+        notes: Vec<String>,
     },
     Rsl {
         preset: VersionedRslPreset,
@@ -1214,10 +1727,21 @@ impl FromStr for Goal {
 pub(crate) struct CleanShutdown {
     pub(crate) requested: bool,
     pub(crate) block_new: bool,
-    pub(crate) open_rooms: HashSet<String>,
+    /// Rooms currently handled by this instance, keyed by room URL.
+    pub(crate) open_rooms: HashMap<String, OpenRoom>,
     pub(crate) notifier: Arc<Notify>,
 }
 
+#[derive(Clone)]
+pub(crate) struct OpenRoom {
+    pub(crate) goal: Goal,
+    pub(crate) unlisted: bool,
+    /// Set once the room's [`Handler`] has looked up the race in the database.
+    pub(crate) official: bool,
+    /// Set once the room's handler task has been spawned, used to force-stop a stuck handler via the UNIX socket.
+    pub(crate) abort_handle: Option<tokio::task::AbortHandle>,
+}
+
 impl CleanShutdown {
     fn should_handle_new(&self) -> bool {
         !self.requested || !self.block_new && !self.open_rooms.is_empty()
@@ -1228,6 +1752,10 @@ impl CleanShutdown {
 pub(crate) struct SeedMetadata {
     pub(crate) locked_spoiler_log_path: Option<String>,
     pub(crate) progression_spoiler: bool,
+    /// The randomizer commit the seed was rolled from, if it was rolled locally from an unpinned branch.
+    pub(crate) rando_commit: Option<String>,
+    /// When the seed was rolled, if it was rolled by this instance.
+    pub(crate) rolled_at: Option<DateTime<Utc>>,
 }
 
 pub(crate) struct GlobalState {
@@ -1235,6 +1763,9 @@ pub(crate) struct GlobalState {
     new_room_lock: Arc<Mutex<()>>,
     host_info: racetime::HostInfo,
     racetime_config: ConfigRaceTime,
+    reconnect_behavior: ReconnectBehavior,
+    /// State of rooms handled by this process, kept so it can be restored if the connection to the room drops.
+    room_snapshots: Mutex<HashMap<String, RoomSnapshot>>,
     extra_room_tx: Arc<RwLock<mpsc::Sender<String>>>,
     pub(crate) db_pool: PgPool,
     pub(crate) http_client: reqwest::Client,
@@ -1245,12 +1776,46 @@ pub(crate) struct GlobalState {
     clean_shutdown: Arc<Mutex<CleanShutdown>>,
     seed_cache_tx: watch::Sender<()>,
     seed_metadata: Arc<RwLock<HashMap<String, SeedMetadata>>>,
+    /// Limits how many seeds are rolled locally at the same time to avoid overloading the server when many rooms open at once.
+    local_seed_rollers: Semaphore,
+    rsl_web_plando_attempts: u8,
+    fpa_entrant_threshold: usize,
+    handle_user_opened_standard_rooms: bool,
+    /// The number of seeds currently being rolled for race rooms, including ones waiting in a queue.
+    rolling_seeds: AtomicUsize,
+    /// When organizers were last notified about a failed seed roll in each race room, to avoid spamming them with repeated failures.
+    roll_failure_notifications: Mutex<HashMap<String, Instant>>,
+    /// The most recently scraped Triforce Blitz seed of the day as `(expires_at, (date, ordinal, file_hash))`, valid until the next daily rollover.
+    tfb_sotd_cache: Mutex<Option<(DateTime<Utc>, (NaiveDate, u64, [HashIcon; 5]))>>,
+    /// Held while an unversioned RSL checkout is in use. Rolls pinned to a git ref hold it exclusively since they reset the checkout to a different commit.
+    rsl_checkout: RwLock<()>,
+}
+
+/// Keeps [`GlobalState::rolling_seeds`] up to date, including when a seed roll is cancelled.
+struct RollingSeed(Arc<GlobalState>);
+
+impl RollingSeed {
+    fn new(global_state: Arc<GlobalState>) -> Self {
+        global_state.rolling_seeds.fetch_add(1, atomic::Ordering::Relaxed);
+        Self(global_state)
+    }
+}
+
+impl Drop for RollingSeed {
+    fn drop(&mut self) {
+        self.0.rolling_seeds.fetch_sub(1, atomic::Ordering::Relaxed);
+    }
 }
 
 impl GlobalState {
     pub(crate) async fn new(
         new_room_lock: Arc<Mutex<()>>,
         racetime_config: ConfigRaceTime,
+        reconnect_behavior: ReconnectBehavior,
+        local_seed_rollers: NonZeroUsize,
+        rsl_web_plando_attempts: u8,
+        fpa_entrant_threshold: usize,
+        handle_user_opened_standard_rooms: bool,
         extra_room_tx: Arc<RwLock<mpsc::Sender<String>>>,
         db_pool: PgPool,
         http_client: reqwest::Client,
@@ -1268,19 +1833,28 @@ impl GlobalState {
                 ..racetime::HostInfo::default()
             },
             ootr_api_client: ootr_web::ApiClient::new(http_client.clone(), ootr_api_key, ootr_api_key_encryption),
-            new_room_lock, racetime_config, extra_room_tx, db_pool, http_client, startgg_token, discord_ctx, clean_shutdown, seed_cache_tx, seed_metadata,
+            room_snapshots: Mutex::default(),
+            local_seed_rollers: Semaphore::new(local_seed_rollers.get()),
+            rolling_seeds: AtomicUsize::default(),
+            roll_failure_notifications: Mutex::default(),
+            tfb_sotd_cache: Mutex::default(),
+            rsl_checkout: RwLock::default(),
+            new_room_lock, racetime_config, reconnect_behavior, rsl_web_plando_attempts, fpa_entrant_threshold, handle_user_opened_standard_rooms, extra_room_tx, db_pool, http_client, startgg_token, discord_ctx, clean_shutdown, seed_cache_tx, seed_metadata,
         }
     }
 
-    pub(crate) fn roll_seed(self: Arc<Self>, preroll: PrerollMode, allow_web: bool, delay_until: Option<DateTime<Utc>>, version: VersionedBranch, mut settings: serde_json::Map<String, Json>, unlock_spoiler_log: UnlockSpoilerLog) -> mpsc::Receiver<SeedRollUpdate> {
-        let world_count = settings.get("world_count").map_or(1, |world_count| world_count.as_u64().expect("world_count setting wasn't valid u64").try_into().expect("too many worlds"));
-        let password_lock = settings.get("password_lock").map_or(false, |password_lock| password_lock.as_bool().expect("password_lock setting wasn't a Boolean"));
-        settings.insert(format!("create_spoiler"), json!(match unlock_spoiler_log {
-            UnlockSpoilerLog::Now | UnlockSpoilerLog::Progression | UnlockSpoilerLog::After => true,
-            UnlockSpoilerLog::Never => password_lock, // spoiler log needs to be generated so the backend can read the password
-        }));
+    pub(crate) fn roll_seed(self: Arc<Self>, preroll: PrerollMode, allow_web: bool, is_official: bool, delay_until: Option<DateTime<Utc>>, version: VersionedBranch, mut settings: serde_json::Map<String, Json>, unlock_spoiler_log: UnlockSpoilerLog) -> mpsc::Receiver<SeedRollUpdate> {
+        let world_count = world_count(&settings);
+        insert_create_spoiler(&mut settings, unlock_spoiler_log);
         let (update_tx, update_rx) = mpsc::channel(128);
-        tokio::spawn(async move {
+        tokio::spawn(until_closed(update_tx.clone(), async move {
+            let world_count = match world_count {
+                Ok(world_count) => world_count,
+                Err(e) => {
+                    update_tx.send(SeedRollUpdate::Error(e)).await?;
+                    return Ok(())
+                }
+            };
             if_chain! {
                 if allow_web;
                 if let Some(web_version) = self.ootr_api_client.can_roll_on_web(None, &version, world_count, unlock_spoiler_log).await;
@@ -1310,8 +1884,8 @@ impl GlobalState {
                         // Start rolling the seed immediately upon the room being opened.
                         PrerollMode::Long => {}
                     }
-                    match self.ootr_api_client.roll_seed_web(update_tx.clone(), delay_until, web_version, false, unlock_spoiler_log, settings).await {
-                        Ok(ootr_web::SeedInfo { id, gen_time, file_hash, file_stem, password }) => update_tx.send(SeedRollUpdate::Done {
+                    match self.ootr_api_client.roll_seed_web(update_tx.clone(), is_official, delay_until, web_version, false, unlock_spoiler_log, settings.clone()).await {
+                        Ok(ootr_web::SeedInfo { id, gen_time, file_hash, file_stem, password, attempts }) => update_tx.send(SeedRollUpdate::Done {
                             seed: seed::Data {
                                 file_hash: Some(file_hash),
                                 files: Some(seed::Files::OotrWeb {
@@ -1323,35 +1897,37 @@ impl GlobalState {
                             },
                             rsl_preset: None,
                             unlock_spoiler_log,
+                            rando_commit: None,
+                            attempts: Some(attempts),
                         }).await?,
-                        Err(e) => update_tx.send(SeedRollUpdate::Error(e.into())).await?, //TODO fall back to rolling locally for network errors
+                        // genuine generation failures are reported as-is since they would most likely fail locally as well
+                        Err(e) if e.is_network_error() => {
+                            update_tx.send(SeedRollUpdate::WebFallback).await?;
+                            roll_seed_locally_with_updates(&self.local_seed_rollers, &update_tx, delay_until, version, unlock_spoiler_log, settings, None).await?;
+                        }
+                        Err(e) => update_tx.send(SeedRollUpdate::Error(e.into())).await?,
                     }
                 } else {
                     update_tx.send(SeedRollUpdate::Started).await?;
-                    match roll_seed_locally(delay_until, version, unlock_spoiler_log, settings).await {
-                        Ok((patch_filename, spoiler_log_path)) => update_tx.send(match spoiler_log_path.map(|spoiler_log_path| spoiler_log_path.into_os_string().into_string()).transpose() {
-                            Ok(locked_spoiler_log_path) => match regex_captures!(r"^(.+)\.zpfz?$", &patch_filename) {
-                                Some((_, file_stem)) => SeedRollUpdate::Done {
-                                    seed: seed::Data {
-                                        file_hash: None, password: None, // will be read from spoiler log
-                                        files: Some(seed::Files::MidosHouse {
-                                            file_stem: Cow::Owned(file_stem.to_owned()),
-                                            locked_spoiler_log_path,
-                                        }),
-                                        progression_spoiler: unlock_spoiler_log == UnlockSpoilerLog::Progression,
-                                    },
-                                    rsl_preset: None,
-                                    unlock_spoiler_log,
-                                },
-                                None => SeedRollUpdate::Error(RollError::PatchPath),
-                            },
-                            Err(e) => SeedRollUpdate::Error(e.into())
-                        }).await?,
-                        Err(e) => update_tx.send(SeedRollUpdate::Error(e)).await?,
-                    }
+                    roll_seed_locally_with_updates(&self.local_seed_rollers, &update_tx, delay_until, version, unlock_spoiler_log, settings, None).await?;
                 }
             }
             Ok::<_, mpsc::error::SendError<_>>(())
+        }));
+        update_rx
+    }
+
+    /// Rolls a seed locally using the given randomizer seed string, so that a seed can be reproduced when investigating a bug.
+    ///
+    /// Must not be used in production since it would make tournament seeds predictable.
+    pub(crate) fn roll_debug_seed(self: Arc<Self>, version: VersionedBranch, mut settings: serde_json::Map<String, Json>, unlock_spoiler_log: UnlockSpoilerLog, rng_seed: String) -> mpsc::Receiver<SeedRollUpdate> {
+        assert!(Environment::default().is_dev(), "attempted to roll a seed with a fixed RNG seed in production");
+        insert_create_spoiler(&mut settings, unlock_spoiler_log);
+        let (update_tx, update_rx) = mpsc::channel(128);
+        tokio::spawn(async move {
+            update_tx.send(SeedRollUpdate::Started).await?;
+            roll_seed_locally_with_updates(&self.local_seed_rollers, &update_tx, None, version, unlock_spoiler_log, settings, Some(rng_seed)).await?;
+            Ok::<_, mpsc::error::SendError<_>>(())
         });
         update_rx
     }
@@ -1359,8 +1935,15 @@ impl GlobalState {
     pub(crate) fn roll_rsl_seed(self: Arc<Self>, delay_until: Option<DateTime<Utc>>, preset: VersionedRslPreset, world_count: u8, unlock_spoiler_log: UnlockSpoilerLog) -> mpsc::Receiver<SeedRollUpdate> {
         let (update_tx, update_rx) = mpsc::channel(128);
         let update_tx2 = update_tx.clone();
-        tokio::spawn(async move {
+        tokio::spawn(until_closed(update_tx.clone(), async move {
             let rsl_script_path = preset.script_path()?; //TODO automatically clone if not present and ensure base rom is in place (need to create data directory)
+            let (_shared_checkout, _exclusive_checkout) = if preset.is_version_locked() {
+                (None, None)
+            } else if preset.git_ref().is_some() {
+                (None, Some(self.rsl_checkout.write().await))
+            } else {
+                (Some(self.rsl_checkout.read().await), None)
+            };
             // update the RSL script
             if !preset.is_version_locked() {
                 let repo = Repository::open(&rsl_script_path)?;
@@ -1369,8 +1952,15 @@ impl GlobalState {
                     VersionedRslPreset::Xopar { .. } => "release",
                     VersionedRslPreset::Fenhl { .. } => "dev-fenhl",
                 };
-                origin.fetch(&[branch_name], None, None)?;
-                repo.reset(&repo.find_branch(&format!("origin/{branch_name}"), BranchType::Remote)?.into_reference().peel_to_commit()?.into_object(), ResetType::Hard, None)?;
+                if let Some(git_ref) = preset.git_ref() {
+                    // also fetch tags so the script can be pinned to a release
+                    origin.fetch(&[branch_name, "+refs/tags/*:refs/tags/*"], None, None)?;
+                    let commit = repo.revparse_single(git_ref).and_then(|object| object.peel_to_commit()).map_err(|_| RollError::RslRef(git_ref.to_owned()))?;
+                    repo.reset(&commit.into_object(), ResetType::Hard, None)?;
+                } else {
+                    origin.fetch(&[branch_name], None, None)?;
+                    repo.reset(&repo.find_branch(&format!("origin/{branch_name}"), BranchType::Remote)?.into_reference().peel_to_commit()?.into_object(), ResetType::Hard, None)?;
+                }
             }
             // check RSL script version
             let rsl_version = Command::new(PYTHON)
@@ -1394,14 +1984,29 @@ impl GlobalState {
             let randomizer_version = String::from_utf8(randomizer_version)?.trim().parse::<rando::Version>()?;
             let web_version = self.ootr_api_client.can_roll_on_web(Some(&preset), &VersionedBranch::Pinned(randomizer_version.clone()), world_count, unlock_spoiler_log).await;
             // run the RSL script
+            let _permit = if web_version.is_some() { None } else { Some(acquire_local_seed_roller(&self.local_seed_rollers, &update_tx).await) };
             let _ = update_tx.send(SeedRollUpdate::Started).await;
-            let outer_tries = if web_version.is_some() { 5 } else { 1 }; // when generating locally, retries are already handled by the RSL script
+            let outer_tries = if web_version.is_some() { self.rsl_web_plando_attempts } else { 1 }; // when generating locally, retries are already handled by the RSL script
             let mut last_error = None;
+            let mut web_attempts = 0u8;
+            let mut last_failure_was_web = false;
             for attempt in 0.. {
                 if attempt >= outer_tries && delay_until.map_or(true, |delay_until| Utc::now() >= delay_until) {
-                    return Err(RollError::Retries {
-                        num_retries: 3 * attempt,
-                        last_error,
+                    return Err(if web_version.is_none() {
+                        RollError::Retries {
+                            num_retries: 3 * attempt,
+                            last_error,
+                        }
+                    } else if last_failure_was_web {
+                        RollError::RslWeb {
+                            num_plandos: attempt,
+                            num_retries: web_attempts,
+                        }
+                    } else {
+                        RollError::RslWeights {
+                            num_attempts: attempt,
+                            last_error,
+                        }
                     })
                 }
                 let mut rsl_cmd = Command::new(PYTHON);
@@ -1429,6 +2034,7 @@ impl GlobalState {
                     Some(0) => {}
                     Some(2) => {
                         last_error = Some(String::from_utf8_lossy(&output.stderr).into_owned());
+                        last_failure_was_web = false;
                         continue
                     }
                     _ => return Err(RollError::Wheel(wheel::Error::CommandExit { name: Cow::Borrowed("RandomSettingsGenerator.py"), output })),
@@ -1453,9 +2059,13 @@ impl GlobalState {
                         let sleep_duration = thread_rng().gen_range(Duration::default()..max_sleep_duration);
                         sleep(sleep_duration).await;
                     }
-                    let ootr_web::SeedInfo { id, gen_time, file_hash, file_stem, password } = match self.ootr_api_client.roll_seed_web(update_tx.clone(), None /* always limit to 3 tries per settings */, web_version, true, unlock_spoiler_log, settings).await {
+                    let ootr_web::SeedInfo { id, gen_time, file_hash, file_stem, password, attempts } = match self.ootr_api_client.roll_seed_web(update_tx.clone(), false /* RSL races aren't official */, None /* always limit to 3 tries per settings */, web_version, true, unlock_spoiler_log, settings).await {
                         Ok(data) => data,
-                        Err(ootr_web::Error::Retries { .. }) => continue,
+                        Err(ootr_web::Error::Retries { num_retries, .. }) => {
+                            web_attempts = web_attempts.saturating_add(num_retries);
+                            last_failure_was_web = true;
+                            continue
+                        }
                         Err(e) => return Err(e.into()), //TODO fall back to rolling locally for network errors
                     };
                     let _ = update_tx.send(SeedRollUpdate::Done {
@@ -1470,6 +2080,8 @@ impl GlobalState {
                         },
                         rsl_preset: if let VersionedRslPreset::Xopar { preset, .. } = preset { Some(preset) } else { None },
                         unlock_spoiler_log,
+                        rando_commit: None,
+                        attempts: Some(web_attempts.saturating_add(attempts)),
                     }).await;
                     return Ok(())
                 } else {
@@ -1498,6 +2110,8 @@ impl GlobalState {
                             },
                             rsl_preset: if let VersionedRslPreset::Xopar { preset, .. } = preset { Some(preset) } else { None },
                             unlock_spoiler_log,
+                            rando_commit: None,
+                            attempts: None, // retries are handled by the RSL script
                         },
                         None => SeedRollUpdate::Error(RollError::PatchPath),
                     }).await;
@@ -1505,16 +2119,107 @@ impl GlobalState {
                 }
             }
             Ok(())
-        }.then(|res| async move {
+        }).then(|res| async move {
             match res {
-                Ok(()) => {}
-                Err(e) => { let _ = update_tx2.send(SeedRollUpdate::Error(e)).await; }
+                Some(Ok(())) | None => {}
+                Some(Err(e)) => { let _ = update_tx2.send(SeedRollUpdate::Error(e)).await; }
             }
         }));
         update_rx
     }
 
     pub(crate) fn roll_tfb_seed(self: Arc<Self>, delay_until: Option<DateTime<Utc>>, version: &'static str, room: Option<String>, unlock_spoiler_log: UnlockSpoilerLog) -> mpsc::Receiver<SeedRollUpdate> {
+        self.roll_tfb_seed_inner(delay_until, Cow::Borrowed(version), room, unlock_spoiler_log, Vec::default())
+    }
+
+    /// Rolls a Triforce Blitz seed with additional generator form fields, e.g. a plando, for testing upcoming features of a new Triforce Blitz version.
+    ///
+    /// Only available via the UNIX socket, i.e. to server admins.
+    pub(crate) fn roll_tfb_dev_seed(self: Arc<Self>, version: String, unlock_spoiler_log: UnlockSpoilerLog, extra_form_data: Vec<(String, String)>) -> mpsc::Receiver<SeedRollUpdate> {
+        self.roll_tfb_seed_inner(None, Cow::Owned(version), None, unlock_spoiler_log, extra_form_data)
+    }
+
+    /// Replays the open, in progress, and finished states of an existing race room without connecting to its websocket, reporting whether the room would be handled at each step and whether it's cleaned up from the open rooms afterwards.
+    ///
+    /// The room's data is fetched from racetime.gg and only its status is changed, so the simulation doesn't depend on a hand-written race data fixture.
+    /// Chat commands and score reporting aren't covered since they require a [`RaceContext`], which can only be created from a live websocket connection.
+    /// Only available via the UNIX socket in the dev environment.
+    pub(crate) fn simulate_race(self: Arc<Self>, room_url: String) -> mpsc::Receiver<String> {
+        let (update_tx, update_rx) = mpsc::channel(128);
+        tokio::spawn(async move {
+            let room_url = room_url.strip_prefix(&format!("https://{}", racetime_host())).unwrap_or(&room_url);
+            let mut race_data = match async {
+                self.http_client.get(format!("https://{}{room_url}/data", racetime_host()))
+                    .send().await?
+                    .detailed_error_for_status().await.to_racetime()?
+                    .json_with_text_in_error::<RaceData>().await.to_racetime()
+            }.await {
+                Ok(race_data) => race_data,
+                Err(e) => {
+                    let _ = update_tx.send(format!("failed to get race data: {e}")).await;
+                    return
+                }
+            };
+            // use a separate key for the open rooms so the simulation doesn't interfere with a handler for the actual room
+            race_data.url = format!("{}/simulated-{:04}", race_data.url, thread_rng().gen_range(0..10000));
+            race_data.status.value = RaceStatusValue::Open;
+            race_data.started_at = None;
+            race_data.ended_at = None;
+            race_data.cancelled_at = None;
+            let url = race_data.url.clone();
+            let is_open = |global_state: &Arc<Self>| {
+                let global_state = global_state.clone();
+                let url = url.clone();
+                async move { lock!(clean_shutdown = global_state.clean_shutdown; clean_shutdown.open_rooms.contains_key(&url)) }
+            };
+            let handled = Handler::should_handle_inner(&race_data, self.clone(), None).await;
+            let _ = update_tx.send(format!("open: room would {}be handled", if handled { "" } else { "not " })).await;
+            if !handled {
+                if is_open(&self).await {
+                    lock!(clean_shutdown = self.clean_shutdown; clean_shutdown.open_rooms.remove(&url));
+                }
+                return
+            }
+            let race_data = Arc::new(tokio::sync::RwLock::new(race_data));
+            // stands in for the handler task, which is normally driven by the racetime.gg websocket
+            let (stop_tx, stop_rx) = tokio::sync::oneshot::channel::<()>();
+            let join_handle = tokio::spawn(async move { let _ = stop_rx.await; });
+            if let Err(e) = Handler::task(self.clone(), race_data.clone(), join_handle).await {
+                let _ = update_tx.send(format!("failed to start mock handler task: {e}")).await;
+                return
+            }
+            let _ = update_tx.send(format!("open: room is {}tracked as an open room", if is_open(&self).await { "" } else { "not " })).await;
+            {
+                let mut data = race_data.write().await;
+                data.status.value = RaceStatusValue::InProgress;
+                data.started_at = Some(Utc::now());
+            }
+            // without official race data, a handler's state doesn't affect whether the room is still handled
+            let handled = Handler::should_handle_inner(&*race_data.read().await, self.clone(), Some(None)).await;
+            let _ = update_tx.send(format!("in progress: handler would {}", if handled { "keep running" } else { "stop" })).await;
+            {
+                let mut data = race_data.write().await;
+                data.status.value = RaceStatusValue::Finished;
+                data.ended_at = Some(Utc::now());
+            }
+            let handled = Handler::should_handle_inner(&*race_data.read().await, self.clone(), Some(None)).await;
+            let _ = update_tx.send(format!("finished: handler would {}", if handled { "keep running" } else { "stop" })).await;
+            let _ = stop_tx.send(());
+            let cleaned_up = timeout(Duration::from_secs(10), async {
+                while is_open(&self).await {
+                    sleep(Duration::from_millis(100)).await;
+                }
+            }).await.is_ok();
+            let _ = update_tx.send(if cleaned_up {
+                format!("finished: room was removed from the open rooms")
+            } else {
+                format!("finished: room was still tracked as an open room 10 seconds after the handler stopped")
+            }).await;
+        });
+        update_rx
+    }
+
+    fn roll_tfb_seed_inner(self: Arc<Self>, delay_until: Option<DateTime<Utc>>, version: Cow<'static, str>, room: Option<String>, unlock_spoiler_log: UnlockSpoilerLog, extra_form_data: Vec<(String, String)>) -> mpsc::Receiver<SeedRollUpdate> {
         let (update_tx, update_rx) = mpsc::channel(128);
         let update_tx2 = update_tx.clone();
         tokio::spawn(async move {
@@ -1526,12 +2231,14 @@ impl GlobalState {
                 sleep(sleep_duration).await;
             }
             let _ = update_tx.send(SeedRollUpdate::Started).await;
-            let form_data = match unlock_spoiler_log {
+            let version = &*version;
+            let mut form_data = match unlock_spoiler_log {
                 UnlockSpoilerLog::Now => vec![
                     ("unlockSetting", "ALWAYS"),
                     ("version", version),
                 ],
                 UnlockSpoilerLog::Progression => panic!("progression spoiler mode not supported by triforceblitz.com"),
+                UnlockSpoilerLog::Organizers => panic!("organizer spoiler mode not supported by triforceblitz.com"),
                 UnlockSpoilerLog::After => if let Some(ref room) = room {
                     vec![
                         ("unlockSetting", "RACETIME"),
@@ -1546,6 +2253,7 @@ impl GlobalState {
                     ("version", version),
                 ],
             };
+            form_data.extend(extra_form_data.iter().map(|(name, value)| (&**name, &**value)));
             let mut attempts = 0;
             let response = loop {
                 attempts += 1;
@@ -1557,7 +2265,12 @@ impl GlobalState {
                     .detailed_error_for_status().await;
                 match response {
                     Ok(response) => break response,
-                    Err(wheel::Error::ResponseStatus { inner, .. }) if attempts < 3 && inner.status().is_some_and(|status| status.is_server_error()) => continue,
+                    Err(wheel::Error::ResponseStatus { inner, .. }) if attempts < 3 && inner.status().is_some_and(|status| status.is_server_error()) => {
+                        let backoff = TFB_RETRY_BASE_DELAY * 2u32.pow(attempts - 1);
+                        let sleep_duration = thread_rng().gen_range(backoff - TFB_RETRY_JITTER..=backoff + TFB_RETRY_JITTER);
+                        sleep(sleep_duration).await;
+                    }
+                    Err(wheel::Error::ResponseStatus { inner, .. }) if !extra_form_data.is_empty() && inner.status().is_some_and(|status| status.is_client_error()) => return Err(RollError::TfbRejected),
                     Err(e) => return Err(e.into()),
                 }
             };
@@ -1579,6 +2292,8 @@ impl GlobalState {
                 },
                 rsl_preset: None,
                 unlock_spoiler_log,
+                rando_commit: None,
+                attempts: None,
             }).await;
             Ok(())
         }.then(|res| async move {
@@ -1591,8 +2306,77 @@ impl GlobalState {
     }
 }
 
-async fn roll_seed_locally(delay_until: Option<DateTime<Utc>>, version: VersionedBranch, unlock_spoiler_log: UnlockSpoilerLog, mut settings: serde_json::Map<String, Json>) -> Result<(String, Option<PathBuf>), RollError> {
-    let rando_path = match version {
+/// Reads the number of worlds from the given settings, which defaults to 1 if not specified.
+///
+/// Settings may come from user-provided plandos, so this returns an error rather than panicking if the value is malformed.
+fn world_count(settings: &serde_json::Map<String, Json>) -> Result<u8, RollError> {
+    settings.get("world_count").map_or(Ok(1), |world_count| world_count.as_u64()
+        .and_then(|world_count| u8::try_from(world_count).ok())
+        .filter(|&world_count| world_count >= 1)
+        .ok_or_else(|| RollError::InvalidWorldCount(world_count.to_string()))
+    )
+}
+
+/// Tells the randomizer whether to generate a spoiler log for a seed with the given spoiler log unlock setting.
+fn insert_create_spoiler(settings: &mut serde_json::Map<String, Json>, unlock_spoiler_log: UnlockSpoilerLog) {
+    let password_lock = settings.get("password_lock").map_or(false, |password_lock| password_lock.as_bool().expect("password_lock setting wasn't a Boolean"));
+    settings.insert(format!("create_spoiler"), json!(match unlock_spoiler_log {
+        UnlockSpoilerLog::Now | UnlockSpoilerLog::Progression | UnlockSpoilerLog::After | UnlockSpoilerLog::Organizers => true,
+        UnlockSpoilerLog::Never => password_lock, // spoiler log needs to be generated so the backend can read the password
+    }));
+}
+
+async fn roll_seed_locally_with_updates(local_seed_rollers: &Semaphore, update_tx: &mpsc::Sender<SeedRollUpdate>, delay_until: Option<DateTime<Utc>>, version: VersionedBranch, unlock_spoiler_log: UnlockSpoilerLog, settings: serde_json::Map<String, Json>, rng_seed: Option<String>) -> Result<(), mpsc::error::SendError<SeedRollUpdate>> {
+    let _permit = acquire_local_seed_roller(local_seed_rollers, update_tx).await;
+    match roll_seed_locally(delay_until, version, unlock_spoiler_log, settings, rng_seed).await {
+        Ok((patch_filename, spoiler_log_path, rando_commit, attempts)) => update_tx.send(match spoiler_log_path.map(|spoiler_log_path| spoiler_log_path.into_os_string().into_string()).transpose() {
+            Ok(locked_spoiler_log_path) => match regex_captures!(r"^(.+)\.zpfz?$", &patch_filename) {
+                Some((_, file_stem)) => SeedRollUpdate::Done {
+                    seed: seed::Data {
+                        file_hash: None, password: None, // will be read from spoiler log
+                        files: Some(seed::Files::MidosHouse {
+                            file_stem: Cow::Owned(file_stem.to_owned()),
+                            locked_spoiler_log_path,
+                        }),
+                        progression_spoiler: unlock_spoiler_log == UnlockSpoilerLog::Progression,
+                    },
+                    rsl_preset: None,
+                    unlock_spoiler_log,
+                    rando_commit,
+                    attempts: Some(attempts),
+                },
+                None => SeedRollUpdate::Error(RollError::PatchPath),
+            },
+            Err(e) => SeedRollUpdate::Error(e.into())
+        }).await,
+        Err(e) => update_tx.send(SeedRollUpdate::Error(e)).await,
+    }
+}
+
+/// Runs a seed roll until it finishes or nothing is waiting for its updates anymore, e.g. because of `!seed cancel` or the race being cancelled.
+///
+/// This stops the roll itself rather than just the task posting its updates, so it doesn't keep its spot in the multiworld queue.
+async fn until_closed<T>(update_tx: mpsc::Sender<SeedRollUpdate>, roll: impl Future<Output = T>) -> Option<T> {
+    select! {
+        res = roll => Some(res),
+        () = update_tx.closed() => None,
+    }
+}
+
+/// Waits until fewer than the configured maximum number of seeds are being rolled locally, notifying the room if this requires waiting.
+async fn acquire_local_seed_roller<'a>(local_seed_rollers: &'a Semaphore, update_tx: &mpsc::Sender<SeedRollUpdate>) -> SemaphorePermit<'a> {
+    match local_seed_rollers.try_acquire() {
+        Ok(permit) => permit,
+        Err(_) => {
+            let _ = update_tx.send(SeedRollUpdate::LocalQueued).await;
+            local_seed_rollers.acquire().await.expect("local seed roller semaphore closed")
+        }
+    }
+}
+
+/// Makes sure the given randomizer version is available locally and returns its directory.
+async fn rando_dir(version: VersionedBranch) -> Result<PathBuf, RollError> {
+    Ok(match version {
         VersionedBranch::Pinned(version) => {
             version.clone_repo().await?;
             version.dir()?
@@ -1623,7 +2407,32 @@ async fn roll_seed_locally(delay_until: Option<DateTime<Utc>>, version: Versione
             }
             dir
         }
-    };
+    })
+}
+
+/// Checks the given settings against the given randomizer version without generating a seed.
+///
+/// Returns the randomizer's error output if the settings are invalid.
+pub(crate) async fn validate_settings(version: VersionedBranch, settings: &serde_json::Map<String, Json>) -> Result<Option<String>, RollError> {
+    let rando_path = rando_dir(version).await?;
+    let mut rando_process = Command::new(PYTHON).arg("OoTRandomizer.py").arg("--convert_settings").arg("--settings=-").current_dir(&rando_path).stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::piped()).spawn().at_command(PYTHON)?;
+    rando_process.stdin.as_mut().expect("piped stdin missing").write_all(&serde_json::to_vec(settings)?).await.at_command(PYTHON)?;
+    let output = rando_process.wait_with_output().await.at_command(PYTHON)?;
+    Ok(if output.status.success() {
+        None
+    } else {
+        Some(String::from_utf8_lossy(&output.stderr).into_owned())
+    })
+}
+
+/// Rolls a seed using a local copy of the randomizer.
+///
+/// If `rng_seed` is given, it's passed to the randomizer as the seed string, making the result reproducible. This should only be used for debugging.
+async fn roll_seed_locally(delay_until: Option<DateTime<Utc>>, version: VersionedBranch, unlock_spoiler_log: UnlockSpoilerLog, mut settings: serde_json::Map<String, Json>, rng_seed: Option<String>) -> Result<(String, Option<PathBuf>, Option<String>, u8), RollError> {
+    let pinned = matches!(version, VersionedBranch::Pinned(_));
+    let rando_path = rando_dir(version).await?;
+    // the exact commit is only of interest for unpinned branches since pinned versions are reproducible by definition
+    let rando_commit = if pinned { None } else { Some(Repository::open(&rando_path)?.head()?.peel_to_commit()?.id().to_string()) };
     #[cfg(unix)] {
         settings.insert(format!("rom"), json!(BaseDirectories::new()?.find_data_file(Path::new("midos-house").join("oot-ntscu-1.0.z64")).ok_or(RollError::RomPath)?));
         if settings.get("language").and_then(|language| language.as_str()).map_or(false, |language| matches!(language, "french" | "german")) {
@@ -1632,26 +2441,35 @@ async fn roll_seed_locally(delay_until: Option<DateTime<Utc>>, version: Versione
     }
     settings.insert(format!("create_patch_file"), json!(true));
     settings.insert(format!("create_compressed_rom"), json!(false));
+    let world_count = world_count(&settings)?;
+    // a fixed seed string fails the same way every time, so retrying would be pointless
+    let max_attempts = if rng_seed.is_some() { 1 } else { 3 };
     let mut last_error = None;
     for attempt in 0.. {
-        if attempt >= 3 && delay_until.map_or(true, |delay_until| Utc::now() >= delay_until) {
+        if attempt >= max_attempts && delay_until.map_or(true, |delay_until| Utc::now() >= delay_until) {
             return Err(RollError::Retries {
                 num_retries: attempt,
                 last_error,
             })
         }
-        let mut rando_process = Command::new(PYTHON).arg("OoTRandomizer.py").arg("--no_log").arg("--settings=-").current_dir(&rando_path).stdin(Stdio::piped()).stderr(Stdio::piped()).spawn().at_command(PYTHON)?;
+        let mut command = Command::new(PYTHON);
+        command.arg("OoTRandomizer.py");
+        command.arg("--no_log");
+        command.arg("--settings=-");
+        if let Some(ref rng_seed) = rng_seed {
+            command.arg(format!("--seed={rng_seed}"));
+        }
+        let mut rando_process = command.current_dir(&rando_path).stdin(Stdio::piped()).stderr(Stdio::piped()).spawn().at_command(PYTHON)?;
         rando_process.stdin.as_mut().expect("piped stdin missing").write_all(&serde_json::to_vec(&settings)?).await.at_command(PYTHON)?;
         let output = rando_process.wait_with_output().await.at_command(PYTHON)?;
         let stderr = if output.status.success() { BufRead::lines(&*output.stderr).try_collect::<_, Vec<_>, _>().at_command(PYTHON)? } else {
             last_error = Some(String::from_utf8_lossy(&output.stderr).into_owned());
             continue
         };
-        let world_count = settings.get("world_count").map_or(1, |world_count| world_count.as_u64().expect("world_count setting wasn't valid u64").try_into().expect("too many worlds"));
         let patch_path_prefix = if world_count > 1 { "Created patch file archive at: " } else { "Creating Patch File: " };
         let patch_path = rando_path.join("Output").join(stderr.iter().rev().find_map(|line| line.strip_prefix(patch_path_prefix)).ok_or(RollError::PatchPath)?);
         let spoiler_log_path = match unlock_spoiler_log {
-            UnlockSpoilerLog::Now | UnlockSpoilerLog::Progression | UnlockSpoilerLog::After => Some(rando_path.join("Output").join(stderr.iter().rev().find_map(|line| line.strip_prefix("Created spoiler log at: ")).ok_or_else(|| RollError::SpoilerLogPath {
+            UnlockSpoilerLog::Now | UnlockSpoilerLog::Progression | UnlockSpoilerLog::After | UnlockSpoilerLog::Organizers => Some(rando_path.join("Output").join(stderr.iter().rev().find_map(|line| line.strip_prefix("Created spoiler log at: ")).ok_or_else(|| RollError::SpoilerLogPath {
                 stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
                 stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
             })?).to_owned()),
@@ -1662,6 +2480,8 @@ async fn roll_seed_locally(delay_until: Option<DateTime<Utc>>, version: Versione
         return Ok((
             patch_filename.to_str().expect("non-UTF-8 patch filename").to_owned(),
             spoiler_log_path,
+            rando_commit,
+            attempt + 1,
         ))
     }
     unreachable!()
@@ -1694,6 +2514,8 @@ pub(crate) enum RollError {
     #[cfg(unix)]
     #[error("randomizer settings must be a JSON object")]
     NonObjectSettings,
+    #[error("world_count setting must be a whole number between 1 and 255, got {0}")]
+    InvalidWorldCount(String),
     #[error("non-UTF-8 filename")]
     OsString(std::ffi::OsString),
     #[error("randomizer did not report patch location")]
@@ -1704,6 +2526,8 @@ pub(crate) enum RollError {
     #[cfg(unix)]
     #[error("RSL script not found")]
     RslPath,
+    #[error("RSL script version {0:?} not found, refusing to roll with the latest version instead")]
+    RslRef(String),
     #[error("max retries exceeded")]
     Retries {
         num_retries: u8,
@@ -1711,6 +2535,16 @@ pub(crate) enum RollError {
     },
     #[error("failed to parse random settings script output")]
     RslScriptOutput,
+    #[error("the random settings script failed to generate valid settings")]
+    RslWeights {
+        num_attempts: u8,
+        last_error: Option<String>,
+    },
+    #[error("ootrandomizer.com failed to generate a seed with any of the random settings tried")]
+    RslWeb {
+        num_plandos: u8,
+        num_retries: u8,
+    },
     #[cfg(unix)]
     #[error("failed to parse randomizer version from RSL script")]
     RslVersion,
@@ -1723,6 +2557,8 @@ pub(crate) enum RollError {
     TfbHash,
     #[error("failed to parse Triforce Blitz seed page")]
     TfbHtml,
+    #[error("Triforce Blitz website rejected the additional generator options")]
+    TfbRejected,
     #[error("Triforce Blitz website returned unexpected URL")]
     TfbUrl,
     #[cfg(windows)]
@@ -1758,16 +2594,28 @@ impl<'a> From<&'a RollError> for (String, String) {
 #[cfg_attr(unix, derive(Protocol))]
 pub(crate) enum SeedRollUpdate {
     /// The seed rollers are busy and the seed has been queued.
-    Queued(u64),
+    ///
+    /// The second field is an estimate of how long it will take until the seed is done, if enough seeds have been rolled recently to estimate it.
+    Queued(u64, Option<Duration>),
     /// A seed in front of us is done and we've moved to a new position in the queue.
     MovedForward(u64),
+    /// A seed for an official race has been moved ahead of us in the queue.
+    MovedBack(u64),
     /// We've cleared the queue and are now being rolled.
     Started,
+    /// ootrandomizer.com could not be reached, so the seed is being rolled locally instead.
+    WebFallback,
+    /// The maximum number of seeds are already being rolled locally, so this seed will be rolled once one of them is done.
+    LocalQueued,
     /// The seed has been rolled successfully.
     Done {
         seed: seed::Data,
         rsl_preset: Option<rsl::Preset>,
         unlock_spoiler_log: UnlockSpoilerLog,
+        /// The randomizer commit the seed was rolled from, if it was rolled locally from an unpinned branch.
+        rando_commit: Option<String>,
+        /// How many times the randomizer was run to generate this seed, if known.
+        attempts: Option<u8>,
     },
     /// Seed rolling failed.
     Error(RollError),
@@ -1776,25 +2624,107 @@ pub(crate) enum SeedRollUpdate {
     Message(String),
 }
 
+/// Information about a seed roll that is recorded in the `seed_roll_log` table along with its outcome, so organizers can find settings that often fail to generate.
+struct SeedRollLog {
+    room: String,
+    goal: Option<Goal>,
+    settings: Option<serde_json::Map<String, Json>>,
+    /// When the seed was requested. The time until `finished_at` includes any waiting in the seed queue or until the seed is due to be rolled, not just seed generation.
+    requested_at: DateTime<Utc>,
+}
+
+impl SeedRollLog {
+    /// Logging is best-effort, so errors are only printed rather than interrupting the seed roll.
+    async fn record(&self, db_pool: &PgPool, finished_at: DateTime<Utc>, success: bool, num_retries: Option<u8>, sample_error: Option<&str>) {
+        if let Err(e) = sqlx::query!("INSERT INTO seed_roll_log (room, goal, settings, settings_hash, success, num_retries, requested_at, finished_at, sample_error) VALUES ($1, $2, $3, md5($3::text), $4, $5, $6, $7, $8)",
+            self.room,
+            self.goal.map(|goal| goal.as_str()),
+            self.settings.as_ref().map(sqlx::types::Json) as _,
+            success,
+            num_retries.map(i16::from),
+            self.requested_at,
+            finished_at,
+            sample_error,
+        ).execute(db_pool).await {
+            eprintln!("failed to record seed roll outcome: {e} ({e:?})");
+        }
+    }
+}
+
 impl SeedRollUpdate {
-    async fn handle(self, db_pool: &PgPool, ctx: &RaceContext<GlobalState>, state: &ArcRwLock<RaceState>, official_data: Option<&OfficialRaceData>, language: Language, article: &'static str, description: &str) -> Result<(), Error> {
+    async fn handle(self, db_pool: &PgPool, ctx: &RaceContext<GlobalState>, state: &ArcRwLock<RaceState>, official_data: Option<&OfficialRaceData>, roll_log: Option<&SeedRollLog>, received_at: DateTime<Utc>, expected_gen_time: Option<Duration>, language: Language, article: &'static str, description: &str) -> Result<(), Error> {
+        if let Self::Done { .. } | Self::Error(_) = self {
+            sqlx::query!("DELETE FROM seed_queue WHERE room = $1", format!("https://{}{}", racetime_host(), ctx.data().await.url)).execute(db_pool).await.to_racetime()?;
+        }
+        if let Some(roll_log) = roll_log {
+            match self {
+                Self::Done { attempts, .. } => roll_log.record(db_pool, received_at, true, attempts.map(|attempts| attempts.saturating_sub(1)), None).await,
+                Self::Error(RollError::Retries { num_retries, ref last_error }) => roll_log.record(db_pool, received_at, false, Some(num_retries), last_error.as_deref()).await,
+                Self::Error(RollError::RslWeights { num_attempts, ref last_error }) => roll_log.record(db_pool, received_at, false, Some(num_attempts), last_error.as_deref()).await,
+                Self::Error(RollError::RslWeb { num_retries, .. }) => roll_log.record(db_pool, received_at, false, Some(num_retries), None).await,
+                Self::Error(ref e) => roll_log.record(db_pool, received_at, false, None, Some(&e.to_string())).await,
+                _ => {}
+            }
+        }
         match self {
-            Self::Queued(0) => ctx.say("I'm already rolling other multiworld seeds so your seed has been queued. It is at the front of the queue so it will be rolled next.").await?,
-            Self::Queued(1) => ctx.say("I'm already rolling other multiworld seeds so your seed has been queued. There is 1 seed in front of it in the queue.").await?,
-            Self::Queued(pos) => ctx.say(format!("I'm already rolling other multiworld seeds so your seed has been queued. There are {pos} seeds in front of it in the queue.")).await?,
-            Self::MovedForward(0) => ctx.say("The queue has moved and your seed is now at the front so it will be rolled next.").await?,
-            Self::MovedForward(1) => ctx.say("The queue has moved and there is only 1 more seed in front of yours.").await?,
-            Self::MovedForward(pos) => ctx.say(format!("The queue has moved and there are now {pos} seeds in front of yours.")).await?,
-            Self::Started => ctx.say(if let French = language {
-                format!("Génération d'{article} {description}…")
+            Self::Queued(pos, eta) => {
+                let mut msg = match (language, pos) {
+                    (French, 0) => format!("Je génère déjà d'autres seeds multiworld, donc votre seed a été placée dans la file d'attente. Elle est en tête de la file, elle sera donc générée ensuite."),
+                    (French, 1) => format!("Je génère déjà d'autres seeds multiworld, donc votre seed a été placée dans la file d'attente. Il y a 1 seed devant elle dans la file."),
+                    (French, _) => format!("Je génère déjà d'autres seeds multiworld, donc votre seed a été placée dans la file d'attente. Il y a {pos} seeds devant elle dans la file."),
+                    (_, 0) => format!("I'm already rolling other multiworld seeds so your seed has been queued. It is at the front of the queue so it will be rolled next."),
+                    (_, 1) => format!("I'm already rolling other multiworld seeds so your seed has been queued. There is 1 seed in front of it in the queue."),
+                    (_, _) => format!("I'm already rolling other multiworld seeds so your seed has been queued. There are {pos} seeds in front of it in the queue."),
+                };
+                if let Some(eta) = eta {
+                    // rounded up to full minutes since this is only a rough estimate
+                    let eta = Duration::from_secs(eta.as_secs().div_ceil(60).max(1) * 60);
+                    msg.push_str(&if let French = language {
+                        format!(" Temps d'attente estimé : ~{}.", French.format_duration(eta, true))
+                    } else {
+                        format!(" Estimated wait: ~{}.", English.format_duration(eta, true))
+                    });
+                }
+                ctx.say(msg).await?;
+            }
+            Self::MovedForward(pos) => ctx.say(match (language, pos) {
+                (French, 0) => format!("La file d'attente a avancé et votre seed est maintenant en tête, elle sera donc générée ensuite."),
+                (French, 1) => format!("La file d'attente a avancé et il n'y a plus qu'une seed devant la vôtre."),
+                (French, _) => format!("La file d'attente a avancé et il y a maintenant {pos} seeds devant la vôtre."),
+                (_, 0) => format!("The queue has moved and your seed is now at the front so it will be rolled next."),
+                (_, 1) => format!("The queue has moved and there is only 1 more seed in front of yours."),
+                (_, _) => format!("The queue has moved and there are now {pos} seeds in front of yours."),
+            }).await?,
+            Self::MovedBack(pos) => ctx.say(match (language, pos) {
+                (French, 1) => format!("Une seed pour une race officielle a été placée devant la vôtre dans la file d'attente. Il y a maintenant 1 seed devant la vôtre."),
+                (French, _) => format!("Une seed pour une race officielle a été placée devant la vôtre dans la file d'attente. Il y a maintenant {pos} seeds devant la vôtre."),
+                (_, 1) => format!("A seed for an official race has been moved ahead of yours in the queue. There is now 1 seed in front of yours."),
+                (_, _) => format!("A seed for an official race has been moved ahead of yours in the queue. There are now {pos} seeds in front of yours."),
+            }).await?,
+            Self::Started => ctx.say(match (language, expected_gen_time) {
+                (French, Some(expected_gen_time)) => format!("Génération d'{article} {description}… Cela prend généralement jusqu'à environ {}.", French.format_duration(expected_gen_time, true)),
+                (French, None) => format!("Génération d'{article} {description}…"),
+                (_, Some(expected_gen_time)) => format!("Rolling {article} {description}… This usually takes up to about {}.", English.format_duration(expected_gen_time, true)),
+                (_, None) => format!("Rolling {article} {description}…"),
+            }).await?,
+            Self::WebFallback => ctx.say(if let French = language {
+                format!("ootrandomizer.com semble être indisponible, donc je génère {article} {description} localement. La seed sera hébergée sur Mido's House au lieu de ootrandomizer.com.")
+            } else {
+                format!("ootrandomizer.com seems to be unavailable, so I'm rolling {article} {description} locally instead. The seed will be hosted on Mido's House rather than ootrandomizer.com.")
+            }).await?,
+            Self::LocalQueued => ctx.say(if let French = language {
+                "Je génère déjà d'autres seeds en ce moment, donc la vôtre sera générée dès que l'une d'entre elles sera terminée."
             } else {
-                format!("Rolling {article} {description}…")
+                "I'm already rolling other seeds right now, so yours will be rolled as soon as one of them is done."
             }).await?,
-            Self::Done { mut seed, rsl_preset, unlock_spoiler_log } => {
+            Self::Done { mut seed, rsl_preset, unlock_spoiler_log, rando_commit, attempts } => {
                 if let Some(seed::Files::MidosHouse { ref file_stem, ref mut locked_spoiler_log_path }) = seed.files {
                     lock!(@write seed_metadata = ctx.global_state.seed_metadata; seed_metadata.insert(file_stem.to_string(), SeedMetadata {
                         locked_spoiler_log_path: locked_spoiler_log_path.clone(),
                         progression_spoiler: unlock_spoiler_log == UnlockSpoilerLog::Progression,
+                        rando_commit: rando_commit.clone(),
+                        // the update may be handled well after it was received if the seed is held back until shortly before the race, and seeds that were queued rather than rolled have no known generation time
+                        rolled_at: roll_log.is_some().then_some(received_at),
                     }));
                     if unlock_spoiler_log == UnlockSpoilerLog::Now && locked_spoiler_log_path.is_some() {
                         fs::rename(locked_spoiler_log_path.as_ref().unwrap(), Path::new(seed::DIR).join(format!("{file_stem}_Spoiler.json"))).await.to_racetime()?;
@@ -1850,25 +2780,46 @@ impl SeedRollUpdate {
                     if let Some(password) = extra.password {
                         sqlx::query!("UPDATE races SET seed_password = $1 WHERE id = $2", password.into_iter().map(char::from).collect::<String>(), cal_event.race.id as _).execute(db_pool).await.to_racetime()?;
                     }
+                    if let Some(ref rando_commit) = rando_commit {
+                        sqlx::query!("UPDATE races SET rando_commit = $1 WHERE id = $2", rando_commit, cal_event.race.id as _).execute(db_pool).await.to_racetime()?;
+                    }
                 }
-                let seed_url = match seed.files.as_ref().expect("received seed with no files") {
-                    seed::Files::MidosHouse { file_stem, .. } => format!("https://midos.house/seed/{file_stem}"),
-                    seed::Files::OotrWeb { id, .. } => format!("https://ootrandomizer.com/seed/get?id={id}"),
-                    seed::Files::TriforceBlitz { uuid } => format!("https://www.triforceblitz.com/seed/{uuid}"),
-                    seed::Files::TfbSotd { ordinal, .. } => format!("https://www.triforceblitz.com/seed/daily/{ordinal}"),
-                };
-                ctx.say(if let French = language {
-                    format!("@entrants Voici votre seed : {seed_url}")
+                let seed_url = seed_url(seed.files.as_ref().expect("received seed with no files"));
+                let bilingual = ctx.data().await.goal.name.parse::<Goal>().is_ok_and(|goal| goal.is_bilingual());
+                let seed_message_languages = official_data.map(|OfficialRaceData { event, .. }| &*event.seed_message_languages).filter(|languages| !languages.is_empty());
+                if let Some(seed_message_languages) = seed_message_languages {
+                    // posted as separate messages to stay within the chat message length limit
+                    for (idx, &language) in seed_message_languages.iter().enumerate() {
+                        ctx.say(format!("{}{}", if idx == 0 { "@entrants " } else { "" }, match language {
+                            English => format!("Here is your seed: {seed_url}"),
+                            French => format!("Voici votre seed : {seed_url}"),
+                            German => format!("Hier ist euer Seed: {seed_url}"),
+                            Portuguese => format!("Aqui está a sua seed: {seed_url}"),
+                        })).await?;
+                    }
                 } else {
-                    format!("@entrants Here is your seed: {seed_url}")
-                }).await?;
+                    ctx.say(if bilingual {
+                        format!("@entrants Here is your seed / Voici votre seed : {seed_url}")
+                    } else if let French = language {
+                        format!("@entrants Voici votre seed : {seed_url}")
+                    } else {
+                        format!("@entrants Here is your seed: {seed_url}")
+                    }).await?;
+                }
                 if let Some(file_hash) = extra.file_hash {
                     ctx.say(format_hash(file_hash)).await?;
                 }
+                if let Some(attempts @ 2..) = attempts {
+                    ctx.say(if let French = language {
+                        format!("Cette seed a nécessité {attempts} tentatives de génération.")
+                    } else {
+                        format!("This seed took {attempts} attempts to generate.")
+                    }).await?;
+                }
                 match unlock_spoiler_log {
                     UnlockSpoilerLog::Now => ctx.say("The spoiler log is also available on the seed page.").await?,
                     UnlockSpoilerLog::Progression => ctx.say("The progression spoiler is also available on the seed page. The full spoiler will be available there after the race.").await?,
-                    UnlockSpoilerLog::After => if let Some(seed::Files::TfbSotd { date, .. }) = seed.files {
+                    UnlockSpoilerLog::After | UnlockSpoilerLog::Organizers => if let Some(seed::Files::TfbSotd { date, .. }) = seed.files {
                         if let Some(unlock_date) = date.succ_opt().and_then(|next| next.succ_opt()) {
                             let unlock_time = Utc.from_utc_datetime(&unlock_date.and_hms_opt(20, 0, 0).expect("failed to construct naive datetime at 20:00:00"));
                             let unlock_time = (unlock_time - Utc::now()).to_std().expect("unlock time for current daily seed in the past");
@@ -1876,8 +2827,19 @@ impl SeedRollUpdate {
                         } else {
                             unimplemented!("distant future Triforce Blitz SotD")
                         }
+                    } else if let Some(seed_message_languages) = seed_message_languages {
+                        for &language in seed_message_languages {
+                            ctx.say(match language {
+                                English => "The spoiler log will be available on the seed page after the race.",
+                                French => "Le spoiler log sera disponible sur le lien de la seed après la race.",
+                                German => "Das Spoiler-Log wird nach dem Rennen auf der Seed-Seite verfügbar sein.",
+                                Portuguese => "O spoiler log estará disponível na página da seed após a corrida.",
+                            }).await?;
+                        }
                     } else {
-                        ctx.say(if let French = language {
+                        ctx.say(if bilingual {
+                            "The spoiler log will be available on the seed page after the race. / Le spoiler log sera disponible sur le lien de la seed après la race."
+                        } else if let French = language {
                             "Le spoiler log sera disponible sur le lien de la seed après la seed."
                         } else {
                             "The spoiler log will be available on the seed page after the race."
@@ -1888,8 +2850,26 @@ impl SeedRollUpdate {
                 if extra.password.is_some() {
                     ctx.say("Please note that this seed is password protected. You will receive the password to start a file ingame as soon as the countdown starts.").await?;
                 }
+                if_chain! {
+                    if let UnlockSpoilerLog::Organizers = unlock_spoiler_log;
+                    if let Some(OfficialRaceData { event, .. }) = official_data;
+                    if let Some(organizer_channel) = event.discord_organizer_channel;
+                    if let Some(seed::Files::MidosHouse { locked_spoiler_log_path: Some(ref locked_spoiler_log_path), .. }) = seed.files;
+                    then {
+                        organizer_channel.send_message(&*ctx.global_state.discord_ctx.read().await, CreateMessage::default()
+                            .content(MessageBuilder::default()
+                                .push("spoiler log for <https://")
+                                .push(racetime_host())
+                                .push(&ctx.data().await.url)
+                                .push(">, please don't share it with entrants until after the race:")
+                                .build()
+                            )
+                            .add_file(CreateAttachment::path(locked_spoiler_log_path).await.to_racetime()?)
+                        ).await.to_racetime()?;
+                    }
+                }
                 set_bot_raceinfo(ctx, &seed, rsl_preset, false).await?;
-                if let Some(OfficialRaceData { cal_event, event, .. }) = official_data {
+                if let Some(OfficialRaceData { cal_event, event, mw_rooms, .. }) = official_data {
                     // send multiworld rooms
                     let mut transaction = ctx.global_state.db_pool.begin().await.to_racetime()?;
                     let mut mw_rooms_created = 0;
@@ -1936,22 +2916,56 @@ impl SeedRollUpdate {
                                 mw_room_name.push_str(ellipsis);
                             }
                             if let Some([hash1, hash2, hash3, hash4, hash5]) = extra.file_hash {
-                                let mut cmd = Command::new("/usr/local/share/midos-house/bin/ootrmwd");
-                                cmd.arg("create-tournament-room");
-                                cmd.arg(&mw_room_name);
-                                cmd.arg(hash1.to_string());
-                                cmd.arg(hash2.to_string());
-                                cmd.arg(hash3.to_string());
-                                cmd.arg(hash4.to_string());
-                                cmd.arg(hash5.to_string());
-                                for (member, role) in members {
-                                    if event.team_config.role_is_racing(role) {
-                                        cmd.arg(member.id.to_string());
+                                let mut last_error = None;
+                                for attempt in 0..MW_ROOM_ATTEMPTS {
+                                    if attempt > 0 {
+                                        // room creation failures are often transient
+                                        sleep(MW_ROOM_RETRY_BASE_DELAY * 2u32.pow(attempt - 1)).await;
+                                    }
+                                    let mut cmd = Command::new("/usr/local/share/midos-house/bin/ootrmwd");
+                                    cmd.arg("create-tournament-room");
+                                    cmd.arg(&mw_room_name);
+                                    cmd.arg(hash1.to_string());
+                                    cmd.arg(hash2.to_string());
+                                    cmd.arg(hash3.to_string());
+                                    cmd.arg(hash4.to_string());
+                                    cmd.arg(hash5.to_string());
+                                    for (member, role) in &members {
+                                        if event.team_config.role_is_racing(*role) {
+                                            cmd.arg(member.id.to_string());
+                                        }
+                                    }
+                                    match cmd.check("ootrmwd create-tournament-room").await {
+                                        Ok(_) => {
+                                            last_error = None;
+                                            break
+                                        }
+                                        Err(e) => last_error = Some(e),
                                     }
                                 }
-                                cmd.check("ootrmwd create-tournament-room").await.to_racetime()?;
-                                ctx.say(format!("{reply_to}, your Mido's House Multiworld room named “{mw_room_name}” is now open.")).await?;
-                                mw_rooms_created += 1;
+                                if let Some(e) = last_error {
+                                    eprintln!("failed to create Mido's House Multiworld room after {MW_ROOM_ATTEMPTS} attempts: {e} ({e:?})");
+                                    ctx.say(format!("Sorry {reply_to}, there was an error creating your Mido's House Multiworld room. Please create one manually.")).await?;
+                                    if let Some(organizer_channel) = event.discord_organizer_channel {
+                                        let room_url = format!("https://{}{}", racetime_host(), ctx.data().await.url);
+                                        if let Err(e) = organizer_channel.say(&*ctx.global_state.discord_ctx.read().await, MessageBuilder::default()
+                                            .push("failed to create Mido's House Multiworld room for ")
+                                            .push_safe(team.name.as_deref().unwrap_or("unnamed team"))
+                                            .push(" after ")
+                                            .push(MW_ROOM_ATTEMPTS.to_string())
+                                            .push(" attempts, they have been asked to create one manually: <")
+                                            .push(&room_url)
+                                            .push('>')
+                                            .build()
+                                        ).await {
+                                            eprintln!("failed to notify organizers about Mido's House Multiworld room creation failure in {room_url}: {e} ({e:?})");
+                                        }
+                                    }
+                                } else {
+                                    ctx.say(format!("{reply_to}, your Mido's House Multiworld room named “{mw_room_name}” is now open.")).await?;
+                                    lock!(@write mw_rooms = mw_rooms; mw_rooms.push(mw_room_name));
+                                    mw_rooms_created += 1;
+                                }
                             } else {
                                 ctx.say(format!("Sorry {reply_to}, there was an error creating your Mido's House Multiworld room. Please create one manually.")).await?;
                             }
@@ -1970,6 +2984,40 @@ impl SeedRollUpdate {
                 } else {
                     eprintln!("seed rolling failed {num_retries} times, no sample error recorded");
                 }
+                if let Some(OfficialRaceData { event, goal, .. }) = official_data {
+                    if let Some(organizer_channel) = event.discord_organizer_channel {
+                        let room_url = ctx.data().await.url.clone();
+                        let should_notify = lock!(roll_failure_notifications = ctx.global_state.roll_failure_notifications; {
+                            let now = Instant::now();
+                            if roll_failure_notifications.get(&room_url).is_some_and(|last_notified| now.duration_since(*last_notified) < ROLL_FAILURE_NOTIFICATION_COOLDOWN) {
+                                false
+                            } else {
+                                roll_failure_notifications.insert(room_url.clone(), now);
+                                true
+                            }
+                        });
+                        if should_notify {
+                            let mut msg = MessageBuilder::default();
+                            msg.push("seed rolling for <https://");
+                            msg.push(racetime_host());
+                            msg.push(&room_url);
+                            msg.push("> (goal: ");
+                            msg.push_safe(goal.as_str());
+                            msg.push(") failed ");
+                            msg.push(num_retries.to_string());
+                            msg.push(" times");
+                            if let Some(ref last_error) = last_error {
+                                // the end of the output is usually the most informative part, e.g. the last line of a Python traceback
+                                let skip = last_error.chars().count().saturating_sub(ROLL_FAILURE_SAMPLE_ERROR_LENGTH);
+                                msg.push(", sample error:");
+                                msg.push_codeblock_safe(last_error.chars().skip(skip).collect::<String>(), None);
+                            } else {
+                                msg.push(", no sample error recorded");
+                            }
+                            organizer_channel.say(&*ctx.global_state.discord_ctx.read().await, msg.build()).await.to_racetime()?;
+                        }
+                    }
+                }
                 ctx.say(if let French = language {
                     format!("Désolé @entrants, le randomizer a rapporté une erreur {num_retries} fois de suite donc je vais laisser tomber. Veuillez réessayer et, si l'erreur persiste, essayer de roll une seed de votre côté et contacter Fenhl.")
                 } else {
@@ -1977,6 +3025,36 @@ impl SeedRollUpdate {
                 }).await?; //TODO for official races, explain that retrying is done using !seed
                 lock!(@write state = state; *state = RaceState::Init);
             }
+            Self::Error(RollError::RslWeights { num_attempts, last_error }) => {
+                if let Some(last_error) = last_error {
+                    eprintln!("random settings script failed {num_attempts} times, sample error:\n{last_error}");
+                } else {
+                    eprintln!("random settings script failed {num_attempts} times, no sample error recorded");
+                }
+                ctx.say(if let French = language {
+                    format!("Désolé @entrants, le script de random settings n'a pas réussi à générer des paramètres valides {num_attempts} fois de suite donc je vais laisser tomber. Veuillez réessayer et, si l'erreur persiste, contacter Fenhl.")
+                } else {
+                    format!("Sorry @entrants, the random settings script failed to generate valid settings {num_attempts} times, so I'm giving up on rolling the seed. Please try again. If this error persists, please report it to Fenhl.")
+                }).await?;
+                lock!(@write state = state; *state = RaceState::Init);
+            }
+            Self::Error(RollError::RslWeb { num_plandos, num_retries }) => {
+                eprintln!("ootrandomizer.com failed to roll {num_plandos} random settings plandos ({num_retries} attempts total)");
+                ctx.say(if let French = language {
+                    format!("Désolé @entrants, ootrandomizer.com n'a réussi à générer une seed avec aucun des {num_plandos} jeux de paramètres essayés donc je vais laisser tomber. Veuillez réessayer et, si l'erreur persiste, contacter Fenhl.")
+                } else {
+                    format!("Sorry @entrants, ootrandomizer.com failed to generate a seed with any of the {num_plandos} sets of random settings I tried, so I'm giving up on rolling the seed. Please try again. If this error persists, please report it to Fenhl.")
+                }).await?;
+                lock!(@write state = state; *state = RaceState::Init);
+            }
+            Self::Error(RollError::InvalidWorldCount(world_count)) => {
+                ctx.say(if let French = language {
+                    format!("Désolé @entrants, le nombre de mondes ({world_count}) n'est pas valide. Il doit s'agir d'un nombre entier entre 1 et 255.")
+                } else {
+                    format!("Sorry @entrants, the world count ({world_count}) is invalid. It must be a whole number between 1 and 255.")
+                }).await?;
+                lock!(@write state = state; *state = RaceState::Init);
+            }
             Self::Error(e) => {
                 eprintln!("seed roll error: {e} ({e:?})");
                 if let Environment::Production = Environment::default() {
@@ -1990,6 +3068,36 @@ impl SeedRollUpdate {
     }
 }
 
+/// Parses a seed hash given as chat command arguments, either as racetime.gg emotes or as hash icon names (which may consist of multiple words and be separated by commas).
+fn parse_file_hash(args: &[String]) -> Option<[HashIcon; 5]> {
+    let words = args.iter().flat_map(|arg| arg.split(',')).filter(|word| !word.is_empty()).collect_vec();
+    let mut words = &*words;
+    let mut icons = Vec::with_capacity(5);
+    while let Some(first) = words.first() {
+        let (icon, len) = if let Some(icon) = HashIcon::from_racetime_emoji(first) {
+            (icon, 1)
+        } else {
+            // try the longest icon name first, e.g. “Lens of Truth” rather than “Lens”
+            (1..=words.len().min(3)).rev().find_map(|len| {
+                let name = words[..len].join(" ");
+                all::<HashIcon>().find(|icon| icon.to_string().eq_ignore_ascii_case(&name)).map(|icon| (icon, len))
+            })?
+        };
+        icons.push(icon);
+        words = &words[len..];
+    }
+    icons.try_into().ok()
+}
+
+fn seed_url(files: &seed::Files) -> String {
+    match files {
+        seed::Files::MidosHouse { file_stem, .. } => format!("https://midos.house/seed/{file_stem}"),
+        seed::Files::OotrWeb { id, .. } => format!("https://ootrandomizer.com/seed/get?id={id}"),
+        seed::Files::TriforceBlitz { uuid } => format!("https://www.triforceblitz.com/seed/{uuid}"),
+        seed::Files::TfbSotd { ordinal, .. } => format!("https://www.triforceblitz.com/seed/daily/{ordinal}"),
+    }
+}
+
 fn format_hash(file_hash: [HashIcon; 5]) -> impl fmt::Display {
     file_hash.into_iter().map(|icon| icon.to_racetime_emoji()).format(" ")
 }
@@ -2018,26 +3126,25 @@ fn ocarina_note_to_ootr_discord_emoji(note: OcarinaNote) -> ReactionType {
     }
 }
 
-async fn room_options(goal: Goal, event: &event::Data<'_>, cal_event: &cal::Event, info_user: String, info_bot: String, auto_start: bool) -> racetime::StartRace {
+async fn room_options(goal: Goal, event: &event::Data<'_>, cal_event: &cal::Event, info_user: String, info_bot: String, unlisted: bool, auto_start: bool) -> racetime::StartRace {
     racetime::StartRace {
         goal: goal.as_str().to_owned(),
         goal_is_custom: goal.is_custom(),
         team_race: event.team_config.is_racetime_team_format() && matches!(cal_event.kind, cal::EventKind::Normal),
         invitational: !matches!(cal_event.race.entrants, Entrants::Open),
-        unlisted: cal_event.is_private_async_part(),
         ranked: event.series != Series::TriforceBlitz && !matches!(cal_event.race.schedule, RaceSchedule::Async { .. }),
         require_even_teams: true,
-        start_delay: if event.series == Series::Standard && event.event != "w" && cal_event.race.entrants == Entrants::Open { 30 } else { 15 },
-        time_limit: 24,
+        start_delay: event.start_delay.map_or_else(|| if event.series == Series::Standard && event.event != "w" && cal_event.race.entrants == Entrants::Open { 30 } else { 15 }, Into::into),
+        time_limit: event.time_limit.unwrap_or(event::configure::DEFAULT_TIME_LIMIT).into(),
         time_limit_auto_complete: false,
-        streaming_required: !Environment::default().is_dev() && !cal_event.is_private_async_part(),
+        streaming_required: event.streaming_required && !Environment::default().is_dev() && !cal_event.is_private_async_part(),
         allow_comments: true,
         hide_comments: true,
-        allow_prerace_chat: event.series != Series::Standard || event.event != "8" || cal_event.race.phase.as_ref().is_none_or(|phase| phase != "Qualifier"),
-        allow_midrace_chat: event.series != Series::Standard || event.event != "8" || cal_event.race.phase.as_ref().is_none_or(|phase| phase != "Qualifier"),
+        allow_prerace_chat: cal_event.race.allow_prerace_chat.unwrap_or(event.allow_prerace_chat),
+        allow_midrace_chat: cal_event.race.allow_midrace_chat.unwrap_or(event.allow_midrace_chat),
         allow_non_entrant_chat: false, // only affects the race while it's ongoing, so !monitor still works
         chat_message_delay: 0,
-        info_user, info_bot, auto_start,
+        info_user, info_bot, unlisted, auto_start,
     }
 }
 
@@ -2059,13 +3166,37 @@ async fn set_bot_raceinfo(ctx: &RaceContext<GlobalState>, seed: &seed::Data, rsl
     )).await
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 struct Breaks {
     duration: Duration,
     interval: Duration,
 }
 
+/// The reason a breaks configuration is rejected by the `!breaks` command.
+enum InvalidBreaks {
+    TooShort,
+    TooFrequent,
+    TooLate,
+}
+
 impl Breaks {
+    /// Checks whether these breaks would be accepted by the `!breaks` command.
+    fn validate(&self) -> Result<(), InvalidBreaks> {
+        if self.duration < Duration::from_secs(60) {
+            Err(InvalidBreaks::TooShort)
+        } else if self.interval < self.duration + Duration::from_secs(5 * 60) {
+            Err(InvalidBreaks::TooFrequent)
+        } else if self.duration + self.interval >= Duration::from_secs(24 * 60 * 60) {
+            Err(InvalidBreaks::TooLate)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
     fn format(&self, language: Language) -> String {
         if let French = language {
             format!("{} toutes les {}", French.format_duration(self.duration, true), French.format_duration(self.interval, true))
@@ -2087,6 +3218,33 @@ impl FromStr for Breaks {
     }
 }
 
+/// The resolved inputs of the last seed rolled in a practice room, so `!reroll` can roll another seed with the same settings.
+#[derive(Clone)]
+enum LastRoll {
+    Regular {
+        settings: serde_json::Map<String, Json>,
+        unlock_spoiler_log: UnlockSpoilerLog,
+        language: Language,
+        article: &'static str,
+        description: String,
+    },
+    Rsl {
+        preset: VersionedRslPreset,
+        world_count: u8,
+        unlock_spoiler_log: UnlockSpoilerLog,
+        language: Language,
+        article: &'static str,
+        description: String,
+    },
+    Tfb {
+        version: &'static str,
+        unlock_spoiler_log: UnlockSpoilerLog,
+        language: Language,
+        article: &'static str,
+        description: String,
+    },
+}
+
 #[derive(Default)]
 enum RaceState {
     #[default]
@@ -2106,9 +3264,40 @@ struct OfficialRaceData {
     event: event::Data<'static>,
     goal: Goal,
     restreams: HashMap<Url, RestreamState>,
+    /// Languages whose restreams must be ready before auto-start is unlocked. If empty, all restreams are required.
+    required_restream_languages: HashSet<Language>,
     entrants: Vec<String>,
     fpa_invoked: bool,
     scores: HashMap<String, Option<tfb::Score>>,
+    /// Whether a race monitor has confirmed the reported Triforce Blitz scores, allowing the result to be recorded.
+    scores_confirmed: bool,
+    alternates: Vec<Alternate>,
+    /// Names of the Mido's House Multiworld rooms created for this race. Shared with the task that rolls the seed, since that's where the rooms are created.
+    mw_rooms: ArcRwLock<Vec<String>>,
+    /// Whether the race room is unlisted. Private async parts are always unlisted, but organizers can also unlist other rooms using `!unlist`.
+    unlisted: bool,
+    /// The team of each racing entrant, by racetime.gg user ID.
+    teams_by_entrant: HashMap<String, Id<Teams>>,
+    /// Co-op teams whose members have already been told that a teammate finished.
+    partner_finish_notified: HashSet<Id<Teams>>,
+}
+
+impl OfficialRaceData {
+    /// Whether the race room should currently auto-start, i.e. there are no restreams or all required restreams are ready.
+    fn auto_start(&self) -> bool {
+        self.cal_event.is_private_async_part() || self.restreams.values()
+            .filter(|state| self.required_restream_languages.is_empty() || state.language.is_some_and(|language| self.required_restream_languages.contains(&language)))
+            .all(|state| state.ready)
+    }
+}
+
+/// A player who is invited in place of a team member who declines the race or doesn't join in time.
+#[derive(Clone)]
+struct Alternate {
+    /// racetime.gg user IDs of the racing members of the team this is an alternate for.
+    team_members: Vec<String>,
+    racetime_id: String,
+    display_name: String,
 }
 
 #[derive(Default, Clone)]
@@ -2118,16 +3307,43 @@ struct RestreamState {
     ready: bool,
 }
 
+/// Whether anyone has actually joined the race, as opposed to only having been invited, requesting to join, or having declined an invitation.
+fn has_joined_entrants(data: &RaceData) -> bool {
+    data.entrants.iter().any(|entrant| !matches!(entrant.status.value, EntrantStatusValue::Invited | EntrantStatusValue::Requested | EntrantStatusValue::Declined))
+}
+
+/// The parts of a [`Handler`]'s state that can't be recovered from race data or the database, kept in case the connection to the room drops.
+struct RoomSnapshot {
+    race_state: ArcRwLock<RaceState>,
+    breaks: Option<Breaks>,
+    fpa_enabled: bool,
+    fpa_invoked: bool,
+    locked: bool,
+    on_hold: bool,
+    password_sent: bool,
+}
+
 struct Handler {
     official_data: Option<OfficialRaceData>,
     high_seed_name: String,
     low_seed_name: String,
     breaks: Option<Breaks>,
     break_notifications: Option<tokio::task::JoinHandle<()>>,
+    /// The stream delay required for this official race, which can be overridden using `!streamdelay`.
+    stream_delay: Option<watch::Sender<Duration>>,
+    /// The task forwarding seed rolling updates to the room, so it can be aborted using `!seed cancel`.
+    seed_roll: Mutex<Option<tokio::task::JoinHandle<Result<(), Error>>>>,
     goal_notifications: Option<tokio::task::JoinHandle<()>>,
+    /// Started when a Triforce Blitz race ends and aborted once the scores have been confirmed. Notifies the organizers if scores are still missing or unconfirmed when the score wait runs out.
+    cleanup_timeout: Option<tokio::task::JoinHandle<()>>,
+    last_roll: Mutex<Option<LastRoll>>,
+    /// How many times `!reroll` has been used in this room, limited to [`MAX_REROLLS`].
+    rerolls: u8,
     start_saved: bool,
     fpa_enabled: bool,
     locked: bool,
+    /// Set while some players are registered on multiple teams. Unlike `locked`, this only holds back the seed, so players can still draft settings in the meantime.
+    on_hold: bool,
     password_sent: bool,
     race_state: ArcRwLock<RaceState>,
 }
@@ -2135,12 +3351,19 @@ struct Handler {
 impl Handler {
     /// For `existing_state`, `Some(None)` means this is an existing race room with unknown state, while `None` means this is a new race room.
     async fn should_handle_inner(race_data: &RaceData, global_state: Arc<GlobalState>, existing_state: Option<Option<&Self>>) -> bool {
-        if Goal::from_race_data(race_data).is_none() { return false }
+        let Some(goal) = Goal::from_race_data(race_data, global_state.handle_user_opened_standard_rooms) else { return false };
         if let Some(existing_state) = existing_state {
             if let Some(existing_state) = existing_state {
                 if let Some(ref official_data) = existing_state.official_data {
-                    if race_data.entrants.iter().any(|entrant| entrant.status.value == EntrantStatusValue::Done && official_data.scores.get(&entrant.user.id).is_some_and(|score| score.is_none())) {
-                        return true
+                    let score_wait = official_data.event.score_wait.unwrap_or_else(|| official_data.goal.default_score_wait());
+                    if race_data.ended_at.is_none_or(|ended_at| TimeDelta::from_std(score_wait).is_ok_and(|score_wait| Utc::now() < ended_at + score_wait)) {
+                        if race_data.entrants.iter().any(|entrant| entrant.status.value == EntrantStatusValue::Done && official_data.scores.get(&entrant.user.id).is_some_and(|score| score.is_none())) {
+                            return true
+                        }
+                        // once scores have been reported, keep the room around until a race monitor has confirmed them, but not past the score wait so a room can't block a clean shutdown forever
+                        if !official_data.scores_confirmed && official_data.scores.values().any(Option::is_some) {
+                            return true
+                        }
                     }
                 }
             }
@@ -2150,7 +3373,12 @@ impl Handler {
                     unlock!();
                     return false
                 }
-                assert!(clean_shutdown.open_rooms.insert(race_data.url.clone()));
+                assert!(clean_shutdown.open_rooms.insert(race_data.url.clone(), OpenRoom {
+                    unlisted: race_data.unlisted,
+                    official: false,
+                    abort_handle: None,
+                    goal,
+                }).is_none());
             });
         }
         if let RaceStatusValue::Finished | RaceStatusValue::Cancelled = race_data.status.value { return false }
@@ -2169,6 +3397,11 @@ impl Handler {
 
     async fn can_monitor(&self, ctx: &RaceContext<GlobalState>, is_monitor: bool, msg: &ChatMessage) -> sqlx::Result<bool> {
         if is_monitor { return Ok(true) }
+        self.is_organizer(ctx, msg).await
+    }
+
+    /// Whether the sender of the message is an organizer of this official race's event. Unlike [`Handler::can_monitor`], this doesn't include race monitors.
+    async fn is_organizer(&self, ctx: &RaceContext<GlobalState>, msg: &ChatMessage) -> sqlx::Result<bool> {
         if let Some(OfficialRaceData { ref event, .. }) = self.official_data {
             if let Some(UserData { ref id, .. }) = msg.user {
                 if let Some(user) = User::from_racetime(&ctx.global_state.db_pool, id).await? {
@@ -2179,6 +3412,64 @@ impl Handler {
         Ok(false)
     }
 
+    /// Lists the commands that can currently be used in this room, depending on the goal and the state of the race.
+    async fn send_help(&self, ctx: &RaceContext<GlobalState>, goal: Goal) -> Result<(), Error> {
+        let french = matches!(goal.language(), French);
+        // (English, French)
+        let mut commands = Vec::<(&str, &str)>::default();
+        if let RaceStatusValue::Open | RaceStatusValue::Invitational = ctx.data().await.status.value {
+            lock!(@read state = self.race_state; match *state {
+                RaceState::Init => {
+                    commands.push(("!seed <preset>: roll a seed", "!seed <preset> : générer une seed"));
+                    commands.push(("!seed preview <preset>: see what a preset would roll", "!seed preview <preset> : voir ce qu'un preset générerait"));
+                    commands.push(("!presets: list presets", "!presets : liste des presets"));
+                    if goal.draft_kind().is_some() {
+                        commands.push(("!settings: list draftable settings", "!settings : liste des settings du draft"));
+                    }
+                }
+                RaceState::Draft { .. } => {
+                    commands.push(("!ban <setting>: ban a setting", "!ban <setting> : bannir un setting"));
+                    commands.push(("!draft <setting> <value>: pick a setting", "!draft <setting> <configuration> : choisir un setting"));
+                    commands.push(("!skip: skip your ban or pick", "!skip : passer votre ban ou pick"));
+                    commands.push(("!first / !second: choose the draft order", "!first / !second : choisir l'ordre du draft"));
+                    commands.push(("!yes / !no: answer a yes/no question", "!yes / !no : répondre à une question"));
+                    commands.push(("!order: show the draft order", "!order : voir l'ordre du draft"));
+                    commands.push(("!state: show what has been banned and picked so far", "!state : voir les settings déjà bannis et choisis"));
+                    commands.push(("!settings: list available settings", "!settings : liste des settings disponibles"));
+                }
+                RaceState::Rolling => commands.push(("!seed cancel: stop rolling the seed", "!seed cancel : annuler la génération de la seed")),
+                RaceState::Rolled(ref seed) => {
+                    commands.push(("!seed: post the seed again", "!seed : reposter la seed"));
+                    if !self.is_official() {
+                        commands.push(("!reroll: roll a new seed with the same settings", "!reroll : générer une nouvelle seed avec les mêmes settings"));
+                    }
+                    if let Some(seed::Files::MidosHouse { .. }) = seed.files {
+                        commands.push(("!seed upload: copy the seed to ootrandomizer.com (race monitors only)", "!seed upload : copier la seed sur ootrandomizer.com (race monitors uniquement)"));
+                    }
+                    commands.push(("!rolled: when the seed was rolled", "!rolled : date de génération de la seed"));
+                    commands.push(("!passwordlock: whether the seed has a password", "!passwordlock : la seed a-t-elle un mot de passe ?"));
+                }
+                RaceState::SpoilerSent => {}
+            });
+        }
+        commands.push(("!changelog: randomizer release notes", "!changelog : notes de version du randomizer"));
+        commands.push(("!event: info about the event", "!event : informations sur l'événement"));
+        commands.push(("!breaks: show or set up breaks", "!breaks : voir ou configurer les pauses"));
+        if self.fpa_enabled {
+            commands.push(("!fpa [reason <reason>]: invoke the fair play agreement", "!fpa [reason <raison>] : invoquer le FPA"));
+        }
+        commands.push(("!streams: list entrants' streams", "!streams : liste des streams des participants"));
+        if self.is_official() {
+            commands.push(("!time: when the race is scheduled to start", "!time : horaire prévu de la race"));
+        }
+        if self.official_data.as_ref().is_some_and(|OfficialRaceData { event, .. }| event.charity_donation_url.is_some()) {
+            commands.push(("!charity: donation total and link", "!charity : total des dons et lien"));
+        }
+        ctx.say(if french { "Commandes disponibles :" } else { "Available commands:" }).await?;
+        say_joined(ctx, commands.into_iter().map(|(en, fr)| if french { fr.to_owned() } else { en.to_owned() })).await?;
+        Ok(())
+    }
+
     async fn send_settings(&self, ctx: &RaceContext<GlobalState>, preface: &str, reply_to: &str) -> Result<(), Error> {
         let goal = self.goal(ctx).await.to_racetime()?;
         if let Some(draft_kind) = goal.draft_kind() {
@@ -2211,6 +3502,22 @@ impl Handler {
                     ctx.say(setting).await?;
                 }
             }
+        } else if let Ok(settings) = goal.single_settings() {
+            let notable_settings = seed::notable_settings(&settings);
+            if notable_settings.is_empty() {
+                ctx.say(if let French = goal.language() {
+                    format!("Désolé {reply_to}, cet événement utilise les settings par défaut.")
+                } else {
+                    format!("Sorry {reply_to}, this event uses the default settings.")
+                }).await?;
+            } else {
+                ctx.say(if let French = goal.language() {
+                    "Settings de cet événement :"
+                } else {
+                    "Settings for this event:"
+                }).await?;
+                say_joined(ctx, notable_settings).await?;
+            }
         } else {
             ctx.say(format!("Sorry {reply_to}, this event doesn't have a settings draft.")).await?;
         }
@@ -2223,12 +3530,20 @@ impl Handler {
         let RaceState::Draft { state: ref draft, unlock_spoiler_log } = *state else { unreachable!() };
         let step = draft.next_step(draft_kind, self.official_data.as_ref().and_then(|OfficialRaceData { cal_event, .. }| cal_event.race.game), &mut draft::MessageContext::RaceTime { high_seed_name: &self.high_seed_name, low_seed_name: &self.low_seed_name, reply_to: "friend" }).await.to_racetime()?;
         if let draft::StepKind::Done(settings) = step.kind {
+            if self.on_hold {
+                ctx.say(if let French = goal.language() {
+                    "Le draft des settings est terminé. La seed sera générée une fois que les organisateurs auront résolu les inscriptions en double."
+                } else {
+                    "The settings draft is complete. The seed will be rolled once tournament organizers have resolved the duplicate team registrations."
+                }).await?;
+                return Ok(())
+            }
             let (article, description) = if let French = goal.language() {
                 ("une", format!("seed avec {}", step.message))
             } else {
                 ("a", format!("seed with {}", step.message))
             };
-            self.roll_seed(ctx, goal.preroll_seeds(), goal.rando_version(self.official_data.as_ref().map(|OfficialRaceData { event, .. }| (event.series, &*event.event))), settings, unlock_spoiler_log, goal.language(), article, description).await;
+            self.roll_seed(ctx, goal.preroll_mode(self.official_data.as_ref().map(|OfficialRaceData { event, .. }| event)), goal.rando_version(self.official_data.as_ref().map(|OfficialRaceData { event, .. }| event)), settings, unlock_spoiler_log, goal.language(), article, description).await?;
             return Ok(())
         } else {
             ctx.say(step.message).await?;
@@ -2299,14 +3614,25 @@ impl Handler {
         Ok(())
     }
 
-    async fn roll_seed_inner(&self, ctx: &RaceContext<GlobalState>, delay_until: Option<DateTime<Utc>>, mut updates: mpsc::Receiver<SeedRollUpdate>, language: Language, article: &'static str, description: String) {
+    async fn seed_roll_log(&self, ctx: &RaceContext<GlobalState>, settings: Option<serde_json::Map<String, Json>>) -> SeedRollLog {
+        SeedRollLog {
+            room: format!("https://{}{}", racetime_host(), ctx.data().await.url),
+            goal: self.goal(ctx).await.ok(),
+            requested_at: Utc::now(),
+            settings,
+        }
+    }
+
+    async fn roll_seed_inner(&self, ctx: &RaceContext<GlobalState>, delay_until: Option<DateTime<Utc>>, mut updates: mpsc::Receiver<SeedRollUpdate>, roll_log: Option<SeedRollLog>, language: Language, article: &'static str, description: String) {
         let db_pool = ctx.global_state.db_pool.clone();
         let ctx = ctx.clone();
         let state = self.race_state.clone();
         let official_data = self.official_data.clone();
-        tokio::spawn(async move {
-            lock!(@write state = state; *state = RaceState::Rolling); //TODO ensure only one seed is rolled at a time
-            let mut seed_state = None::<SeedRollUpdate>;
+        let expected_gen_time = self.goal(&ctx).await.ok().map(|goal| goal.expected_gen_time());
+        let seed_roll = tokio::spawn(async move {
+            let _rolling_seed = RollingSeed::new(Arc::clone(&ctx.global_state));
+            lock!(@write state = state; *state = RaceState::Rolling);
+            let mut seed_state = None::<(SeedRollUpdate, DateTime<Utc>)>;
             if let Some(delay) = delay_until.and_then(|delay_until| (delay_until - Utc::now()).to_std().ok()) {
                 // don't want to give an unnecessarily exact estimate if the room was opened automatically 30 or 60 minutes ahead of start
                 let display_delay = if delay > Duration::from_secs(14 * 60) && delay < Duration::from_secs(16 * 60) {
@@ -2325,67 +3651,466 @@ impl Handler {
                 loop {
                     select! {
                         () = &mut sleep => {
-                            if let Some(update) = seed_state.take() {
-                                update.handle(&db_pool, &ctx, &state, official_data.as_ref(), language, article, &description).await?;
+                            if let Some((update, received_at)) = seed_state.take() {
+                                update.handle(&db_pool, &ctx, &state, official_data.as_ref(), roll_log.as_ref(), received_at, expected_gen_time, language, article, &description).await?;
                             }
                             while let Some(update) = updates.recv().await {
-                                update.handle(&db_pool, &ctx, &state, official_data.as_ref(), language, article, &description).await?;
+                                update.handle(&db_pool, &ctx, &state, official_data.as_ref(), roll_log.as_ref(), Utc::now(), expected_gen_time, language, article, &description).await?;
                             }
                             break
                         }
-                        Some(update) = updates.recv() => seed_state = Some(update),
+                        Some(update) = updates.recv() => seed_state = Some((update, Utc::now())),
                     }
                 }
             } else {
                 while let Some(update) = updates.recv().await {
-                    update.handle(&db_pool, &ctx, &state, official_data.as_ref(), language, article, &description).await?;
+                    update.handle(&db_pool, &ctx, &state, official_data.as_ref(), roll_log.as_ref(), Utc::now(), expected_gen_time, language, article, &description).await?;
                 }
             }
             Ok::<_, Error>(())
         });
+        lock!(seed_roll_handle = self.seed_roll; *seed_roll_handle = Some(seed_roll));
     }
 
-    async fn roll_seed(&self, ctx: &RaceContext<GlobalState>, preroll: PrerollMode, version: VersionedBranch, settings: serde_json::Map<String, Json>, unlock_spoiler_log: UnlockSpoilerLog, language: Language, article: &'static str, description: String) {
-        let official_start = self.official_data.as_ref().map(|official_data| official_data.cal_event.start().expect("handling room for official race without start time"));
-        let delay_until = official_start.map(|start| start - TimeDelta::minutes(15));
-        self.roll_seed_inner(ctx, delay_until, Arc::clone(&ctx.global_state).roll_seed(preroll, true, delay_until, version, settings, unlock_spoiler_log), language, article, description).await;
+    /// Invites an alternate in place of a team member who declined or didn't join in time, and lets the room and the event's organizers know.
+    async fn promote_alternate(ctx: &RaceContext<GlobalState>, alternate: &Alternate, missing: &str, organizer_channel: Option<ChannelId>, language: Language) -> Result<(), Error> {
+        ctx.invite_user(&alternate.racetime_id).await?;
+        ctx.say(if let French = language {
+            format!("{missing} n'a pas rejoint la race, donc j'ai invité {} en remplacement.", alternate.display_name)
+        } else {
+            format!("{missing} hasn't joined the race, so I've invited {} as an alternate.", alternate.display_name)
+        }).await?;
+        if let Some(organizer_channel) = organizer_channel {
+            organizer_channel.say(&*ctx.global_state.discord_ctx.read().await, MessageBuilder::default()
+                .push("alternate ")
+                .push_safe(&alternate.display_name)
+                .push(" invited in place of ")
+                .push_safe(missing)
+                .push(": <https://")
+                .push(racetime_host())
+                .push(&ctx.data().await.url)
+                .push('>')
+                .build()
+            ).await.to_racetime()?;
+        }
+        Ok(())
     }
 
-    async fn roll_rsl_seed(&self, ctx: &RaceContext<GlobalState>, preset: VersionedRslPreset, world_count: u8, unlock_spoiler_log: UnlockSpoilerLog, language: Language, article: &'static str, description: String) {
-        let official_start = self.official_data.as_ref().map(|official_data| official_data.cal_event.start().expect("handling room for official race without start time"));
-        let delay_until = official_start.map(|start| start - TimeDelta::minutes(15));
-        self.roll_seed_inner(ctx, delay_until, Arc::clone(&ctx.global_state).roll_rsl_seed(delay_until, preset, world_count, unlock_spoiler_log), language, article, description).await;
+    /// Posts the link and hash of an already rolled seed again, e.g. for entrants who joined late.
+    ///
+    /// The seed password is not included since entrants receive it when the countdown starts.
+    async fn repost_seed(&self, ctx: &RaceContext<GlobalState>, goal: Goal, seed: &seed::Data) -> Result<(), Error> {
+        let Some(ref files) = seed.files else {
+            ctx.say(if let French = goal.language() {
+                "Le lien de la seed n'est pas disponible pour cette race."
+            } else {
+                "The seed link isn't available for this race."
+            }).await?;
+            return Ok(())
+        };
+        let extra = seed.extra(Utc::now()).await.to_racetime()?;
+        let seed_url = seed_url(files);
+        ctx.say(if goal.is_bilingual() {
+            format!("The seed for this race is / La seed de cette race est : {seed_url}")
+        } else if let French = goal.language() {
+            format!("La seed de cette race est : {seed_url}")
+        } else {
+            format!("The seed for this race is: {seed_url}")
+        }).await?;
+        if let Some(file_hash) = extra.file_hash {
+            ctx.say(format_hash(file_hash)).await?;
+        }
+        let unlock_spoiler_log = match files {
+            seed::Files::MidosHouse { locked_spoiler_log_path: None, .. } => UnlockSpoilerLog::Now,
+            _ => self.unlock_spoiler_log_for_event(goal.unlock_spoiler_log(self.official_data.as_ref().map(|OfficialRaceData { event, .. }| event), self.is_official(), false)),
+        };
+        match unlock_spoiler_log {
+            UnlockSpoilerLog::Now => ctx.say(if let French = goal.language() {
+                "Le spoiler log est également disponible sur le lien de la seed."
+            } else {
+                "The spoiler log is also available on the seed page."
+            }).await?,
+            UnlockSpoilerLog::Progression => ctx.say("The progression spoiler is also available on the seed page. The full spoiler will be available there after the race.").await?,
+            UnlockSpoilerLog::After | UnlockSpoilerLog::Organizers => ctx.say(if goal.is_bilingual() {
+                "The spoiler log will be available on the seed page after the race. / Le spoiler log sera disponible sur le lien de la seed après la race."
+            } else if let French = goal.language() {
+                "Le spoiler log sera disponible sur le lien de la seed après la race."
+            } else {
+                "The spoiler log will be available on the seed page after the race."
+            }).await?,
+            UnlockSpoilerLog::Never => {}
+        }
+        if extra.password.is_some() {
+            ctx.say(if let French = goal.language() {
+                "Cette seed est protégée par mot de passe. Vous recevrez le mot de passe au début du compte à rebours."
+            } else {
+                "Please note that this seed is password protected. You will receive the password to start a file ingame as soon as the countdown starts."
+            }).await?;
+        }
+        Ok(())
     }
 
-    async fn roll_tfb_seed(&self, ctx: &RaceContext<GlobalState>, version: &'static str, unlock_spoiler_log: UnlockSpoilerLog, language: Language, article: &'static str, description: String) {
-        let official_start = self.official_data.as_ref().map(|official_data| official_data.cal_event.start().expect("handling room for official race without start time"));
-        let delay_until = official_start.map(|start| start - TimeDelta::minutes(15));
-        self.roll_seed_inner(ctx, delay_until, Arc::clone(&ctx.global_state).roll_tfb_seed(delay_until, version, Some(format!("https://{}{}", racetime_host(), ctx.data().await.url)), unlock_spoiler_log), language, article, description).await;
-    }
+    /// Generates a copy of a locally rolled seed on ootrandomizer.com so it can be opened in the web patcher. The canonical seed for the race is not changed.
+    async fn upload_seed(&self, ctx: &RaceContext<GlobalState>, goal: Goal, reply_to: &str, seed: seed::Data) -> Result<(), Error> {
+        #[derive(Deserialize)]
+        struct UploadSpoilerLog {
+            #[serde(rename = ":seed")]
+            seed: String,
+            file_hash: [HashIcon; 5],
+            settings: serde_json::Map<String, Json>,
+        }
+
+        let Some(seed::Files::MidosHouse { file_stem, locked_spoiler_log_path }) = seed.files else {
+            ctx.say(if let French = goal.language() {
+                format!("Désolé {reply_to}, seules les seeds générées par Mido's House peuvent être mises en ligne sur ootrandomizer.com.")
+            } else {
+                format!("Sorry {reply_to}, only seeds rolled by Mido's House itself can be uploaded to ootrandomizer.com.")
+            }).await?;
+            return Ok(())
+        };
+        let Some(version) = goal.try_rando_version(self.official_data.as_ref().map(|OfficialRaceData { event, .. }| event)) else {
+            ctx.say(if let French = goal.language() {
+                format!("Désolé {reply_to}, les seeds random settings ne peuvent pas être mises en ligne sur ootrandomizer.com.")
+            } else {
+                format!("Sorry {reply_to}, random settings seeds can't be uploaded to ootrandomizer.com.")
+            }).await?;
+            return Ok(())
+        };
+        let locked = locked_spoiler_log_path.is_some();
+        let spoiler_path = locked_spoiler_log_path.map(PathBuf::from).unwrap_or_else(|| Path::new(seed::DIR).join(format!("{file_stem}_Spoiler.json")));
+        let Ok(log) = serde_json::from_str::<UploadSpoilerLog>(&fs::read_to_string(&spoiler_path).await.to_racetime()?) else {
+            ctx.say(if let French = goal.language() {
+                format!("Désolé {reply_to}, je n'ai pas pu lire le spoiler log de cette seed.")
+            } else {
+                format!("Sorry {reply_to}, I couldn't read the spoiler log for this seed.")
+            }).await?;
+            return Ok(())
+        };
+        let world_count = log.settings.get("world_count").and_then(|world_count| world_count.as_u64()).map_or(1, |world_count| world_count.try_into().unwrap_or(u8::MAX));
+        let Some(web_version) = ctx.global_state.ootr_api_client.can_roll_on_web(None, &version, world_count, UnlockSpoilerLog::Now).await else {
+            ctx.say(if let French = goal.language() {
+                format!("Désolé {reply_to}, la version du randomizer utilisée pour cette seed n'est pas disponible sur ootrandomizer.com, donc elle ne peut pas y être mise en ligne.")
+            } else {
+                format!("Sorry {reply_to}, the randomizer version used for this seed isn't available on ootrandomizer.com, so it can't be uploaded there.")
+            }).await?;
+            return Ok(())
+        };
+        ctx.say(if let French = goal.language() {
+            "Mise en ligne de la seed sur ootrandomizer.com…"
+        } else {
+            "Uploading the seed to ootrandomizer.com…"
+        }).await?;
+        let mut settings = log.settings;
+        settings.insert(format!("seed"), json!(log.seed));
+        match ctx.global_state.ootr_api_client.upload_seed(web_version, locked, settings).await {
+            Ok((id, file_hash)) if file_hash == log.file_hash => ctx.say(if let French = goal.language() {
+                format!("@entrants Cette seed est également disponible sur ootrandomizer.com : https://ootrandomizer.com/seed/get?id={id}")
+            } else {
+                format!("@entrants This seed is also available on ootrandomizer.com: https://ootrandomizer.com/seed/get?id={id}")
+            }).await?,
+            Ok((_, _)) => ctx.say(if let French = goal.language() {
+                format!("Désolé {reply_to}, la seed générée sur ootrandomizer.com ne correspond pas à celle de cette race, probablement parce que la version du randomizer a changé depuis.")
+            } else {
+                format!("Sorry {reply_to}, the seed generated on ootrandomizer.com doesn't match the one for this race, probably because the randomizer version has changed since.")
+            }).await?,
+            Err(e) => ctx.say(if let French = goal.language() {
+                format!("Désolé {reply_to}, la mise en ligne de la seed a échoué : {e}")
+            } else {
+                format!("Sorry {reply_to}, uploading the seed failed: {e}")
+            }).await?,
+        }
+        Ok(())
+    }
+
+    /// Upgrades [`UnlockSpoilerLog::After`] to [`UnlockSpoilerLog::Organizers`] for official races of events configured to send spoiler logs to their organizers.
+    fn unlock_spoiler_log_for_event(&self, unlock_spoiler_log: UnlockSpoilerLog) -> UnlockSpoilerLog {
+        match (unlock_spoiler_log, &self.official_data) {
+            (UnlockSpoilerLog::After, Some(OfficialRaceData { event, .. })) if event.spoiler_to_organizers && event.discord_organizer_channel.is_some() => UnlockSpoilerLog::Organizers,
+            (unlock_spoiler_log, _) => unlock_spoiler_log,
+        }
+    }
+
+    async fn roll_seed(&self, ctx: &RaceContext<GlobalState>, preroll: PrerollMode, version: VersionedBranch, settings: serde_json::Map<String, Json>, unlock_spoiler_log: UnlockSpoilerLog, language: Language, article: &'static str, description: String) -> Result<(), Error> {
+        if !self.is_official() {
+            lock!(last_roll = self.last_roll; *last_roll = Some(LastRoll::Regular { settings: settings.clone(), description: description.clone(), unlock_spoiler_log, language, article }));
+        }
+        let unlock_spoiler_log = self.unlock_spoiler_log_for_event(unlock_spoiler_log);
+        if settings.get("world_count").and_then(|world_count| world_count.as_u64()).is_some_and(|world_count| world_count > 1) {
+            // multiworld seeds may have to wait in the queue for a while, so remember them in case the bot is restarted in the meantime
+            sqlx::query!("INSERT INTO seed_queue (room, goal, settings, unlock_spoiler_log, language, description, queued_at) VALUES ($1, $2, $3, $4, $5, $6, NOW()) ON CONFLICT (room) DO UPDATE SET goal = EXCLUDED.goal, settings = EXCLUDED.settings, unlock_spoiler_log = EXCLUDED.unlock_spoiler_log, language = EXCLUDED.language, description = EXCLUDED.description",
+                format!("https://{}{}", racetime_host(), ctx.data().await.url),
+                self.goal(ctx).await.to_racetime()?.as_str(),
+                sqlx::types::Json(&settings) as _,
+                unlock_spoiler_log as _,
+                language as _,
+                &description,
+            ).execute(&ctx.global_state.db_pool).await.to_racetime()?;
+        }
+        let official_start = self.official_data.as_ref().map(|official_data| official_data.cal_event.start().expect("handling room for official race without start time"));
+        let delay_until = official_start.map(|start| start - TimeDelta::minutes(15));
+        let roll_log = self.seed_roll_log(ctx, Some(settings.clone())).await;
+        self.roll_seed_inner(ctx, delay_until, Arc::clone(&ctx.global_state).roll_seed(preroll, true, self.is_official(), delay_until, version, settings, unlock_spoiler_log), Some(roll_log), language, article, description).await;
+        Ok(())
+    }
+
+    async fn roll_debug_seed(&self, ctx: &RaceContext<GlobalState>, version: VersionedBranch, settings: serde_json::Map<String, Json>, unlock_spoiler_log: UnlockSpoilerLog, rng_seed: String, language: Language, article: &'static str, description: String) {
+        let roll_log = self.seed_roll_log(ctx, Some(settings.clone())).await;
+        self.roll_seed_inner(ctx, None, Arc::clone(&ctx.global_state).roll_debug_seed(version, settings, unlock_spoiler_log, rng_seed), Some(roll_log), language, article, description).await;
+    }
+
+    async fn roll_rsl_seed(&self, ctx: &RaceContext<GlobalState>, preset: VersionedRslPreset, world_count: u8, unlock_spoiler_log: UnlockSpoilerLog, language: Language, article: &'static str, description: String) {
+        if !self.is_official() {
+            lock!(last_roll = self.last_roll; *last_roll = Some(LastRoll::Rsl { preset: preset.clone(), description: description.clone(), world_count, unlock_spoiler_log, language, article }));
+        }
+        let unlock_spoiler_log = self.unlock_spoiler_log_for_event(unlock_spoiler_log);
+        let official_start = self.official_data.as_ref().map(|official_data| official_data.cal_event.start().expect("handling room for official race without start time"));
+        let delay_until = official_start.map(|start| start - TimeDelta::minutes(15));
+        let roll_log = self.seed_roll_log(ctx, None).await;
+        self.roll_seed_inner(ctx, delay_until, Arc::clone(&ctx.global_state).roll_rsl_seed(delay_until, preset, world_count, unlock_spoiler_log), Some(roll_log), language, article, description).await;
+    }
+
+    async fn roll_tfb_seed(&self, ctx: &RaceContext<GlobalState>, version: &'static str, unlock_spoiler_log: UnlockSpoilerLog, language: Language, article: &'static str, description: String) {
+        if !self.is_official() {
+            lock!(last_roll = self.last_roll; *last_roll = Some(LastRoll::Tfb { version, description: description.clone(), unlock_spoiler_log, language, article }));
+        }
+        let official_start = self.official_data.as_ref().map(|official_data| official_data.cal_event.start().expect("handling room for official race without start time"));
+        let delay_until = official_start.map(|start| start - TimeDelta::minutes(15));
+        let roll_log = self.seed_roll_log(ctx, None).await;
+        self.roll_seed_inner(ctx, delay_until, Arc::clone(&ctx.global_state).roll_tfb_seed(delay_until, version, Some(format!("https://{}{}", racetime_host(), ctx.data().await.url)), unlock_spoiler_log), Some(roll_log), language, article, description).await;
+    }
 
     async fn queue_existing_seed(&self, ctx: &RaceContext<GlobalState>, seed: seed::Data, language: Language, article: &'static str, description: String) {
         let official_start = self.official_data.as_ref().map(|official_data| official_data.cal_event.start().expect("handling room for official race without start time"));
         let delay_until = official_start.map(|start| start - TimeDelta::minutes(15));
         let (tx, rx) = mpsc::channel(1);
-        tx.send(SeedRollUpdate::Done { rsl_preset: None, unlock_spoiler_log: UnlockSpoilerLog::After, seed }).await.unwrap();
-        self.roll_seed_inner(ctx, delay_until, rx, language, article, description).await;
+        tx.send(SeedRollUpdate::Done { rsl_preset: None, unlock_spoiler_log: UnlockSpoilerLog::After, rando_commit: None, attempts: None, seed }).await.unwrap();
+        // no seed is being rolled, so there is no outcome to log
+        self.roll_seed_inner(ctx, delay_until, rx, None, language, article, description).await;
+    }
+
+    /// Records who locked or unlocked this room so organizers can review it later, and so the lock survives a bot restart.
+    async fn record_lock(ctx: &RaceContext<GlobalState>, msg: &ChatMessage, locked: bool) -> Result<(), Error> {
+        sqlx::query!(
+            "INSERT INTO room_locks (room, racetime_id, locked) VALUES ($1, $2, $3)",
+            format!("https://{}{}", racetime_host(), ctx.data().await.url), msg.user.as_ref().map(|user| &*user.id), locked,
+        ).execute(&ctx.global_state.db_pool).await.to_racetime()?;
+        Ok(())
+    }
+
+    async fn set_unlisted(&mut self, ctx: &RaceContext<GlobalState>, goal: Goal, msg: &ChatMessage, unlisted: bool) -> Result<(), Error> {
+        let reply_to = msg.user.as_ref().map_or("friend", |user| &user.name);
+        let Some(ref mut official_data) = self.official_data else {
+            ctx.say(if let French = goal.language() {
+                format!("Désolé {reply_to}, cette commande n'est disponible que pour les races officielles.")
+            } else {
+                format!("Sorry {reply_to}, this command is only available for official races.")
+            }).await?;
+            return Ok(())
+        };
+        if !unlisted && official_data.cal_event.is_private_async_part() {
+            ctx.say(if let French = goal.language() {
+                format!("Désolé {reply_to}, cette room est une partie privée d'un async et doit rester non listée.")
+            } else {
+                format!("Sorry {reply_to}, this room is a private async part and must stay unlisted.")
+            }).await?;
+            return Ok(())
+        }
+        if official_data.unlisted == unlisted {
+            ctx.say(if let French = goal.language() {
+                format!("{reply_to}, cette room est déjà {}.", if unlisted { "non listée" } else { "listée" })
+            } else {
+                format!("{reply_to}, this room is already {}.", if unlisted { "unlisted" } else { "listed" })
+            }).await?;
+            return Ok(())
+        }
+        let (access_token, _) = racetime::authorize_with_host(&ctx.global_state.host_info, &ctx.global_state.racetime_config.client_id, &ctx.global_state.racetime_config.client_secret, &ctx.global_state.http_client).await?;
+        room_options(
+            goal, &official_data.event, &official_data.cal_event,
+            ctx.data().await.info_user.clone().unwrap_or_default(),
+            ctx.data().await.info_bot.clone().unwrap_or_default(),
+            unlisted,
+            official_data.auto_start(),
+        ).await.edit_with_host(&ctx.global_state.host_info, &access_token, &ctx.global_state.http_client, CATEGORY, &ctx.data().await.slug).await?;
+        official_data.unlisted = unlisted;
+        sqlx::query!(
+            "INSERT INTO room_listing_changes (room, racetime_id, unlisted) VALUES ($1, $2, $3)",
+            format!("https://{}{}", racetime_host(), ctx.data().await.url), msg.user.as_ref().map(|user| &*user.id), unlisted,
+        ).execute(&ctx.global_state.db_pool).await.to_racetime()?;
+        ctx.say(if let French = goal.language() {
+            format!("Room {} par {reply_to}.", if unlisted { "retirée de la liste" } else { "ajoutée à la liste" })
+        } else {
+            format!("Room {} by {reply_to}.", if unlisted { "unlisted" } else { "listed" })
+        }).await?;
+        Ok(())
+    }
+
+    /// Handles `!fpa` and `!fpa reason <reason>`. In official races, the invocation is recorded along with the race time and optional reason for review by organizers.
+    async fn invoke_fpa(&mut self, ctx: &RaceContext<GlobalState>, goal: Goal, msg: &ChatMessage, reason: Option<String>) -> Result<(), Error> {
+        let reply_to = msg.user.as_ref().map_or("friend", |user| &user.name);
+        if self.fpa_enabled {
+            if let RaceStatusValue::Open | RaceStatusValue::Invitational = ctx.data().await.status.value {
+                ctx.say(if let French = goal.language() {
+                    "Le FPA ne peut pas être appelé avant que la race ne commence."
+                } else {
+                    "FPA cannot be invoked before the race starts."
+                }).await?;
+            } else {
+                let elapsed = ctx.data().await.started_at.and_then(|started_at| (Utc::now() - started_at).to_std().ok()).unwrap_or_default();
+                if let Some(OfficialRaceData { ref cal_event, ref restreams, ref mut fpa_invoked, ref event, .. }) = self.official_data {
+                    *fpa_invoked = true;
+                    if restreams.is_empty() {
+                        ctx.say(if_chain! {
+                            if let French = goal.language();
+                            if let TeamConfig::Solo = event.team_config;
+                            then {
+                                format!(
+                                    "@everyone Le FPA a été appelé par {reply_to}.{} La race sera re-timée après le fin de celle-ci.",
+                                    if let RaceSchedule::Async { .. } = cal_event.race.schedule { "" } else { " Le joueur qui ne l'a pas demandé peut continuer à jouer." },
+                                )
+                            } else {
+                                format!(
+                                    "@everyone FPA has been invoked by {reply_to}. T{}he race will be retimed once completed.",
+                                    if let RaceSchedule::Async { .. } = cal_event.race.schedule {
+                                        String::default()
+                                    } else {
+                                        format!(
+                                            "he {player_team} that did not call FPA can continue playing; t",
+                                            player_team = if let TeamConfig::Solo = event.team_config { "player" } else { "team" },
+                                        )
+                                    },
+                                )
+                            }
+                        }).await?;
+                    } else {
+                        ctx.say(if let French = goal.language() {
+                            format!("@everyone Le FPA a été appelé par {reply_to}. Merci d'arrêter de jouer, la race étant restreamée.")
+                        } else {
+                            format!("@everyone FPA has been invoked by {reply_to}. Please pause since this race is being restreamed.")
+                        }).await?;
+                    }
+                    sqlx::query!(
+                        "INSERT INTO fpa_invocations (race, invoker, elapsed, reason) VALUES ($1, $2, $3, $4)",
+                        cal_event.race.id as _, msg.user.as_ref().map(|user| &*user.id), elapsed as _, reason.as_deref(),
+                    ).execute(&ctx.global_state.db_pool).await.to_racetime()?;
+                    ctx.say(if let French = goal.language() {
+                        format!("FPA enregistré à {} de course{}.", French.format_duration(elapsed, false), reason.map(|reason| format!(" avec la raison « {reason} »")).unwrap_or_default())
+                    } else {
+                        format!("FPA recorded at race time {}{}.", English.format_duration(elapsed, false), reason.map(|reason| format!(" with reason “{reason}”")).unwrap_or_default())
+                    }).await?;
+                } else {
+                    ctx.say(if let French = goal.language() {
+                        format!("@everyone Le FPA a été appelé par {reply_to}.")
+                    } else {
+                        format!("@everyone FPA has been invoked by {reply_to}.")
+                    }).await?;
+                }
+            }
+        } else {
+            ctx.say(if let French = goal.language() {
+                "Le FPA n'est pas activé. Les Race Monitors peuvent l'activer avec !fpa on."
+            } else {
+                "Fair play agreement is not active. Race monitors may enable FPA for this race with !fpa on"
+            }).await?;
+        }
+        Ok(())
+    }
+
+    /// Reconstructs the breaks configuration from the room's chat log, for when the bot restarted after `!breaks` may have been used.
+    ///
+    /// Replays all valid `!breaks` commands sent before the race started, starting from `default_breaks`.
+    async fn recover_breaks(ctx: &RaceContext<GlobalState>, data: &RaceData, default_breaks: Option<Breaks>) -> Result<Option<Breaks>, Error> {
+        let log = ctx.global_state.http_client
+            .get(format!("https://{}{}/log", racetime_host(), data.url))
+            .send().await?
+            .detailed_error_for_status().await.to_racetime()?
+            .text().await?;
+        let mut breaks = default_breaks;
+        for line in log.lines() {
+            let Some((_, posted_at, message)) = regex_captures!(r"^\[([^\]]+)\] [^:]+: (.*)$", line) else { continue };
+            let posted_at = match NaiveDateTime::parse_from_str(posted_at, "%Y-%m-%d %H:%M:%S") {
+                Ok(posted_at) => posted_at.and_utc(),
+                Err(e) => {
+                    eprintln!("skipping chat log line with unrecognized timestamp {posted_at:?} while recovering breaks for race room https://{}{}: {e} ({e:?})", racetime_host(), data.url);
+                    continue
+                }
+            };
+            if data.started_at.is_some_and(|started_at| posted_at >= started_at) { break }
+            let Some((_, args)) = regex_captures!("^!breaks? ?(.*)$", message) else { continue };
+            match args.trim() {
+                "" => {}
+                "off" | "désactivé" => breaks = None,
+                args => if let Some(new_breaks) = args.parse::<Breaks>().ok().filter(Breaks::is_valid) {
+                    breaks = Some(new_breaks);
+                },
+            }
+        }
+        Ok(breaks)
+    }
+
+    /// Restores state saved before the connection to this room dropped, reconciled with the current race data.
+    ///
+    /// Returns `false` if the race state could not be restored, in which case the room is set up as if the bot had just restarted.
+    async fn reconcile(&mut self, ctx: &RaceContext<GlobalState>, snapshot: RoomSnapshot) -> Result<bool, Error> {
+        let RoomSnapshot { race_state, breaks, fpa_enabled, fpa_invoked, locked, on_hold, password_sent } = snapshot;
+        self.breaks = breaks;
+        self.fpa_enabled = fpa_enabled;
+        self.locked = locked;
+        self.on_hold = on_hold;
+        self.password_sent = password_sent;
+        if let Some(OfficialRaceData { fpa_invoked: ref mut official_fpa_invoked, .. }) = self.official_data {
+            *official_fpa_invoked = fpa_invoked;
+        }
+        let old_state = lock!(@write state = race_state; mem::take(&mut *state));
+        let reconciled = lock!(@write state = self.race_state; match (old_state, &*state) {
+            // the task rolling the seed belonged to the old connection
+            (RaceState::Rolling, _) => false,
+            // drafts of official races are saved to the database after each step, so the freshly loaded state is authoritative
+            (RaceState::Init, _) | (RaceState::Draft { .. }, RaceState::Draft { .. }) => true,
+            (old_state, _) => {
+                *state = old_state;
+                true
+            }
+        });
+        if !reconciled {
+            let room_url = format!("https://{}{}", racetime_host(), ctx.data().await.url);
+            eprintln!("race handler for {room_url} reconnected while a seed was being rolled, state could not be restored");
+            if let Environment::Production = Environment::default() {
+                let discord_ctx = ctx.global_state.discord_ctx.read().await;
+                FENHL.create_dm_channel(&*discord_ctx).await.to_racetime()?.say(&*discord_ctx, format!("race handler for <{room_url}> reconnected while a seed was being rolled, state could not be restored")).await.to_racetime()?;
+            }
+            ctx.say(if let French = self.goal(ctx).await.to_racetime()?.language() {
+                "@entrants J'ai perdu la connexion à cette room pendant que la seed était en train d'être générée. Si elle n'est pas relancée automatiquement, utilisez !seed pour en générer une nouvelle."
+            } else {
+                "@entrants I lost my connection to this room while the seed was being rolled. If it doesn't get rolled again automatically, please use !seed to roll a new one."
+            }).await?;
+        }
+        Ok(reconciled)
     }
 
     /// Returns `false` if this race was already finished/cancelled.
     async fn unlock_spoiler_log(&self, ctx: &RaceContext<GlobalState>, goal: Goal) -> Result<bool, Error> {
+        let mut announce_url = None;
         lock!(@write state = self.race_state; {
             match *state {
                 RaceState::Rolled(seed::Data { files: Some(ref files), .. }) => if self.official_data.as_ref().map_or(true, |official_data| !official_data.cal_event.is_private_async_part()) {
-                    if let UnlockSpoilerLog::Progression | UnlockSpoilerLog::After = goal.unlock_spoiler_log(self.is_official(), false /* we may try to unlock a log that's already unlocked, but other than that, this assumption doesn't break anything */) {
+                    let unlock_spoiler_log = goal.unlock_spoiler_log(self.official_data.as_ref().map(|OfficialRaceData { event, .. }| event), self.is_official(), false /* we may try to unlock a log that's already unlocked, but other than that, this assumption doesn't break anything */);
+                    if let UnlockSpoilerLog::Progression | UnlockSpoilerLog::After | UnlockSpoilerLog::Organizers = unlock_spoiler_log {
+                        let announce = matches!(unlock_spoiler_log, UnlockSpoilerLog::After);
                         match files {
                             seed::Files::MidosHouse { file_stem, locked_spoiler_log_path } => if let Some(locked_spoiler_log_path) = locked_spoiler_log_path {
                                 lock!(@write seed_metadata = ctx.global_state.seed_metadata; seed_metadata.remove(&**file_stem));
                                 fs::rename(locked_spoiler_log_path, Path::new(seed::DIR).join(format!("{file_stem}_Spoiler.json"))).await.to_racetime()?;
+                                if announce {
+                                    announce_url = Some(format!("https://midos.house/seed/{file_stem}_Spoiler.json"));
+                                }
                             },
                             seed::Files::OotrWeb { id, file_stem, .. } => {
                                 ctx.global_state.ootr_api_client.unlock_spoiler_log(*id).await.to_racetime()?;
                                 let spoiler_log = ctx.global_state.ootr_api_client.seed_details(*id).await.to_racetime()?.spoiler_log;
                                 fs::write(Path::new(seed::DIR).join(format!("{file_stem}_Spoiler.json")), &spoiler_log).await.to_racetime()?;
+                                if announce {
+                                    announce_url = Some(format!("https://ootrandomizer.com/spoilers/get?id={id}"));
+                                }
                             }
                             seed::Files::TriforceBlitz { .. } | seed::Files::TfbSotd { .. } => {} // automatically unlocked by triforceblitz.com
                         }
@@ -2399,6 +4124,14 @@ impl Handler {
             }
             *state = RaceState::SpoilerSent;
         });
+        // the race state is only set to SpoilerSent once, so this is never posted twice
+        if let Some(announce_url) = announce_url {
+            ctx.say(if let French = goal.language() {
+                format!("Le spoiler log est maintenant disponible : {announce_url}")
+            } else {
+                format!("The spoiler log is now available: {announce_url}")
+            }).await?;
+        }
         Ok(true)
     }
 }
@@ -2410,23 +4143,58 @@ impl RaceHandler<GlobalState> for Handler {
     }
 
     async fn should_stop(&mut self, ctx: &RaceContext<GlobalState>) -> Result<bool, Error> {
-        Ok(!Self::should_handle_inner(&*ctx.data().await, ctx.global_state.clone(), Some(Some(self))).await)
+        let mut data = ctx.data().await;
+        let should_stop = !Self::should_handle_inner(&*data, ctx.global_state.clone(), Some(Some(self))).await;
+        if should_stop && self.official_data.as_ref().is_some_and(|official_data| !official_data.scores_confirmed && official_data.scores.values().any(Option::is_some)) {
+            // the score wait is over without a race monitor confirming the reported scores, so record them as reported rather than losing the result
+            drop(data);
+            ctx.say("The score reporting period is over without the scores being confirmed by a race monitor. Recording the scores as reported.").await?;
+            if let Some(OfficialRaceData { ref mut scores_confirmed, .. }) = self.official_data {
+                *scores_confirmed = true;
+            }
+            // if some scores are still missing, this doesn't record anything and the organizers have already been notified by the cleanup timeout
+            self.check_tfb_finish(ctx).await?;
+            data = ctx.data().await;
+        }
+        // this is checked after every message, so it's a good place to keep the snapshot up to date
+        lock!(room_snapshots = ctx.global_state.room_snapshots; if should_stop {
+            room_snapshots.remove(&data.url);
+        } else {
+            room_snapshots.insert(data.url.clone(), RoomSnapshot {
+                race_state: self.race_state.clone(),
+                breaks: self.breaks,
+                fpa_enabled: self.fpa_enabled,
+                fpa_invoked: self.official_data.as_ref().is_some_and(|OfficialRaceData { fpa_invoked, .. }| *fpa_invoked),
+                locked: self.locked,
+                on_hold: self.on_hold,
+                password_sent: self.password_sent,
+            });
+        });
+        if should_stop {
+            lock!(roll_failure_notifications = ctx.global_state.roll_failure_notifications; roll_failure_notifications.remove(&data.url));
+        }
+        Ok(should_stop)
     }
 
     async fn task(global_state: Arc<GlobalState>, race_data: Arc<tokio::sync::RwLock<RaceData>>, join_handle: tokio::task::JoinHandle<()>) -> Result<(), Error> {
         let race_data = ArcRwLock::from(race_data);
+        lock!(@read data = race_data; lock!(clean_shutdown = global_state.clean_shutdown; if let Some(open_room) = clean_shutdown.open_rooms.get_mut(&data.url) {
+            open_room.abort_handle = Some(join_handle.abort_handle());
+        }));
         tokio::spawn(async move {
             lock!(@read data = race_data; println!("race handler for https://{}{} started", racetime_host(), data.url));
             let res = join_handle.await;
             lock!(@read data = race_data; {
                 lock!(clean_shutdown = global_state.clean_shutdown; {
-                    assert!(clean_shutdown.open_rooms.remove(&data.url));
+                    assert!(clean_shutdown.open_rooms.remove(&data.url).is_some());
                     if clean_shutdown.requested && clean_shutdown.open_rooms.is_empty() {
                         clean_shutdown.notifier.notify_waiters();
                     }
                 });
                 if let Ok(()) = res {
                     println!("race handler for https://{}{} stopped", racetime_host(), data.url);
+                } else if res.as_ref().is_err_and(|e| e.is_cancelled()) {
+                    println!("race handler for https://{}{} was force-stopped", racetime_host(), data.url);
                 } else {
                     eprintln!("race handler for https://{}{} panicked", racetime_host(), data.url);
                     if let Environment::Production = Environment::default() {
@@ -2441,12 +4209,30 @@ impl RaceHandler<GlobalState> for Handler {
     async fn new(ctx: &RaceContext<GlobalState>) -> Result<Self, Error> {
         let data = ctx.data().await;
         let goal = data.goal.name.parse::<Goal>().to_racetime()?;
-        let (existing_seed, official_data, race_state, high_seed_name, low_seed_name, fpa_enabled) = lock!(new_room_lock = ctx.global_state.new_room_lock; { // make sure a new room isn't handled before it's added to the database
+        let snapshot = lock!(room_snapshots = ctx.global_state.room_snapshots; room_snapshots.remove(&data.url));
+        let snapshot = if snapshot.is_some() {
+            match ctx.global_state.reconnect_behavior {
+                ReconnectBehavior::Resync => {
+                    println!("race handler for https://{}{} reconnected, restoring previous state", racetime_host(), data.url);
+                    snapshot
+                }
+                ReconnectBehavior::Restart => {
+                    println!("race handler for https://{}{} reconnected, handling as a new room", racetime_host(), data.url);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        let (existing_seed, official_data, race_state, high_seed_name, low_seed_name, fpa_enabled, stream_delay) = lock!(new_room_lock = ctx.global_state.new_room_lock; { // make sure a new room isn't handled before it's added to the database
             let mut transaction = ctx.global_state.db_pool.begin().await.to_racetime()?;
             let new_data = if let Some(cal_event) = cal::Event::from_room(&mut transaction, &ctx.global_state.http_client, format!("https://{}{}", racetime_host(), ctx.data().await.url).parse()?).await.to_racetime()? {
                 let event = cal_event.race.event(&mut transaction).await.to_racetime()?;
                 let mut entrants = Vec::default();
+                let mut teams_by_entrant = HashMap::default();
+                let mut alternates = Vec::default();
                 for team in cal_event.active_teams() {
+                    let mut team_members = Vec::default();
                     for (member, role) in team.members_roles(&mut transaction).await.to_racetime()? {
                         if event.team_config.role_is_racing(role) {
                             if let Some(member) = member.racetime {
@@ -2465,6 +4251,8 @@ impl RaceHandler<GlobalState> for Handler {
                                 } else {
                                     ctx.invite_user(&member.id).await?;
                                 }
+                                team_members.push(member.id.clone());
+                                teams_by_entrant.insert(member.id.clone(), team.id);
                                 entrants.push(member.id);
                             } else {
                                 ctx.say(format!(
@@ -2478,47 +4266,73 @@ impl RaceHandler<GlobalState> for Handler {
                             }
                         }
                     }
+                    if let Some(alternate) = sqlx::query_scalar!(r#"SELECT alternate AS "alternate: Id<Users>" FROM race_alternates WHERE race = $1 AND team = $2"#, cal_event.race.id as _, team.id as _).fetch_optional(&mut *transaction).await.to_racetime()? {
+                        let alternate = User::from_id(&mut *transaction, alternate).await.to_racetime()?.ok_or_else(|| Error::Custom(Box::new(sqlx::Error::RowNotFound)))?;
+                        if let Some(ref racetime) = alternate.racetime {
+                            alternates.push(Alternate {
+                                racetime_id: racetime.id.clone(),
+                                display_name: alternate.display_name().to_owned(),
+                                team_members,
+                            });
+                        } else {
+                            ctx.say(format!(
+                                "Warning: the alternate {name} can't be invited automatically because {subj} {has_not} linked {poss} racetime.gg account to {poss} Mido's House account.",
+                                name = alternate,
+                                subj = alternate.subjective_pronoun(),
+                                has_not = if alternate.subjective_pronoun_uses_plural_form() { "haven't" } else { "hasn't" },
+                                poss = alternate.possessive_determiner(),
+                            )).await?;
+                        }
+                    }
                 }
-                ctx.send_message(&if_chain! {
-                    if let French = goal.language();
-                    if !event.is_single_race();
-                    if let (Some(phase), Some(round)) = (cal_event.race.phase.as_ref(), cal_event.race.round.as_ref());
-                    if let Some(Some(phase_round)) = sqlx::query_scalar!("SELECT display_fr FROM phase_round_options WHERE series = $1 AND event = $2 AND phase = $3 AND round = $4", event.series as _, &event.event, phase, round).fetch_optional(&mut *transaction).await.to_racetime()?;
-                    then {
+                if snapshot.is_none() {
+                    let english = if let (true, Some(weekly_name)) = (cal_event.race.phase.is_none(), cal_event.race.round.as_deref().and_then(|round| round.strip_suffix(" Weekly"))) {
                         format!(
-                            "Bienvenue pour cette race de {phase_round} ! Pour plus d'informations : https://midos.house/event/{}/{}",
+                            "Welcome to the {weekly_name} weekly! Current settings: {}. See https://midos.house/event/{}/{} for details.",
+                            s::SHORT_WEEKLY_SETTINGS,
                             event.series,
                             event.event,
                         )
                     } else {
-                        if let (true, Some(weekly_name)) = (cal_event.race.phase.is_none(), cal_event.race.round.as_deref().and_then(|round| round.strip_suffix(" Weekly"))) {
-                            format!(
-                                "Welcome to the {weekly_name} weekly! Current settings: {}. See https://midos.house/event/{}/{} for details.",
-                                s::SHORT_WEEKLY_SETTINGS,
+                        format!(
+                            "Welcome to {}! Learn more about the event at https://midos.house/event/{}/{}",
+                            if event.is_single_race() {
+                                format!("the {}", event.display_name) //TODO remove “the” depending on event name
+                            } else {
+                                match (cal_event.race.phase.as_deref(), cal_event.race.round.as_deref()) {
+                                    (Some("Qualifier"), Some(round)) => format!("qualifier {round}"),
+                                    (Some("Live Qualifier"), Some(round)) => format!("live qualifier {round}"),
+                                    (Some(phase), Some(round)) => format!("this {phase} {round} race"),
+                                    (Some(phase), None) => format!("this {phase} race"),
+                                    (None, Some(round)) => format!("this {round} race"),
+                                    (None, None) => format!("this {} race", event.display_name),
+                                }
+                            },
+                            event.series,
+                            event.event,
+                        )
+                    };
+                    let french = if_chain! {
+                        if goal.language() == French || goal.is_bilingual();
+                        if !event.is_single_race();
+                        if let (Some(phase), Some(round)) = (cal_event.race.phase.as_ref(), cal_event.race.round.as_ref());
+                        if let Some(Some(phase_round)) = sqlx::query_scalar!("SELECT display_fr FROM phase_round_options WHERE series = $1 AND event = $2 AND phase = $3 AND round = $4", event.series as _, &event.event, phase, round).fetch_optional(&mut *transaction).await.to_racetime()?;
+                        then {
+                            Some(format!(
+                                "Bienvenue pour cette race de {phase_round} ! Pour plus d'informations : https://midos.house/event/{}/{}",
                                 event.series,
                                 event.event,
-                            )
+                            ))
                         } else {
-                            format!(
-                                "Welcome to {}! Learn more about the event at https://midos.house/event/{}/{}",
-                                if event.is_single_race() {
-                                    format!("the {}", event.display_name) //TODO remove “the” depending on event name
-                                } else {
-                                    match (cal_event.race.phase.as_deref(), cal_event.race.round.as_deref()) {
-                                        (Some("Qualifier"), Some(round)) => format!("qualifier {round}"),
-                                        (Some("Live Qualifier"), Some(round)) => format!("live qualifier {round}"),
-                                        (Some(phase), Some(round)) => format!("this {phase} {round} race"),
-                                        (Some(phase), None) => format!("this {phase} race"),
-                                        (None, Some(round)) => format!("this {round} race"),
-                                        (None, None) => format!("this {} race", event.display_name),
-                                    }
-                                },
-                                event.series,
-                                event.event,
-                            )
+                            None
                         }
-                    }
-                }, true, Vec::default()).await?;
+                    };
+                    ctx.send_message(&match french {
+                        Some(french) if goal.is_bilingual() => format!("{english} / {french}"),
+                        Some(french) => french,
+                        None => english,
+                    }, true, Vec::default()).await?;
+                }
                 let (race_state, high_seed_name, low_seed_name) = if let Some(draft_kind) = event.draft_kind() {
                     let state = cal_event.race.draft.clone().expect("missing draft state");
                     let [high_seed_name, low_seed_name] = if let draft::StepKind::Done(_) = state.next_step(draft_kind, cal_event.race.game, &mut draft::MessageContext::None).await.to_racetime()?.kind {
@@ -2557,7 +4371,7 @@ impl RaceHandler<GlobalState> for Handler {
                         }
                     };
                     (RaceState::Draft {
-                        unlock_spoiler_log: goal.unlock_spoiler_log(true, false),
+                        unlock_spoiler_log: goal.unlock_spoiler_log(Some(&event), true, false),
                         state,
                     }, high_seed_name, low_seed_name)
                 } else {
@@ -2574,13 +4388,26 @@ impl RaceHandler<GlobalState> for Handler {
                 };
                 let emulator_settings_reminder = event.series == Series::Standard && event.event != "w"; //TODO move to database
                 let prevent_late_joins = event.series == Series::SpeedGaming || event.series == Series::Standard && event.event == "8"; //TODO move to database
-                if !stream_delay.is_zero() || emulator_settings_reminder || prevent_late_joins {
-                    let delay_until = cal_event.start().expect("handling room for official race without start time") - stream_delay - TimeDelta::minutes(5);
-                    if let Ok(delay) = (delay_until - Utc::now()).to_std() {
+                let pre_race_checklist = event.pre_race_checklist.as_ref().map(|checklist| checklist.lines().map(str::to_owned).collect_vec());
+                let (stream_delay_tx, mut stream_delay_rx) = watch::channel(stream_delay);
+                if !stream_delay.is_zero() || emulator_settings_reminder || prevent_late_joins || pre_race_checklist.is_some() {
+                    let start = cal_event.start().expect("handling room for official race without start time");
+                    if start - stream_delay - TimeDelta::minutes(5) > Utc::now() {
                         let ctx = ctx.clone();
                         let requires_emote_only = event.series == Series::SpeedGaming && cal_event.race.phase.as_ref().map_or(false, |phase| phase == "Bracket");
+                        let language = goal.language();
                         tokio::spawn(async move {
-                            sleep_until(Instant::now() + delay).await;
+                            // the stream delay may be changed using !streamdelay while waiting
+                            let stream_delay = loop {
+                                let stream_delay = *stream_delay_rx.borrow_and_update();
+                                let delay = (start - stream_delay - TimeDelta::minutes(5) - Utc::now()).to_std().unwrap_or_default();
+                                select! {
+                                    () = sleep(delay) => break stream_delay,
+                                    Ok(()) = stream_delay_rx.changed() => {}
+                                }
+                            };
+                            // lets !streamdelay know that changes no longer have an effect
+                            drop(stream_delay_rx);
                             if !Self::should_handle_inner(&*ctx.data().await, ctx.global_state.clone(), Some(None)).await { return }
                             if !stream_delay.is_zero() {
                                 ctx.say(format!("@entrants Remember to go live with a delay of {} ({} seconds){}!",
@@ -2589,43 +4416,131 @@ impl RaceHandler<GlobalState> for Handler {
                                     if requires_emote_only { " and set your chat to emote only" } else { "" },
                                 )).await.expect("failed to send stream delay notice");
                             }
-                            if emulator_settings_reminder || prevent_late_joins {
+                            if emulator_settings_reminder || prevent_late_joins || pre_race_checklist.is_some() {
                                 sleep(stream_delay).await;
                                 let data = ctx.data().await;
                                 if !Self::should_handle_inner(&*data, ctx.global_state.clone(), Some(None)).await { return }
                                 if prevent_late_joins && data.status.value == RaceStatusValue::Open {
                                     ctx.set_invitational().await.expect("failed to make the room invitational");
                                 }
-                                if emulator_settings_reminder {
+                                if let Some(mut pre_race_checklist) = pre_race_checklist {
+                                    // consolidated into a single message
+                                    if emulator_settings_reminder {
+                                        pre_race_checklist.push(if let French = language { format!("montrez vos paramètres d'émulateur") } else { format!("show your emulator settings") });
+                                    }
+                                    ctx.say(if let French = language {
+                                        format!("@entrants Liste de vérification avant la race : {}", pre_race_checklist.into_iter().format(" • "))
+                                    } else {
+                                        format!("@entrants Pre-race checklist: {}", pre_race_checklist.into_iter().format(" • "))
+                                    }).await.expect("failed to send pre-race checklist");
+                                } else if emulator_settings_reminder {
                                     ctx.say("@entrants Remember to show your emulator settings!").await.expect("failed to send emulator settings notice");
                                 }
                             }
                         });
                     }
                 }
+                if event.auto_cancel_empty_rooms {
+                    if let Ok(delay) = (cal_event.start().expect("handling room for official race without start time") - Utc::now()).to_std() {
+                        let ctx = ctx.clone();
+                        let organizer_channel = event.discord_organizer_channel;
+                        let language = goal.language();
+                        tokio::spawn(async move {
+                            sleep_until(Instant::now() + delay).await;
+                            {
+                                let data = ctx.data().await;
+                                if !Self::should_handle_inner(&*data, ctx.global_state.clone(), Some(None)).await { return }
+                                if !matches!(data.status.value, RaceStatusValue::Open | RaceStatusValue::Invitational) || has_joined_entrants(&data) { return }
+                            }
+                            ctx.say(if let French = language {
+                                "Personne n'a rejoint cette race. Elle sera annulée dans 5 minutes si personne ne la rejoint d'ici là."
+                            } else {
+                                "Nobody has joined this race. The room will be cancelled in 5 minutes unless someone joins."
+                            }).await.expect("failed to send empty room warning");
+                            sleep(Duration::from_secs(5 * 60)).await;
+                            {
+                                let data = ctx.data().await;
+                                if !Self::should_handle_inner(&*data, ctx.global_state.clone(), Some(None)).await { return }
+                                if !matches!(data.status.value, RaceStatusValue::Open | RaceStatusValue::Invitational) || has_joined_entrants(&data) { return }
+                            }
+                            if let Some(organizer_channel) = organizer_channel {
+                                organizer_channel.say(&*ctx.global_state.discord_ctx.read().await, MessageBuilder::default()
+                                    .push("race automatically cancelled since nobody joined: <https://")
+                                    .push(racetime_host())
+                                    .push(&ctx.data().await.url)
+                                    .push('>')
+                                    .build()
+                                ).await.expect("failed to notify organizers of empty room");
+                            }
+                            ctx.cancel_race().await.expect("failed to cancel empty room");
+                        });
+                    }
+                }
+                if !alternates.is_empty() {
+                    let deadline = cal_event.start().expect("handling room for official race without start time") - TimeDelta::from_std(event.alternate_deadline.unwrap_or_default()).expect("alternate deadline too long");
+                    if let Ok(delay) = (deadline - Utc::now()).to_std() {
+                        let ctx = ctx.clone();
+                        let alternates = alternates.clone();
+                        let organizer_channel = event.discord_organizer_channel;
+                        let language = goal.language();
+                        tokio::spawn(async move {
+                            sleep_until(Instant::now() + delay).await;
+                            let promotions = {
+                                let data = ctx.data().await;
+                                if !Self::should_handle_inner(&*data, ctx.global_state.clone(), Some(None)).await { return }
+                                if !matches!(data.status.value, RaceStatusValue::Open | RaceStatusValue::Invitational) { return }
+                                alternates.iter()
+                                    .filter(|alternate| !data.entrants.iter().any(|entrant| entrant.user.id == alternate.racetime_id)) // already promoted
+                                    .filter_map(|alternate| alternate.team_members.iter().find_map(|member| match data.entrants.iter().find(|entrant| entrant.user.id == *member) {
+                                        Some(entrant) => matches!(entrant.status.value, EntrantStatusValue::Requested | EntrantStatusValue::Invited | EntrantStatusValue::Declined).then(|| entrant.user.name.clone()),
+                                        None => Some(member.clone()),
+                                    }).map(|missing| (alternate, missing)))
+                                    .collect_vec()
+                            };
+                            for (alternate, missing) in promotions {
+                                Self::promote_alternate(&ctx, alternate, &missing, organizer_channel, language).await.expect("failed to promote alternate");
+                            }
+                        });
+                    }
+                }
                 let fpa_enabled = match data.status.value {
                     RaceStatusValue::Invitational => {
-                        ctx.say(if let French = goal.language() {
-                            "Le FPA est activé pour cette race. Les joueurs pourront utiliser !fpa pendant la race pour signaler d'un problème technique de leur côté. Les race monitors doivent activer les notifications en cliquant sur l'icône de cloche 🔔 sous le chat."
-                        } else {
-                            "Fair play agreement is active for this official race. Entrants may use the !fpa command during the race to notify of a crash. Race monitors (if any) should enable notifications using the bell 🔔 icon below chat."
-                        }).await?; //TODO different message for monitorless FPA?
+                        if snapshot.is_none() {
+                            ctx.say(if goal.is_bilingual() {
+                                "Fair play agreement is active for this official race. Entrants may use the !fpa command during the race to notify of a crash. Race monitors (if any) should enable notifications using the bell 🔔 icon below chat. / Le FPA est activé pour cette race. Les joueurs pourront utiliser !fpa pendant la race pour signaler d'un problème technique de leur côté. Les race monitors doivent activer les notifications en cliquant sur l'icône de cloche 🔔 sous le chat."
+                            } else if let French = goal.language() {
+                                "Le FPA est activé pour cette race. Les joueurs pourront utiliser !fpa pendant la race pour signaler d'un problème technique de leur côté. Les race monitors doivent activer les notifications en cliquant sur l'icône de cloche 🔔 sous le chat."
+                            } else {
+                                "Fair play agreement is active for this official race. Entrants may use the !fpa command during the race to notify of a crash. Race monitors (if any) should enable notifications using the bell 🔔 icon below chat."
+                            }).await?; //TODO different message for monitorless FPA?
+                        }
                         true
                     }
                     RaceStatusValue::Open => false,
-                    _ => data.entrants.len() < 10, // guess based on entrant count, assuming an open race for 10 or more
+                    _ => data.entrants.len() < ctx.global_state.fpa_entrant_threshold, // guess based on entrant count, assuming an open race for larger rooms
                 };
                 (
                     cal_event.race.seed.clone(),
                     Some(OfficialRaceData {
+                        required_restream_languages: cal_event.race.required_restream_languages.clone(),
                         fpa_invoked: false,
                         scores: HashMap::default(),
-                        cal_event, event, goal, restreams, entrants,
+                        scores_confirmed: false,
+                        mw_rooms: ArcRwLock::new(Vec::default()),
+                        unlisted: data.unlisted,
+                        // teams whose members finished before the bot restarted have already been notified
+                        partner_finish_notified: data.entrants.iter()
+                            .filter(|entrant| entrant.status.value == EntrantStatusValue::Done)
+                            .filter_map(|entrant| teams_by_entrant.get(&entrant.user.id).copied())
+                            .collect(),
+                        teams_by_entrant,
+                        cal_event, event, goal, restreams, entrants, alternates,
                     }),
                     race_state,
                     high_seed_name,
                     low_seed_name,
                     fpa_enabled,
+                    Some(stream_delay_tx),
                 )
             } else {
                 let mut race_state = RaceState::Init;
@@ -2658,9 +4573,10 @@ impl RaceHandler<GlobalState> for Handler {
                         }
                     }
                 }
-                if let RaceStatusValue::Pending | RaceStatusValue::InProgress = data.status.value { //TODO also check this in official races
-                    //TODO get chatlog and recover breaks config instead of sending this
-                    ctx.say("@entrants I just restarted and it looks like the race is already in progress. If the !breaks command was used, break notifications may be broken now. Sorry about that.").await?;
+                if snapshot.is_some() {
+                    // state is restored below, no need to welcome entrants again
+                } else if let RaceStatusValue::Pending | RaceStatusValue::InProgress = data.status.value {
+                    // breaks are recovered from the chat log below
                 } else {
                     match race_state {
                         RaceState::Init => match goal {
@@ -2971,7 +4887,18 @@ impl RaceHandler<GlobalState> for Handler {
                                     }),
                                 ],
                             ).await?,
-                            Goal::StandardRuleset => unreachable!("attempted to handle a user-opened Standard Ruleset room"),
+                            Goal::StandardRuleset => ctx.send_message(
+                                "Welcome! Since this room wasn't opened by Mido's House, only some commands are available here. You can still roll a seed with the current Standard settings.",
+                                true,
+                                vec![
+                                    ("Roll seed", ActionButton::Message {
+                                        message: format!("!seed"),
+                                        help_text: Some(format!("Create a seed with the current Standard settings.")),
+                                        survey: None,
+                                        submit: None,
+                                    }),
+                                ],
+                            ).await?,
                             Goal::TournoiFrancoS3 => ctx.send_message(
                                 "Bienvenue ! Ceci est une practice room pour le tournoi francophone saison 3. Vous pouvez obtenir des renseignements supplémentaires ici : https://midos.house/event/fr/3",
                                 true,
@@ -3069,8 +4996,8 @@ impl RaceHandler<GlobalState> for Handler {
                                     }),
                                 ],
                             ).await?,
-                            Goal::TournoiFrancoS4 => ctx.send_message( //TODO post welcome message in both English and French
-                                "Welcome! This is a practice room for the Tournoi Francophone Saison 4. Learn more about the tournament at https://midos.house/event/fr/4",
+                            Goal::TournoiFrancoS4 => ctx.send_message(
+                                "Welcome! This is a practice room for the Tournoi Francophone Saison 4. Learn more about the tournament at https://midos.house/event/fr/4 / Bienvenue ! Ceci est une practice room pour le Tournoi Francophone Saison 4. Vous pouvez obtenir des renseignements supplémentaires ici : https://midos.house/event/fr/4",
                                 true,
                                 vec![
                                     ("Roll seed (base settings)", ActionButton::Message {
@@ -3228,9 +5155,20 @@ impl RaceHandler<GlobalState> for Handler {
                                 ],
                             ).await?,
                         },
-                        RaceState::Rolled(_) => ctx.say("@entrants I just restarted. You may have to reconfigure !breaks and !fpa. Sorry about that.").await?,
+                        RaceState::Rolled(_) => ctx.say("@entrants I just restarted. You may have to reconfigure !fpa. Sorry about that.").await?,
                         RaceState::Draft { .. } | RaceState::Rolling | RaceState::SpoilerSent => unreachable!(),
                     }
+                    let expected_gen_time = goal.expected_gen_time();
+                    if let RaceState::Init = race_state {
+                        // only worth mentioning if it's long enough that players might think something went wrong
+                        if expected_gen_time >= Duration::from_secs(5 * 60) {
+                            ctx.say(if let French = goal.language() {
+                                format!("Veuillez noter que la génération d'une seed pour cette race peut prendre jusqu'à environ {}.", French.format_duration(expected_gen_time, true))
+                            } else {
+                                format!("Please note that generating a seed for this race can take up to about {}.", English.format_duration(expected_gen_time, true))
+                            }).await?;
+                        }
+                    }
                 }
                 (
                     seed::Data::default(),
@@ -3239,22 +5177,136 @@ impl RaceHandler<GlobalState> for Handler {
                     format!("Team A"),
                     format!("Team B"),
                     false,
+                    None,
                 )
             };
             transaction.commit().await.to_racetime()?;
             new_data
         });
-        let this = Self {
-            breaks: None, //TODO default breaks for restreamed matches?
+        let breaks = if snapshot.is_some() {
+            None
+        } else if let RaceStatusValue::Pending | RaceStatusValue::InProgress = data.status.value {
+            let default_breaks = if official_data.is_some() { goal.default_breaks() } else { None };
+            match Self::recover_breaks(ctx, &data, default_breaks).await {
+                Ok(breaks) => {
+                    if let Some(breaks) = breaks {
+                        ctx.say(if let French = goal.language() {
+                            format!("@entrants Je viens de redémarrer et la race a déjà commencé. Les pauses ont été récupérées depuis le chat : {}.", breaks.format(French))
+                        } else {
+                            format!("@entrants I just restarted and it looks like the race is already in progress. Breaks have been recovered from chat: {}.", breaks.format(English))
+                        }).await?;
+                    }
+                    breaks
+                }
+                Err(e) => {
+                    eprintln!("failed to recover breaks for race room https://{}{}: {e} ({e:?})", racetime_host(), data.url);
+                    ctx.say(if let French = goal.language() {
+                        "@entrants Je viens de redémarrer et la race a déjà commencé. Si la commande !breaks a été utilisée, les notifications de pause risquent de ne pas fonctionner. Désolé."
+                    } else {
+                        "@entrants I just restarted and it looks like the race is already in progress. If the !breaks command was used, break notifications may be broken now. Sorry about that."
+                    }).await?;
+                    default_breaks
+                }
+            }
+        } else if official_data.is_some() && matches!(data.status.value, RaceStatusValue::Open | RaceStatusValue::Invitational) {
+            // the bot may have restarted after !breaks was used, so prefer breaks from chat over the defaults
+            let default_breaks = goal.default_breaks();
+            let breaks = Self::recover_breaks(ctx, &data, default_breaks).await.unwrap_or_else(|e| {
+                eprintln!("failed to recover breaks for race room https://{}{}: {e} ({e:?})", racetime_host(), data.url);
+                default_breaks
+            });
+            if let Some(breaks) = breaks.filter(|_| breaks == default_breaks) {
+                ctx.say(if let French = goal.language() {
+                    format!("Vous aurez une pause de {} par défaut. Vous pouvez les modifier avec !breaks ou les désactiver avec !breaks off.", breaks.format(French))
+                } else {
+                    format!("Breaks have been set to {} by default. You can change them using !breaks or disable them using !breaks off.", breaks.format(English))
+                }).await?;
+            }
+            breaks
+        } else if let RaceState::Rolled(_) = race_state {
+            // practice room where the bot restarted after rolling a seed, so !breaks may have been used
+            Self::recover_breaks(ctx, &data, None).await.unwrap_or_else(|e| {
+                eprintln!("failed to recover breaks for race room https://{}{}: {e} ({e:?})", racetime_host(), data.url);
+                None
+            })
+        } else {
+            None
+        };
+        let mut this = Self {
+            breaks, //TODO default breaks for restreamed matches?
             break_notifications: None,
+            seed_roll: Mutex::default(),
             goal_notifications: None,
+            cleanup_timeout: None,
+            last_roll: Mutex::default(),
+            rerolls: 0,
             start_saved: false,
             locked: false,
+            on_hold: false,
             password_sent: false,
             race_state: ArcRwLock::new(race_state),
-            official_data, high_seed_name, low_seed_name, fpa_enabled,
+            official_data, high_seed_name, low_seed_name, fpa_enabled, stream_delay,
+        };
+        lock!(clean_shutdown = ctx.global_state.clean_shutdown; if let Some(open_room) = clean_shutdown.open_rooms.get_mut(&data.url) {
+            open_room.official = this.is_official();
+        });
+        let resynced = if let Some(snapshot) = snapshot {
+            this.reconcile(ctx, snapshot).await?
+        } else {
+            false
+        };
+        if !resynced && sqlx::query_scalar!("SELECT locked FROM room_locks WHERE room = $1 ORDER BY changed_at DESC LIMIT 1", format!("https://{}{}", racetime_host(), data.url)).fetch_optional(&ctx.global_state.db_pool).await.to_racetime()?.unwrap_or(false) {
+            this.locked = true;
+            ctx.say(if let French = goal.language() {
+                format!("Cette race est toujours verrouillée. Seuls {} peuvent générer une seed ou faire des choix de draft.", if this.is_official() { "les race monitors et les organisateurs du tournoi" } else { "les race monitors" })
+            } else {
+                format!("This room is still locked. Only {} may roll a seed or make draft choices.", if this.is_official() { "race monitors or tournament organizers" } else { "race monitors" })
+            }).await?;
+        }
+        let duplicate_members = if let (false, Some(OfficialRaceData { ref cal_event, ref event, .. })) = (resynced, &this.official_data) {
+            let mut transaction = ctx.global_state.db_pool.begin().await.to_racetime()?;
+            let mut duplicate_members = Vec::default();
+            for team in cal_event.active_teams() {
+                for member in team.members(&mut transaction).await.to_racetime()? {
+                    if !duplicate_members.contains(&member) && Team::all_from_event_and_member(&mut transaction, event.series, &event.event, member.id).await.to_racetime()?.len() > 1 {
+                        duplicate_members.push(member);
+                    }
+                }
+            }
+            transaction.commit().await.to_racetime()?;
+            if !duplicate_members.is_empty() {
+                let mut msg = MessageBuilder::default();
+                msg.push("race room <https://");
+                msg.push(racetime_host());
+                msg.push(&data.url);
+                msg.push("> is on hold because ");
+                msg.push(English.join_str(duplicate_members.iter().map(|member| member.display_name())).unwrap_or_default());
+                msg.push(if duplicate_members.len() == 1 { " is" } else { " are" });
+                msg.push(" registered on multiple teams for this event. Please resolve this, then use !unlock in the room to allow the seed to be rolled.");
+                let discord_ctx = ctx.global_state.discord_ctx.read().await;
+                if let Some(organizer_channel) = event.discord_organizer_channel {
+                    organizer_channel.say(&*discord_ctx, msg.build()).await.to_racetime()?;
+                } else {
+                    // DM Fenhl
+                    FENHL.create_dm_channel(&*discord_ctx).await.to_racetime()?.say(&*discord_ctx, msg.build()).await.to_racetime()?;
+                }
+                ctx.say(if let French = goal.language() {
+                    format!("@entrants {} {} inscrit·e·s dans plusieurs équipes pour cet événement. La seed ne sera pas générée tant que les organisateurs n'auront pas résolu ce problème.", French.join_str(duplicate_members.iter().map(|member| member.display_name())).unwrap_or_default(), if duplicate_members.len() == 1 { "est" } else { "sont" })
+                } else {
+                    format!("@entrants {} {} registered on multiple teams for this event. The seed won't be rolled until tournament organizers have resolved this.", English.join_str(duplicate_members.iter().map(|member| member.display_name())).unwrap_or_default(), if duplicate_members.len() == 1 { "is" } else { "are" })
+                }).await?;
+            }
+            duplicate_members
+        } else {
+            Vec::default()
         };
-        if let Some(OfficialRaceData { ref restreams, .. }) = this.official_data {
+        if !duplicate_members.is_empty() {
+            // only monitors and organizers can roll a seed until the duplicate registration is resolved
+            this.on_hold = true;
+        }
+        if resynced {
+            // restreams and seed were already set up before the connection dropped
+        } else if let Some(OfficialRaceData { ref restreams, .. }) = this.official_data {
             if let Some(restreams_text) = English.join_str(restreams.iter().map(|(video_url, state)| format!("in {} at {video_url}", state.language.expect("preset restreams should have languages assigned")))) {
                 for restreamer in restreams.values().flat_map(|RestreamState { restreamer_racetime_id, .. }| restreamer_racetime_id) {
                     let data = ctx.data().await;
@@ -3310,7 +5362,9 @@ impl RaceHandler<GlobalState> for Handler {
                 ctx.send_message(&text, true, Vec::default()).await?;
             }
             lock!(@read state = this.race_state; {
-                if existing_seed.files.is_some() {
+                if !duplicate_members.is_empty() {
+                    // don't proceed until organizers have resolved the duplicate registration
+                } else if existing_seed.files.is_some() {
                     this.queue_existing_seed(ctx, existing_seed, English, "a", format!("seed")).await;
                 } else {
                     match *state {
@@ -3325,17 +5379,18 @@ impl RaceHandler<GlobalState> for Handler {
                             | Goal::SongsOfHope
                             | Goal::StandardRuleset //TODO per-event settings
                             | Goal::TriforceBlitzProgressionSpoiler
-                                => this.roll_seed(ctx, goal.preroll_seeds(), goal.rando_version(this.official_data.as_ref().map(|OfficialRaceData { event, .. }| (event.series, &*event.event))), goal.single_settings().expect("goal has no single settings"), goal.unlock_spoiler_log(true, false), English, "a", format!("seed")).await,
+                                => this.roll_seed(ctx, goal.preroll_mode(this.official_data.as_ref().map(|OfficialRaceData { event, .. }| event)), goal.rando_version(this.official_data.as_ref().map(|OfficialRaceData { event, .. }| event)), goal.single_settings().expect("goal has no single settings"), goal.unlock_spoiler_log(this.official_data.as_ref().map(|OfficialRaceData { event, .. }| event), true, false), English, "a", format!("seed")).await?,
                             | Goal::WeTryToBeBetter
-                                => this.roll_seed(ctx, goal.preroll_seeds(), goal.rando_version(this.official_data.as_ref().map(|OfficialRaceData { event, .. }| (event.series, &*event.event))), goal.single_settings().expect("goal has no single settings"), goal.unlock_spoiler_log(true, false), French, "une", format!("seed")).await,
+                                => this.roll_seed(ctx, goal.preroll_mode(this.official_data.as_ref().map(|OfficialRaceData { event, .. }| event)), goal.rando_version(this.official_data.as_ref().map(|OfficialRaceData { event, .. }| event)), goal.single_settings().expect("goal has no single settings"), goal.unlock_spoiler_log(this.official_data.as_ref().map(|OfficialRaceData { event, .. }| event), true, false), French, "une", format!("seed")).await?,
                             Goal::Rsl => unreachable!("no official race rooms"),
                             Goal::Cc7 | Goal::MultiworldS3 | Goal::MultiworldS4 | Goal::TournoiFrancoS3 | Goal::TournoiFrancoS4 => unreachable!("should have draft state set"),
                             Goal::NineDaysOfSaws => unreachable!("9dos series has concluded"),
                             Goal::PicRs2 => this.roll_rsl_seed(ctx, VersionedRslPreset::Fenhl {
                                 version: Some((Version::new(2, 3, 8), 10)),
+                                git_ref: None,
                                 preset: RslDevFenhlPreset::Pictionary,
-                            }, 1, goal.unlock_spoiler_log(true, false), English, "a", format!("seed")).await,
-                            Goal::TriforceBlitz => this.roll_tfb_seed(ctx, "LATEST", goal.unlock_spoiler_log(true, false), English, "a", format!("Triforce Blitz S3 seed")).await,
+                            }, 1, goal.unlock_spoiler_log(this.official_data.as_ref().map(|OfficialRaceData { event, .. }| event), true, false), English, "a", format!("seed")).await,
+                            Goal::TriforceBlitz => this.roll_tfb_seed(ctx, "LATEST", goal.unlock_spoiler_log(this.official_data.as_ref().map(|OfficialRaceData { event, .. }| event), true, false), English, "a", format!("Triforce Blitz S3 seed")).await,
                         },
                         RaceState::Draft { .. } => this.advance_draft(ctx, &state).await?,
                         RaceState::Rolling | RaceState::Rolled(_) | RaceState::SpoilerSent => {}
@@ -3343,12 +5398,41 @@ impl RaceHandler<GlobalState> for Handler {
                 }
             });
         }
+        if let RaceStatusValue::Open | RaceStatusValue::Invitational = data.status.value {
+            if let Some(row) = sqlx::query!(r#"SELECT settings AS "settings: sqlx::types::Json<serde_json::Map<String, Json>>", unlock_spoiler_log AS "unlock_spoiler_log: UnlockSpoilerLog", language AS "language: Language", description FROM seed_queue WHERE room = $1"#, format!("https://{}{}", racetime_host(), data.url)).fetch_optional(&ctx.global_state.db_pool).await.to_racetime()? {
+                // official rooms with a completed draft already start rolling again in advance_draft
+                if lock!(@read state = this.race_state; matches!(*state, RaceState::Init)) {
+                    ctx.say(if let French = row.language {
+                        format!("@entrants Je viens de redémarrer alors que votre {} était dans la file d'attente. Je la remets dans la file.", row.description)
+                    } else {
+                        format!("@entrants I just restarted while your {} was queued. I'm putting it back in the queue.", row.description)
+                    }).await?;
+                    let article = if let French = row.language { "une" } else { "a" };
+                    this.roll_seed(ctx, goal.preroll_mode(this.official_data.as_ref().map(|OfficialRaceData { event, .. }| event)), goal.rando_version(this.official_data.as_ref().map(|OfficialRaceData { event, .. }| event)), row.settings.0, row.unlock_spoiler_log, row.language, article, row.description).await?;
+                }
+            }
+        }
         Ok(this)
     }
 
     async fn command(&mut self, ctx: &RaceContext<GlobalState>, cmd_name: String, args: Vec<String>, _is_moderator: bool, is_monitor: bool, msg: &ChatMessage) -> Result<(), Error> {
         let goal = self.goal(ctx).await.to_racetime()?;
         let reply_to = msg.user.as_ref().map_or("friend", |user| &user.name);
+        if self.locked && matches!(&*cmd_name.to_ascii_lowercase(), "ban" | "draft" | "first" | "no" | "pick" | "second" | "skip" | "yes") && !self.can_monitor(ctx, is_monitor, msg).await.to_racetime()? {
+            ctx.say(if let French = goal.language() {
+                format!("Désolé {reply_to}, la race est verrouillée. Seuls {} peuvent faire cela.", if self.is_official() { "les race monitors et les organisateurs du tournoi" } else { "les race monitors" })
+            } else {
+                format!("Sorry {reply_to}, this room is locked. Only {} can do that.", if self.is_official() { "race monitors or tournament organizers" } else { "race monitors" })
+            }).await?;
+            return Ok(())
+        }
+        if let (Goal::StandardRuleset, None) = (goal, &self.official_data) {
+            // user-opened Standard Ruleset room, see Config::handle_user_opened_standard_rooms
+            if !matches!(&*cmd_name.to_ascii_lowercase(), "changelog" | "commit" | "event" | "presets" | "rolled" | "seed" | "settings") {
+                ctx.say(format!("Sorry {reply_to}, that command isn't available in Standard Ruleset rooms that weren't opened by Mido's House.")).await?;
+                return Ok(())
+            }
+        }
         match &*cmd_name.to_ascii_lowercase() {
             "ban" => match args[..] {
                 [] => self.send_settings(ctx, &if let French = goal.language() {
@@ -3364,26 +5448,41 @@ impl RaceHandler<GlobalState> for Handler {
                 }).await?,
             },
             "breaks" | "break" => match args[..] {
-                [] => if let Some(breaks) = self.breaks {
-                    ctx.say(if let French = goal.language() {
-                        format!("Vous aurez une pause de {}. Vous pouvez les désactiver avec !breaks off.", breaks.format(French))
-                    } else {
-                        format!("Breaks are currently set to {}. Disable with !breaks off", breaks.format(English))
-                    }).await?;
-                } else {
-                    ctx.say(if let French = goal.language() {
-                        "Les pauses sont actuellement désactivées. Exemple pour les activer : !breaks 5m every 2h30."
+                [] => {
+                    let can_change = matches!(ctx.data().await.status.value, RaceStatusValue::Open | RaceStatusValue::Invitational);
+                    if let Some(breaks) = self.breaks {
+                        ctx.say(match (goal.language(), can_change) {
+                            (French, true) => format!("Vous aurez une pause de {}. Vous pouvez les désactiver avec !breaks off.", breaks.format(French)),
+                            (French, false) => format!("Vous aurez une pause de {}.", breaks.format(French)),
+                            (_, true) => format!("Breaks are currently set to {}. Disable with !breaks off", breaks.format(English)),
+                            (_, false) => format!("Breaks are currently set to {}.", breaks.format(English)),
+                        }).await?;
                     } else {
-                        "Breaks are currently disabled. Example command to enable: !breaks 5m every 2h30"
-                    }).await?;
-                },
-                [ref arg] if arg == "off" => if let RaceStatusValue::Open | RaceStatusValue::Invitational = ctx.data().await.status.value {
-                    self.breaks = None;
-                    ctx.say(if let French = goal.language() {
-                        "Les pauses sont désormais désactivées."
+                        ctx.say(match (goal.language(), can_change) {
+                            (French, true) => "Les pauses sont actuellement désactivées. Exemple pour les activer : !breaks 5m every 2h30.",
+                            (French, false) => "Il n'y a pas de pauses pour cette race.",
+                            (_, true) => "Breaks are currently disabled. Example command to enable: !breaks 5m every 2h30",
+                            (_, false) => "No breaks are set for this race.",
+                        }).await?;
+                    }
+                }
+                [ref arg] if arg == "off" || arg == "désactivé" => if let RaceStatusValue::Open | RaceStatusValue::Invitational = ctx.data().await.status.value {
+                    if self.breaks.take().is_some() {
+                        if let Some(break_notifications) = self.break_notifications.take() {
+                            break_notifications.abort();
+                        }
+                        ctx.say(if let French = goal.language() {
+                            "Les pauses sont désormais désactivées."
+                        } else {
+                            "Breaks are now disabled."
+                        }).await?;
                     } else {
-                        "Breaks are now disabled."
-                    }).await?;
+                        ctx.say(if let French = goal.language() {
+                            format!("Désolé {reply_to}, les pauses sont déjà désactivées.")
+                        } else {
+                            format!("Sorry {reply_to}, breaks are already disabled, so there's nothing to clear.")
+                        }).await?;
+                    }
                 } else {
                     ctx.say(if let French = goal.language() {
                         format!("Désolé {reply_to}, mais la race a débuté.")
@@ -3392,31 +5491,30 @@ impl RaceHandler<GlobalState> for Handler {
                     }).await?;
                 },
                 _ => if let Ok(breaks) = args.join(" ").parse::<Breaks>() {
-                    if breaks.duration < Duration::from_secs(60) {
-                        ctx.say(if let French = goal.language() {
+                    match breaks.validate() {
+                        Err(InvalidBreaks::TooShort) => ctx.say(if let French = goal.language() {
                             format!("Désolé {reply_to}, le temps minimum pour une pause (si active) est de 1 minute. Vous pouvez désactiver les pauses avec !breaks off")
                         } else {
                             format!("Sorry {reply_to}, minimum break time (if enabled at all) is 1 minute. You can disable breaks entirely with !breaks off")
-                        }).await?;
-                    } else if breaks.interval < breaks.duration + Duration::from_secs(5 * 60) {
-                        ctx.say(if let French = goal.language() {
+                        }).await?,
+                        Err(InvalidBreaks::TooFrequent) => ctx.say(if let French = goal.language() {
                             format!("Désolé {reply_to}, il doit y avoir un minimum de 5 minutes entre les pauses.")
                         } else {
                             format!("Sorry {reply_to}, there must be a minimum of 5 minutes between breaks since I notify runners 5 minutes in advance.")
-                        }).await?;
-                    } else if breaks.duration + breaks.interval >= Duration::from_secs(24 * 60 * 60) {
-                        ctx.say(if let French = goal.language() {
+                        }).await?,
+                        Err(InvalidBreaks::TooLate) => ctx.say(if let French = goal.language() {
                             format!("Désolé {reply_to}, vous ne pouvez pas faire de pauses si tard dans la race, vu que les race rooms se ferment au bout de 24 heures.")
                         } else {
                             format!("Sorry {reply_to}, race rooms are automatically closed after 24 hours so these breaks wouldn't work.")
-                        }).await?;
-                    } else {
-                        self.breaks = Some(breaks);
-                        ctx.say(if let French = goal.language() {
-                            format!("Vous aurez une pause de {}.", breaks.format(French))
-                        } else {
-                            format!("Breaks set to {}.", breaks.format(English))
-                        }).await?;
+                        }).await?,
+                        Ok(()) => {
+                            self.breaks = Some(breaks);
+                            ctx.say(if let French = goal.language() {
+                                format!("Vous aurez une pause de {}.", breaks.format(French))
+                            } else {
+                                format!("Breaks set to {}.", breaks.format(English))
+                            }).await?;
+                        }
                     }
                 } else {
                     ctx.say(if let French = goal.language() {
@@ -3426,6 +5524,86 @@ impl RaceHandler<GlobalState> for Handler {
                     }).await?;
                 },
             },
+            "changelog" => {
+                let changelog_url = match goal {
+                    Goal::PicRs2 => Some(format!("https://github.com/fenhl/plando-random-settings/commits/main")),
+                    Goal::Rsl => Some(format!("https://github.com/matthewkirby/plando-random-settings/releases")),
+                    _ => goal.rando_version(self.official_data.as_ref().map(|OfficialRaceData { event, .. }| event)).changelog_url(),
+                };
+                if let Some(changelog_url) = changelog_url {
+                    ctx.say(if let French = goal.language() {
+                        format!("Les notes de version du randomizer utilisé pour cette race sont disponibles ici : {changelog_url}")
+                    } else {
+                        format!("The release notes for the randomizer version used in this race are available at {changelog_url}")
+                    }).await?;
+                } else {
+                    ctx.say(if let French = goal.language() {
+                        format!("Désolé {reply_to}, je ne connais pas de notes de version pour la version du randomizer utilisée pour cette race.")
+                    } else {
+                        format!("Sorry {reply_to}, I don't know where to find the release notes for the randomizer version used in this race.")
+                    }).await?;
+                }
+            }
+            "charity" => if let Some(OfficialRaceData { ref event, .. }) = self.official_data {
+                if let Some(ref charity_donation_url) = event.charity_donation_url {
+                    let total = sqlx::query_scalar!("SELECT charity_total FROM events WHERE series = $1 AND event = $2", event.series as _, &event.event).fetch_one(&ctx.global_state.db_pool).await.to_racetime()?;
+                    ctx.say(if let Some(total) = total {
+                        let total = format!("${}.{:02}", total / 100, total % 100);
+                        if let French = goal.language() {
+                            format!("Le total des dons est actuellement de {total}. Faites un don ici : {charity_donation_url}")
+                        } else {
+                            format!("The donation total is currently {total}. Donate at {charity_donation_url}")
+                        }
+                    } else {
+                        if let French = goal.language() {
+                            format!("Aucun total des dons n'a encore été enregistré. Faites un don ici : {charity_donation_url}")
+                        } else {
+                            format!("No donation total has been recorded yet. Donate at {charity_donation_url}")
+                        }
+                    }).await?;
+                }
+            },
+            "commit" => {
+                let mut rando_commit = lock!(@read state = self.race_state; if let RaceState::Rolled(seed::Data { files: Some(seed::Files::MidosHouse { ref file_stem, .. }), .. }) = *state {
+                    lock!(@read seed_metadata = ctx.global_state.seed_metadata; seed_metadata.get(&**file_stem).and_then(|metadata| metadata.rando_commit.clone()))
+                } else {
+                    None
+                });
+                if rando_commit.is_none() {
+                    if let Some(OfficialRaceData { ref cal_event, .. }) = self.official_data {
+                        rando_commit = sqlx::query_scalar!("SELECT rando_commit FROM races WHERE id = $1", cal_event.race.id as _).fetch_one(&ctx.global_state.db_pool).await.to_racetime()?;
+                    }
+                }
+                if let Some(rando_commit) = rando_commit {
+                    ctx.say(if let French = goal.language() {
+                        format!("Cette seed a été générée avec le commit {rando_commit} du randomizer.")
+                    } else {
+                        format!("This seed was rolled from randomizer commit {rando_commit}.")
+                    }).await?;
+                } else {
+                    ctx.say(if let French = goal.language() {
+                        format!("Désolé {reply_to}, le commit exact du randomizer n'est connu que pour les seeds générées localement à partir d'une branche non épinglée.")
+                    } else {
+                        format!("Sorry {reply_to}, the exact randomizer commit is only recorded for seeds rolled locally from an unpinned branch.")
+                    }).await?;
+                }
+            }
+            "confirmscores" => if !matches!(goal, Goal::TriforceBlitz | Goal::TriforceBlitzProgressionSpoiler) || self.official_data.is_none() {
+                ctx.say(format!("Sorry {reply_to}, this command is only available for official Triforce Blitz races.")).await?;
+            } else if !self.can_monitor(ctx, is_monitor, msg).await.to_racetime()? {
+                ctx.say(format!("Sorry {reply_to}, only race monitors and tournament organizers can do that.")).await?;
+            } else if !matches!(ctx.data().await.status.value, RaceStatusValue::Finished) {
+                ctx.say(format!("Sorry {reply_to}, scores can only be confirmed once the race has finished.")).await?;
+            } else if let Some(OfficialRaceData { ref mut scores_confirmed, .. }) = self.official_data {
+                if mem::replace(scores_confirmed, true) {
+                    ctx.say(format!("Sorry {reply_to}, the scores for this race have already been confirmed.")).await?;
+                } else if !self.check_tfb_finish(ctx).await? {
+                    if let Some(OfficialRaceData { ref mut scores_confirmed, .. }) = self.official_data {
+                        *scores_confirmed = false;
+                    }
+                    ctx.say(format!("Sorry {reply_to}, not all entrants have reported their scores yet. You can enter missing scores using !setscore <entrant> <pieces> <time of last piece>.")).await?;
+                }
+            },
             "draft" | "pick" => match args[..] {
                 [] => self.send_settings(ctx, &if let French = goal.language() {
                     format!("Désolé {reply_to}, un setting doit être choisi. Utilisez un des suivants :")
@@ -3444,63 +5622,66 @@ impl RaceHandler<GlobalState> for Handler {
                     format!("Sorry {reply_to}, only one setting can be drafted at a time. Use “!draft <setting> <value>”")
                 }).await?,
             },
-            "first" => self.draft_action(ctx, msg.user.as_ref(), draft::Action::GoFirst(true)).await?,
-            "fpa" => match args[..] {
-                [] => if self.fpa_enabled {
-                    if let RaceStatusValue::Open | RaceStatusValue::Invitational = ctx.data().await.status.value {
-                        ctx.say(if let French = goal.language() {
-                            "Le FPA ne peut pas être appelé avant que la race ne commence."
-                        } else {
-                            "FPA cannot be invoked before the race starts."
-                        }).await?;
+            "event" => {
+                let (series, event, display_name) = if let Some(OfficialRaceData { ref event, .. }) = self.official_data {
+                    (event.series, Some(event.event.to_string()), event.display_name.clone())
+                } else {
+                    let (series, event) = goal.event();
+                    (series, event.map(str::to_owned), goal.as_str().to_owned())
+                };
+                if let Some(event) = event {
+                    ctx.say(if let French = goal.language() {
+                        format!("Cette race fait partie de {display_name} : https://midos.house/event/{series}/{event}")
                     } else {
-                        if let Some(OfficialRaceData { ref cal_event, ref restreams, ref mut fpa_invoked, ref event, .. }) = self.official_data {
-                            *fpa_invoked = true;
-                            if restreams.is_empty() {
-                                ctx.say(if_chain! {
-                                    if let French = goal.language();
-                                    if let TeamConfig::Solo = event.team_config;
-                                    then {
-                                        format!(
-                                            "@everyone Le FPA a été appelé par {reply_to}.{} La race sera re-timée après le fin de celle-ci.",
-                                            if let RaceSchedule::Async { .. } = cal_event.race.schedule { "" } else { " Le joueur qui ne l'a pas demandé peut continuer à jouer." },
-                                        )
-                                    } else {
-                                        format!(
-                                            "@everyone FPA has been invoked by {reply_to}. T{}he race will be retimed once completed.",
-                                            if let RaceSchedule::Async { .. } = cal_event.race.schedule {
-                                                String::default()
-                                            } else {
-                                                format!(
-                                                    "he {player_team} that did not call FPA can continue playing; t",
-                                                    player_team = if let TeamConfig::Solo = event.team_config { "player" } else { "team" },
-                                                )
-                                            },
-                                        )
-                                    }
-                                }).await?;
-                            } else {
-                                ctx.say(if let French = goal.language() {
-                                    format!("@everyone Le FPA a été appelé par {reply_to}. Merci d'arrêter de jouer, la race étant restreamée.")
-                                } else {
-                                    format!("@everyone FPA has been invoked by {reply_to}. Please pause since this race is being restreamed.")
-                                }).await?;
-                            }
-                        } else {
-                            ctx.say(if let French = goal.language() {
-                                format!("@everyone Le FPA a été appelé par {reply_to}.")
-                            } else {
-                                format!("@everyone FPA has been invoked by {reply_to}.")
-                            }).await?;
-                        }
-                    }
+                        format!("This race is part of {display_name}: https://midos.house/event/{series}/{event}")
+                    }).await?;
+                } else if let Some(latest_event) = sqlx::query_scalar!("SELECT event FROM events WHERE listed AND series = $1 ORDER BY start DESC NULLS LAST LIMIT 1", series as _).fetch_optional(&ctx.global_state.db_pool).await.to_racetime()? {
+                    ctx.say(if let French = goal.language() {
+                        format!("Cette race utilise les paramètres de {display_name}, qui ne correspondent pas à un seul événement. Événement le plus récent de la série : https://midos.house/event/{series}/{latest_event}")
+                    } else {
+                        format!("This race uses the settings for {display_name}, which spans multiple events. Most recent event in the series: https://midos.house/event/{series}/{latest_event}")
+                    }).await?;
                 } else {
                     ctx.say(if let French = goal.language() {
-                        "Le FPA n'est pas activé. Les Race Monitors peuvent l'activer avec !fpa on."
+                        format!("Cette race utilise les paramètres de {display_name}, qui ne correspondent pas à un seul événement.")
                     } else {
-                        "Fair play agreement is not active. Race monitors may enable FPA for this race with !fpa on"
+                        format!("This race uses the settings for {display_name}, which spans multiple events.")
                     }).await?;
-                },
+                }
+            }
+            "findseed" => if self.can_monitor(ctx, is_monitor, msg).await.to_racetime()? {
+                if let Some([hash1, hash2, hash3, hash4, hash5]) = parse_file_hash(&args) {
+                    let races = sqlx::query!(r#"SELECT series AS "series: Series", event, phase, round, room FROM races WHERE hash1 = $1 AND hash2 = $2 AND hash3 = $3 AND hash4 = $4 AND hash5 = $5 ORDER BY start"#, hash1 as _, hash2 as _, hash3 as _, hash4 as _, hash5 as _).fetch_all(&ctx.global_state.db_pool).await.to_racetime()?;
+                    if races.is_empty() {
+                        ctx.say(format!("Sorry {reply_to}, I couldn't find a race with that seed hash.")).await?;
+                    } else {
+                        let races = races.into_iter().map(|row| {
+                            let link = row.room.unwrap_or_else(|| format!("https://midos.house/event/{}/{}/races", row.series, row.event));
+                            match (row.phase, row.round) {
+                                (Some(phase), Some(round)) => format!("{}/{} {phase} {round}: {link}", row.series, row.event),
+                                (Some(phase_or_round), None) | (None, Some(phase_or_round)) => format!("{}/{} {phase_or_round}: {link}", row.series, row.event),
+                                (None, None) => format!("{}/{}: {link}", row.series, row.event),
+                            }
+                        }).collect_vec();
+                        ctx.say(if let Ok(race) = races.iter().exactly_one() {
+                            format!("{reply_to}, that seed was used in {race}")
+                        } else {
+                            format!("{reply_to}, that seed was used in {} races: {}", races.len(), races.iter().format(" • "))
+                        }).await?;
+                    }
+                } else {
+                    ctx.say(format!("Sorry {reply_to}, I don't recognize that seed hash. Please list the 5 hash icons by name, e.g. “!findseed Deku Stick, Lens of Truth, Bow, Bombchu, SOLD OUT”.")).await?;
+                }
+            } else {
+                ctx.say(if let French = goal.language() {
+                    format!("Désolé {reply_to}, seuls {} peuvent faire cela.", if self.is_official() { "les race monitors et les organisateurs du tournoi" } else { "les race monitors" })
+                } else {
+                    format!("Sorry {reply_to}, only {} can do that.", if self.is_official() { "race monitors and tournament organizers" } else { "race monitors" })
+                }).await?;
+            },
+            "first" => self.draft_action(ctx, msg.user.as_ref(), draft::Action::GoFirst(true)).await?,
+            "fpa" => match args[..] {
+                [] => self.invoke_fpa(ctx, goal, msg, None).await?,
                 [ref arg] => match &*arg.to_ascii_lowercase() {
                     "on" => if self.is_official() {
                         ctx.say(if let French = goal.language() {
@@ -3555,26 +5736,52 @@ impl RaceHandler<GlobalState> for Handler {
                         }).await?;
                     },
                     _ => ctx.say(if let French = goal.language() {
-                        format!("Désolé {reply_to}, les seules commandes sont “!fpa on”, “!fpa off” ou “!fpa”.")
+                        format!("Désolé {reply_to}, les seules commandes sont “!fpa on”, “!fpa off”, “!fpa” ou “!fpa reason <raison>”.")
                     } else {
-                        format!("Sorry {reply_to}, I don't recognize that subcommand. Use “!fpa on” or “!fpa off”, or just “!fpa” to invoke FPA.")
+                        format!("Sorry {reply_to}, I don't recognize that subcommand. Use “!fpa on” or “!fpa off”, or “!fpa” or “!fpa reason <reason>” to invoke FPA.")
                     }).await?,
                 },
+                [ref subcommand, ref reason @ ..] if subcommand.eq_ignore_ascii_case("reason") => self.invoke_fpa(ctx, goal, msg, Some(reason.join(" "))).await?,
                 [..] => ctx.say(if let French = goal.language() {
-                    format!("Désolé {reply_to}, les seules commandes sont “!fpa on”, “!fpa off” ou “!fpa”.")
+                    format!("Désolé {reply_to}, les seules commandes sont “!fpa on”, “!fpa off”, “!fpa” ou “!fpa reason <raison>”.")
                 } else {
-                    format!("Sorry {reply_to}, I didn't quite understand that. Use “!fpa on” or “!fpa off”, or just “!fpa” to invoke FPA.")
+                    format!("Sorry {reply_to}, I didn't quite understand that. Use “!fpa on” or “!fpa off”, or “!fpa” or “!fpa reason <reason>” to invoke FPA.")
                 }).await?,
             },
+            "help" => self.send_help(ctx, goal).await?,
+            "load" => if User::from_racetime(&ctx.global_state.db_pool, msg.user.as_ref().map_or("", |user| &user.id)).await.to_racetime()?.and_then(|user| user.discord).is_some_and(|discord| discord.id == FENHL) {
+                let (open_rooms, official_rooms) = lock!(clean_shutdown = ctx.global_state.clean_shutdown; (
+                    clean_shutdown.open_rooms.len(),
+                    clean_shutdown.open_rooms.values().filter(|open_room| open_room.official).count(),
+                ));
+                let rolling_seeds = ctx.global_state.rolling_seeds.load(atomic::Ordering::Relaxed);
+                ctx.say(format!(
+                    "Currently handling {open_rooms} room{} ({official_rooms} official) and rolling {rolling_seeds} seed{}.",
+                    if open_rooms == 1 { "" } else { "s" },
+                    if rolling_seeds == 1 { "" } else { "s" },
+                )).await?;
+            } else {
+                ctx.say(format!("Sorry {reply_to}, only Mido's House admins can do that.")).await?;
+            },
+            "list" => if self.can_monitor(ctx, is_monitor, msg).await.to_racetime()? {
+                self.set_unlisted(ctx, goal, msg, false).await?;
+            } else {
+                ctx.say(if let French = goal.language() {
+                    format!("Désolé {reply_to}, seuls {} peuvent faire cela.", if self.is_official() { "les race monitors et les organisateurs du tournoi" } else { "les race monitors" })
+                } else {
+                    format!("Sorry {reply_to}, only {} can do that.", if self.is_official() { "race monitors and tournament organizers" } else { "race monitors" })
+                }).await?;
+            },
             "lock" => if self.can_monitor(ctx, is_monitor, msg).await.to_racetime()? {
+                Self::record_lock(ctx, msg, true).await?;
                 self.locked = true;
                 ctx.say(if_chain! {
                     if let French = goal.language();
                     if !self.is_official();
                     then {
-                        format!("Race verrouillée. Je ne génèrerai une seed que pour les race monitors.")
+                        format!("Race verrouillée par {reply_to}. Je ne génèrerai une seed ou n'accepterai des actions de draft que pour les race monitors.")
                     } else {
-                        format!("Lock initiated. I will now only roll seeds for {}.", if self.is_official() { "race monitors or tournament organizers" } else { "race monitors" })
+                        format!("Lock initiated by {reply_to}. I will now only roll seeds or accept draft actions for {}.", if self.is_official() { "race monitors or tournament organizers" } else { "race monitors" })
                     }
                 }).await?;
             } else {
@@ -3623,8 +5830,144 @@ impl RaceHandler<GlobalState> for Handler {
                 }).await?;
             },
             "no" => self.draft_action(ctx, msg.user.as_ref(), draft::Action::BooleanChoice(false)).await?,
+            "order" => lock!(@read state = self.race_state; if let Some(draft_kind) = goal.draft_kind() {
+                match *state {
+                    RaceState::Init => if let French = goal.language() {
+                        ctx.say(format!("Désolé {reply_to}, le draft n'a pas débuté. Utilisez “!seed draft” pour en commencer un.")).await?;
+                    } else {
+                        ctx.say(format!("Sorry {reply_to}, no draft has been started. Use “!seed draft” to start one.")).await?;
+                    },
+                    RaceState::Draft { state: ref draft, .. } => if let Some(went_first) = draft.went_first {
+                        let first = if went_first { &self.high_seed_name } else { &self.low_seed_name };
+                        let turns = draft.remaining_turns(draft_kind, self.official_data.as_ref().and_then(|OfficialRaceData { cal_event, .. }| cal_event.race.game)).await.to_racetime()?;
+                        if turns.is_empty() {
+                            if let French = goal.language() {
+                                ctx.say(format!("{first} a commencé le draft. Tous les settings ont été choisis.")).await?;
+                            } else {
+                                ctx.say(format!("{first} went first. All settings have been drafted.")).await?;
+                            }
+                        } else {
+                            let turns = turns.into_iter().map(|(kind, team)| {
+                                let team = team.choose(&self.high_seed_name, &self.low_seed_name);
+                                match (goal.language(), kind) {
+                                    (French, draft::TurnKind::Ban) => format!("ban : {team}"),
+                                    (French, draft::TurnKind::Pick) => format!("pick : {team}"),
+                                    (French, draft::TurnKind::BooleanChoice) => format!("choix : {team}"),
+                                    (_, draft::TurnKind::Ban) => format!("ban: {team}"),
+                                    (_, draft::TurnKind::Pick) => format!("pick: {team}"),
+                                    (_, draft::TurnKind::BooleanChoice) => format!("choice: {team}"),
+                                }
+                            }).join(" • ");
+                            if let French = goal.language() {
+                                ctx.say(format!("{first} a commencé le draft. Ordre restant : {turns}")).await?;
+                            } else {
+                                ctx.say(format!("{first} went first. Remaining order: {turns}")).await?;
+                            }
+                        }
+                    } else if let French = goal.language() {
+                        ctx.say(format!("{} doit encore choisir de commencer le draft en premier ou en second.", self.high_seed_name)).await?;
+                    } else {
+                        ctx.say(format!("{} has yet to choose whether to go first or second.", self.high_seed_name)).await?;
+                    },
+                    RaceState::Rolling | RaceState::Rolled(_) | RaceState::SpoilerSent => if let French = goal.language() {
+                        ctx.say(format!("Désolé {reply_to}, mais il n'y a pas de draft, ou la phase de pick&ban est terminée.")).await?;
+                    } else {
+                        ctx.say(format!("Sorry {reply_to}, there is no settings draft this race or the draft is already completed.")).await?;
+                    },
+                }
+            } else {
+                ctx.say(format!("Sorry {reply_to}, this event doesn't have a settings draft.")).await?;
+            }),
+            "passwordlock" => {
+                let password_lock = lock!(@read state = self.race_state; if let RaceState::Rolled(ref seed) = *state {
+                    Some(seed.extra(Utc::now()).await.to_racetime()?.password.is_some())
+                } else {
+                    None
+                });
+                // the password itself is only posted once the countdown starts
+                ctx.say(match (goal.language(), password_lock, self.password_sent) {
+                    (French, None, _) => format!("Désolé {reply_to}, aucune seed n'a encore été générée pour cette race."),
+                    (French, Some(false), _) => format!("Cette seed n'est pas protégée par un mot de passe."),
+                    (French, Some(true), false) => format!("Cette seed est protégée par un mot de passe. Il sera posté ici au début du compte à rebours."),
+                    (French, Some(true), true) => format!("Cette seed est protégée par un mot de passe. Il a été posté au début du compte à rebours."),
+                    (_, None, _) => format!("Sorry {reply_to}, no seed has been rolled for this race yet."),
+                    (_, Some(false), _) => format!("This seed is not password protected."),
+                    (_, Some(true), false) => format!("This seed is password protected. The password will be posted here when the countdown starts."),
+                    (_, Some(true), true) => format!("This seed is password protected. The password was posted when the countdown started."),
+                }).await?;
+            }
             "presets" => goal.send_presets(ctx).await?,
-            "ready" => if let Some(OfficialRaceData { ref mut restreams, ref cal_event, ref event, .. }) = self.official_data {
+            "publish" => if !matches!(goal, Goal::TriforceBlitz | Goal::TriforceBlitzProgressionSpoiler) || self.official_data.is_none() {
+                ctx.say(format!("Sorry {reply_to}, this command is only available for official Triforce Blitz races.")).await?;
+            } else if !self.is_organizer(ctx, msg).await.to_racetime()? {
+                ctx.say(format!("Sorry {reply_to}, only tournament organizers can do that.")).await?;
+            } else if !matches!(ctx.data().await.status.value, RaceStatusValue::Finished) {
+                ctx.say(format!("Sorry {reply_to}, results can only be published once the race has finished.")).await?;
+            } else if let Some(OfficialRaceData { ref mut scores, ref mut scores_confirmed, .. }) = self.official_data {
+                if mem::replace(scores_confirmed, true) {
+                    ctx.say(format!("Sorry {reply_to}, the results of this race have already been published.")).await?;
+                } else {
+                    // stop waiting for entrants who haven't reported a score, counting them as not having collected any pieces
+                    let mut missing = Vec::default();
+                    for entrant in &ctx.data().await.entrants {
+                        if entrant.status.value == EntrantStatusValue::Done {
+                            let score = scores.entry(entrant.user.id.clone()).or_default();
+                            if score.is_none() {
+                                *score = Some(tfb::Score::default());
+                                missing.push(entrant.user.name.clone());
+                            }
+                        }
+                    }
+                    if !missing.is_empty() {
+                        ctx.say(format!("No score was reported for {}, so they are counted as 0/3.", English.join_str(&missing).unwrap_or_default())).await?;
+                    }
+                    // marks the results as published on the website
+                    self.check_tfb_finish(ctx).await?;
+                    if let Some(OfficialRaceData { ref event, .. }) = self.official_data {
+                        if let Some(organizer_channel) = event.discord_organizer_channel {
+                            organizer_channel.say(&*ctx.global_state.discord_ctx.read().await, MessageBuilder::default()
+                                .push("Triforce Blitz results published by ")
+                                .push_safe(reply_to)
+                                .push(": <https://")
+                                .push(racetime_host())
+                                .push(&ctx.data().await.url)
+                                .push('>')
+                                .build()
+                            ).await.to_racetime()?;
+                        }
+                    }
+                }
+            },
+            "qualifiers" => if let Some(OfficialRaceData { ref cal_event, ref event, .. }) = self.official_data {
+                let mut transaction = ctx.global_state.db_pool.begin().await.to_racetime()?;
+                let has_qualifiers = sqlx::query_scalar!(r#"SELECT EXISTS (SELECT 1 FROM asyncs WHERE series = $1 AND event = $2 AND kind = 'qualifier') AS "exists!""#, event.series as _, &event.event).fetch_one(&mut *transaction).await.to_racetime()?
+                    || sqlx::query_scalar!(r#"SELECT EXISTS (SELECT 1 FROM races WHERE series = $1 AND event = $2 AND (phase = 'Qualifier' OR phase = 'Live Qualifier')) AS "exists!""#, event.series as _, &event.event).fetch_one(&mut *transaction).await.to_racetime()?;
+                transaction.commit().await.to_racetime()?;
+                if has_qualifiers {
+                    let standings_url = format!("https://midos.house/event/{}/{}/teams", event.series, event.event);
+                    ctx.say(match (goal.language(), cal_event.race.phase.as_deref(), cal_event.race.round.as_deref()) {
+                        (French, Some("Qualifier"), Some(round)) => format!("Cette race est le qualifier {round}. Classement des qualifiers : {standings_url}"),
+                        (French, Some("Live Qualifier"), Some(round)) => format!("Cette race est le live qualifier {round}. Classement des qualifiers : {standings_url}"),
+                        (French, _, _) => format!("Classement des qualifiers : {standings_url}"),
+                        (_, Some("Qualifier"), Some(round)) => format!("This is qualifier {round}. Qualifier standings: {standings_url}"),
+                        (_, Some("Live Qualifier"), Some(round)) => format!("This is live qualifier {round}. Qualifier standings: {standings_url}"),
+                        (_, _, _) => format!("Qualifier standings: {standings_url}"),
+                    }).await?;
+                } else {
+                    ctx.say(if let French = goal.language() {
+                        format!("Désolé {reply_to}, cet événement n'a pas de qualifiers.")
+                    } else {
+                        format!("Sorry {reply_to}, this event doesn't have qualifiers.")
+                    }).await?;
+                }
+            } else {
+                ctx.say(if let French = goal.language() {
+                    format!("Désolé {reply_to}, cette commande n'est disponible que pour les races officielles.")
+                } else {
+                    format!("Sorry {reply_to}, this command is only available for official races.")
+                }).await?;
+            },
+            "ready" => if let Some(OfficialRaceData { ref mut restreams, ref required_restream_languages, ref cal_event, ref event, unlisted, .. }) = self.official_data {
                 if let Some(state) = restreams.values_mut().find(|state| state.restreamer_racetime_id.as_ref() == Some(&msg.user.as_ref().expect("received !ready command from bot").id)) {
                     state.ready = true;
                 } else {
@@ -3635,7 +5978,7 @@ impl RaceHandler<GlobalState> for Handler {
                     }).await?;
                     return Ok(())
                 }
-                if restreams.values().all(|state| state.ready) {
+                if restreams.values().filter(|state| required_restream_languages.is_empty() || state.language.is_some_and(|language| required_restream_languages.contains(&language))).all(|state| state.ready) {
                     ctx.say(if_chain! {
                         if let French = goal.language();
                         if let Ok((_, state)) = restreams.iter().exactly_one();
@@ -3643,7 +5986,11 @@ impl RaceHandler<GlobalState> for Handler {
                         then {
                             "Restream prêt. Déverrouillage de l'auto-start."
                         } else {
-                            "All restreams ready, unlocking auto-start…"
+                            if restreams.values().all(|state| state.ready) {
+                                "All restreams ready, unlocking auto-start…"
+                            } else {
+                                "All required restreams ready, unlocking auto-start…"
+                            }
                         }
                     }).await?;
                     let (access_token, _) = racetime::authorize_with_host(&ctx.global_state.host_info, &ctx.global_state.racetime_config.client_id, &ctx.global_state.racetime_config.client_secret, &ctx.global_state.http_client).await?;
@@ -3651,20 +5998,170 @@ impl RaceHandler<GlobalState> for Handler {
                         goal, event, cal_event,
                         ctx.data().await.info_user.clone().unwrap_or_default(),
                         ctx.data().await.info_bot.clone().unwrap_or_default(),
+                        unlisted,
                         true,
                     ).await.edit_with_host(&ctx.global_state.host_info, &access_token, &ctx.global_state.http_client, CATEGORY, &ctx.data().await.slug).await?;
                 } else {
-                    ctx.say(format!("Restream ready, still waiting for other restreams.")).await?;
+                    ctx.say(format!("Restream ready, still waiting for other restreams.")).await?;
+                }
+            } else {
+                ctx.say(if let French = goal.language() {
+                    format!("Désolé {reply_to}, cette commande n'est disponible que pour les races officielles.")
+                } else {
+                    format!("Sorry {reply_to}, this command is only available for official races.")
+                }).await?;
+            },
+            "reroll" => if self.is_official() {
+                ctx.say(if let French = goal.language() {
+                    format!("Désolé {reply_to}, cette commande n'est disponible que dans les practice rooms.")
+                } else {
+                    format!("Sorry {reply_to}, this command is only available in practice rooms.")
+                }).await?;
+            } else if self.rerolls >= MAX_REROLLS {
+                ctx.say(if let French = goal.language() {
+                    format!("Désolé {reply_to}, cette room a déjà utilisé ses {MAX_REROLLS} rerolls. Veuillez ouvrir une nouvelle room pour générer d'autres seeds.")
+                } else {
+                    format!("Sorry {reply_to}, this room has already used all {MAX_REROLLS} rerolls. Please open a new room to roll more seeds.")
+                }).await?;
+            } else if (self.locked || self.on_hold) && !self.can_monitor(ctx, is_monitor, msg).await.to_racetime()? {
+                ctx.say(if let French = goal.language() {
+                    format!("Désolé {reply_to}, la race est verrouillée. Seuls les race monitors peuvent générer une seed pour cette race.")
+                } else {
+                    format!("Sorry {reply_to}, seed rolling is locked. Only race monitors may roll a seed for this race.")
+                }).await?;
+            } else if let RaceStatusValue::Open | RaceStatusValue::Invitational = ctx.data().await.status.value {
+                let is_rolled = lock!(@read state = self.race_state; matches!(*state, RaceState::Rolled(_)));
+                let last_roll = lock!(last_roll = self.last_roll; last_roll.clone());
+                if let (true, Some(last_roll)) = (is_rolled, last_roll) {
+                    self.rerolls += 1;
+                    let remaining = MAX_REROLLS - self.rerolls;
+                    ctx.say(if let French = goal.language() {
+                        format!("Nouvelle seed avec les mêmes settings ({remaining} reroll{} restant{}).", if remaining == 1 { "" } else { "s" }, if remaining == 1 { "" } else { "s" })
+                    } else {
+                        format!("Rerolling with the same settings ({remaining} reroll{} left).", if remaining == 1 { "" } else { "s" })
+                    }).await?;
+                    match last_roll {
+                        LastRoll::Regular { settings, unlock_spoiler_log, language, article, description } => self.roll_seed(ctx, goal.preroll_mode(self.official_data.as_ref().map(|OfficialRaceData { event, .. }| event)), goal.rando_version(self.official_data.as_ref().map(|OfficialRaceData { event, .. }| event)), settings, unlock_spoiler_log, language, article, description).await?,
+                        LastRoll::Rsl { preset, world_count, unlock_spoiler_log, language, article, description } => self.roll_rsl_seed(ctx, preset, world_count, unlock_spoiler_log, language, article, description).await,
+                        LastRoll::Tfb { version, unlock_spoiler_log, language, article, description } => self.roll_tfb_seed(ctx, version, unlock_spoiler_log, language, article, description).await,
+                    }
+                } else {
+                    ctx.say(if let French = goal.language() {
+                        format!("Désolé {reply_to}, il n'y a pas encore de seed à reroll. Utilisez d'abord !seed.")
+                    } else {
+                        format!("Sorry {reply_to}, there is no seed to reroll yet. Use !seed first.")
+                    }).await?;
+                }
+            } else {
+                ctx.say(if let French = goal.language() {
+                    format!("Désolé {reply_to}, mais la race a débuté.")
+                } else {
+                    format!("Sorry {reply_to}, but the race has already started.")
+                }).await?;
+            },
+            "restream" => if self.can_monitor(ctx, is_monitor, msg).await.to_racetime()? {
+                if let Some(ref mut official_data) = self.official_data {
+                    if let [language, restreamer] = &args[..] {
+                        if let Some((language, video_url)) = all::<Language>().find(|iter_language| iter_language.short_code() == language.to_ascii_lowercase()).and_then(|language| Some((language, official_data.cal_event.race.video_urls.get(&language)?))) {
+                            let mut transaction = ctx.global_state.db_pool.begin().await.to_racetime()?;
+                            match parse_user(&mut transaction, &ctx.global_state.http_client, restreamer).await {
+                                Ok(restreamer_racetime_id) => {
+                                    let was_auto_start = official_data.auto_start();
+                                    let state = official_data.restreams.entry(video_url.clone()).or_default();
+                                    state.language = Some(language);
+                                    state.restreamer_racetime_id = Some(restreamer_racetime_id.clone());
+                                    if was_auto_start && !official_data.auto_start() {
+                                        let (access_token, _) = racetime::authorize_with_host(&ctx.global_state.host_info, &ctx.global_state.racetime_config.client_id, &ctx.global_state.racetime_config.client_secret, &ctx.global_state.http_client).await?;
+                                        room_options(
+                                            goal, &official_data.event, &official_data.cal_event,
+                                            ctx.data().await.info_user.clone().unwrap_or_default(),
+                                            ctx.data().await.info_bot.clone().unwrap_or_default(),
+                                            official_data.unlisted,
+                                            false,
+                                        ).await.edit_with_host(&ctx.global_state.host_info, &access_token, &ctx.global_state.http_client, CATEGORY, &ctx.data().await.slug).await?;
+                                    }
+                                    ctx.say(if let French = goal.language() {
+                                        format!("Restreamer {} assigné. Utilisez “!ready” une fois que le restream est prêt.", match language {
+                                            English => "anglais",
+                                            French => "français",
+                                            German => "allemand",
+                                            Portuguese => "portugais",
+                                        })
+                                    } else {
+                                        format!("{language} restreamer assigned. Use “!ready” once the restream is ready.")
+                                    }).await?;
+                                    let mw_rooms = lock!(@read mw_rooms = official_data.mw_rooms; mw_rooms.clone());
+                                    if !mw_rooms.is_empty() {
+                                        let mut msg = MessageBuilder::default();
+                                        msg.push("You have been assigned as restreamer for <https://");
+                                        msg.push(racetime_host());
+                                        msg.push(&ctx.data().await.url);
+                                        msg.push(">. The Mido's House Multiworld room");
+                                        msg.push(if mw_rooms.len() == 1 { " for this race is named " } else { "s for this race are named " });
+                                        msg.push_safe(English.join_str(mw_rooms.iter().map(|mw_room| format!("“{mw_room}”"))).expect("checked to be nonempty"));
+                                        msg.push(", you can find ");
+                                        msg.push(if mw_rooms.len() == 1 { "it" } else { "them" });
+                                        msg.push(" in the multiworld app to follow the race.");
+                                        let msg = msg.build();
+                                        let dm_result = if let Some(discord) = User::from_racetime(&mut *transaction, &restreamer_racetime_id).await.to_racetime()?.and_then(|user| user.discord) {
+                                            let discord_ctx = ctx.global_state.discord_ctx.read().await;
+                                            async {
+                                                discord.id.create_dm_channel(&*discord_ctx).await?.say(&*discord_ctx, &msg).await?;
+                                                Ok::<_, serenity::Error>(())
+                                            }.await.is_ok()
+                                        } else {
+                                            false
+                                        };
+                                        if !dm_result {
+                                            if let Some(organizer_channel) = official_data.event.discord_organizer_channel {
+                                                organizer_channel.say(&*ctx.global_state.discord_ctx.read().await, MessageBuilder::default()
+                                                    .push("Could not DM the multiworld room names for <https://")
+                                                    .push(racetime_host())
+                                                    .push(&ctx.data().await.url)
+                                                    .push("> to the newly assigned restreamer, please send them manually.")
+                                                    .build()
+                                                ).await.to_racetime()?;
+                                            }
+                                        }
+                                    }
+                                }
+                                Err(e) => ctx.say(if let French = goal.language() {
+                                    format!("Désolé {reply_to}, je n'ai pas pu reconnaître le restreamer : {e}")
+                                } else {
+                                    format!("Sorry {reply_to}, I couldn't parse the restreamer: {e}")
+                                }).await?,
+                            }
+                            transaction.commit().await.to_racetime()?;
+                        } else {
+                            ctx.say(if let French = goal.language() {
+                                format!("Désolé {reply_to}, cette race n'est pas restreamée dans cette langue. Langues disponibles : {}", French.join_str(official_data.cal_event.race.video_urls.keys().map(Language::short_code)).unwrap_or_else(|| format!("aucune")))
+                            } else {
+                                format!("Sorry {reply_to}, this race isn't being restreamed in that language. Available languages: {}", English.join_str(official_data.cal_event.race.video_urls.keys().map(Language::short_code)).unwrap_or_else(|| format!("none")))
+                            }).await?;
+                        }
+                    } else {
+                        ctx.say(if let French = goal.language() {
+                            format!("Désolé {reply_to}, je ne reconnais pas ce format. Utilisez “!restream <langue> <restreamer>”, par exemple “!restream fr https://racetime.gg/user/…”.")
+                        } else {
+                            format!("Sorry {reply_to}, I don't recognize that format. Use “!restream <language> <restreamer>”, e.g. “!restream en https://racetime.gg/user/…”.")
+                        }).await?;
+                    }
+                } else {
+                    ctx.say(if let French = goal.language() {
+                        format!("Désolé {reply_to}, cette commande n'est disponible que pour les races officielles.")
+                    } else {
+                        format!("Sorry {reply_to}, this command is only available for official races.")
+                    }).await?;
                 }
             } else {
                 ctx.say(if let French = goal.language() {
-                    format!("Désolé {reply_to}, cette commande n'est disponible que pour les races officielles.")
+                    format!("Désolé {reply_to}, seuls {} peuvent faire cela.", if self.is_official() { "les race monitors et les organisateurs du tournoi" } else { "les race monitors" })
                 } else {
-                    format!("Sorry {reply_to}, this command is only available for official races.")
+                    format!("Sorry {reply_to}, only {} can do that.", if self.is_official() { "race monitors and tournament organizers" } else { "race monitors" })
                 }).await?;
             },
             "restreamer" => if self.can_monitor(ctx, is_monitor, msg).await.to_racetime()? {
-                if let Some(OfficialRaceData { ref mut restreams, ref cal_event, ref event, .. }) = self.official_data {
+                if let Some(OfficialRaceData { ref mut restreams, ref cal_event, ref event, unlisted, .. }) = self.official_data {
                     if let [restream_url, restreamer] = &args[..] {
                         let restream_url = if restream_url.contains('/') {
                             Url::parse(restream_url)
@@ -3681,6 +6178,7 @@ impl RaceHandler<GlobalState> for Handler {
                                             goal, event, cal_event,
                                             ctx.data().await.info_user.clone().unwrap_or_default(),
                                             ctx.data().await.info_bot.clone().unwrap_or_default(),
+                                            unlisted,
                                             false,
                                         ).await.edit_with_host(&ctx.global_state.host_info, &access_token, &ctx.global_state.http_client, CATEGORY, &ctx.data().await.slug).await?;
                                     }
@@ -3710,44 +6208,62 @@ impl RaceHandler<GlobalState> for Handler {
                     format!("Sorry {reply_to}, only {} can do that.", if self.is_official() { "race monitors and tournament organizers" } else { "race monitors" })
                 }).await?;
             },
+            "rolled" => {
+                let rolled_at = lock!(@read state = self.race_state; if let RaceState::Rolled(ref seed) = *state {
+                    Some(match seed.files {
+                        Some(seed::Files::OotrWeb { gen_time, .. }) => Some(gen_time),
+                        Some(seed::Files::MidosHouse { ref file_stem, .. }) => lock!(@read seed_metadata = ctx.global_state.seed_metadata; seed_metadata.get(&**file_stem).and_then(|metadata| metadata.rolled_at)),
+                        Some(seed::Files::TriforceBlitz { .. } | seed::Files::TfbSotd { .. }) | None => None,
+                    })
+                } else {
+                    None
+                });
+                match rolled_at {
+                    Some(Some(rolled_at)) => {
+                        let age = (Utc::now() - rolled_at).to_std().unwrap_or_default();
+                        ctx.say(if let French = goal.language() {
+                            format!("Cette seed a été générée il y a {} ({} UTC).", French.format_duration(age, true), rolled_at.format("%d/%m/%Y %H:%M:%S"))
+                        } else {
+                            format!("This seed was rolled {} ago ({} UTC).", English.format_duration(age, true), rolled_at.format("%Y-%m-%d %H:%M:%S"))
+                        }).await?;
+                    }
+                    Some(None) => ctx.say(if let French = goal.language() {
+                        format!("Désolé {reply_to}, je ne sais pas quand cette seed a été générée.")
+                    } else {
+                        format!("Sorry {reply_to}, I don't know when this seed was rolled.")
+                    }).await?,
+                    None => ctx.say(if let French = goal.language() {
+                        format!("Désolé {reply_to}, aucune seed n'a encore été générée.")
+                    } else {
+                        format!("Sorry {reply_to}, no seed has been rolled yet.")
+                    }).await?,
+                }
+            }
             "score" => if_chain! {
                 if let Goal::TriforceBlitz | Goal::TriforceBlitzProgressionSpoiler = goal;
-                if let Some(OfficialRaceData { ref mut scores, .. }) = self.official_data;
+                if let Some(OfficialRaceData { ref mut scores, scores_confirmed, .. }) = self.official_data;
                 then {
                     if let Some(UserData { ref id, .. }) = msg.user {
-                        if let Some(score) = scores.get_mut(id) {
+                        if scores_confirmed {
+                            ctx.say(format!("Sorry {reply_to}, the scores for this race have already been confirmed. Please contact a race monitor if your score needs to be corrected.")).await?;
+                        } else if let Some(score) = scores.get_mut(id) {
                             let old_score = *score;
-                            if_chain! {
-                                if let Some((pieces, duration)) = args.split_first();
-                                if let Ok(pieces) = pieces.parse();
-                                if pieces <= 3;
-                                then {
-                                    let new_score = tfb::Score {
-                                        last_collection_time: if pieces == 0 {
-                                            Duration::default()
-                                        } else {
-                                            let Some(last_collection_time) = parse_duration(&duration.join(" "), DurationUnit::Hours) else {
-                                                ctx.say(format!("Sorry {reply_to}, I don't recognize that time format. Example format: 1h23m45s")).await?;
-                                                return Ok(())
-                                            };
-                                            last_collection_time
-                                        },
-                                        pieces,
-                                    };
+                            match tfb::Score::parse(&args) {
+                                Ok(new_score) => {
                                     *score = Some(new_score);
                                     ctx.say(if let Some(old_score) = old_score {
-                                        format!("Score edited: {new_score} (was: {old_score})")
+                                        format!("Score edited: {new_score} (was: {old_score}). The score will be recorded once confirmed by a race monitor.")
                                     } else {
-                                        format!("Score reported: {new_score}")
+                                        format!("Score reported: {new_score}. The score will be recorded once confirmed by a race monitor.")
                                     }).await?;
                                     self.check_tfb_finish(ctx).await?;
-                                } else {
-                                    ctx.send_message(
-                                        &format!("Sorry {reply_to}, I didn't quite understand that. Please use this button to try again:"),
-                                        false,
-                                        vec![tfb::report_score_button(None)],
-                                    ).await?;
                                 }
+                                Err(tfb::ScoreParseError::Time) => ctx.say(format!("Sorry {reply_to}, I don't recognize that time format. Example format: 1h23m45s")).await?,
+                                Err(tfb::ScoreParseError::Pieces) => ctx.send_message(
+                                    &format!("Sorry {reply_to}, I didn't quite understand that. Usage: !score <pieces> <time of last piece>, e.g. !score 3 1h23m45s. You can also use this button to try again:"),
+                                    false,
+                                    vec![tfb::report_score_button(None)],
+                                ).await?,
                             }
                         } else {
                             ctx.say(format!("Sorry {reply_to}, only entrants who have already finished can do that.")).await?;
@@ -3760,18 +6276,227 @@ impl RaceHandler<GlobalState> for Handler {
                 }
             },
             "second" => self.draft_action(ctx, msg.user.as_ref(), draft::Action::GoFirst(false)).await?,
-            "seed" | "spoilerseed" => if let RaceStatusValue::Open | RaceStatusValue::Invitational = ctx.data().await.status.value {
+            "seed" | "spoilerseed" => if cmd_name.eq_ignore_ascii_case("seed") && args.first().is_some_and(|arg| arg == "cancel") {
+                if self.is_official() && !self.can_monitor(ctx, is_monitor, msg).await.to_racetime()? {
+                    ctx.say(if let French = goal.language() {
+                        format!("Désolé {reply_to}, seuls les race monitors et les organisateurs du tournoi peuvent faire cela.")
+                    } else {
+                        format!("Sorry {reply_to}, only race monitors and tournament organizers can do that.")
+                    }).await?;
+                } else {
+                    lock!(@write state = self.race_state; if let RaceState::Rolling = *state {
+                        // dropping the update receiver also stops the roll itself, see until_closed
+                        if let Some(seed_roll) = lock!(seed_roll = self.seed_roll; seed_roll.take()) {
+                            seed_roll.abort();
+                        }
+                        *state = RaceState::Init;
+                        sqlx::query!("DELETE FROM seed_queue WHERE room = $1", format!("https://{}{}", racetime_host(), ctx.data().await.url)).execute(&ctx.global_state.db_pool).await.to_racetime()?;
+                        ctx.say(if let French = goal.language() {
+                            format!("La génération de la seed a été annulée. Vous pouvez maintenant générer une nouvelle seed.")
+                        } else {
+                            format!("Seed rolling has been cancelled. You can now roll a new seed.")
+                        }).await?;
+                    } else {
+                        ctx.say(if let French = goal.language() {
+                            format!("Désolé {reply_to}, je ne suis pas en train de générer une seed pour cette race.")
+                        } else {
+                            format!("Sorry {reply_to}, I'm not currently rolling a seed for this race.")
+                        }).await?;
+                    });
+                }
+            } else if cmd_name.eq_ignore_ascii_case("seed") && args.first().is_some_and(|arg| arg == "preview") {
+                let mut transaction = ctx.global_state.db_pool.begin().await.to_racetime()?;
+                let parse_result = goal.parse_seed_command(&mut transaction, &ctx.global_state, self.official_data.as_ref().map(|OfficialRaceData { event, .. }| event), self.is_official(), false, &args[1..]).await.to_racetime()?;
+                // this is only a preview, so don't consume any prerolled seeds
+                transaction.rollback().await.to_racetime()?;
+                match parse_result {
+                    SeedCommandParseResult::Regular { settings, language, article, description, notes, .. } => {
+                        ctx.say(if let French = language {
+                            format!("{reply_to}, cette commande générerait {article} {description}.")
+                        } else {
+                            format!("{reply_to}, this would roll {article} {description}.")
+                        }).await?;
+                        say_joined(ctx, notes).await?;
+                        if let Some(base) = goal.base_settings() {
+                            let diff = seed::settings_diff(&base, &settings);
+                            if diff.is_empty() {
+                                ctx.say(if let French = language {
+                                    "Aucun changement par rapport aux settings de base."
+                                } else {
+                                    "No changes from the base settings."
+                                }).await?;
+                            } else {
+                                ctx.say(if let French = language {
+                                    "Changements par rapport aux settings de base :"
+                                } else {
+                                    "Changes from the base settings:"
+                                }).await?;
+                                say_joined(ctx, diff).await?;
+                            }
+                        } else {
+                            say_joined(ctx, seed::notable_settings(&settings)).await?;
+                        }
+                    }
+                    | SeedCommandParseResult::Rsl { language, article, description, .. }
+                    | SeedCommandParseResult::Tfb { language, article, description, .. }
+                    | SeedCommandParseResult::QueueExisting { language, article, description, .. }
+                        => ctx.say(if let French = language {
+                            format!("{reply_to}, cette commande générerait {article} {description}.")
+                        } else {
+                            format!("{reply_to}, this would roll {article} {description}.")
+                        }).await?,
+                    SeedCommandParseResult::StartDraft { .. } => ctx.say(if let French = goal.language() {
+                        format!("{reply_to}, cette commande commencerait le draft.")
+                    } else {
+                        format!("{reply_to}, this would start a settings draft.")
+                    }).await?,
+                    SeedCommandParseResult::SendPresets { language, msg } => {
+                        ctx.say(if let French = language {
+                            format!("Désolé {reply_to}, {msg}. Veuillez utiliser un des suivants :")
+                        } else {
+                            format!("Sorry {reply_to}, {msg}. Use one of the following:")
+                        }).await?;
+                        goal.send_presets(ctx).await?;
+                    }
+                    SeedCommandParseResult::SendSettings { language, msg } => self.send_settings(ctx, &if let French = language {
+                        format!("Désolé {reply_to}, {msg}")
+                    } else {
+                        format!("Sorry {reply_to}, {msg}")
+                    }, reply_to).await?,
+                    SeedCommandParseResult::Error { language, msg } => ctx.say(if let French = language {
+                        format!("Désolé {reply_to}, {msg}")
+                    } else {
+                        format!("Sorry {reply_to}, {msg}")
+                    }).await?,
+                }
+            } else if cmd_name.eq_ignore_ascii_case("seed") && args.first().is_some_and(|arg| arg == "upload") {
+                // uploading pings all entrants, so it's limited to race monitors and only available before the race
+                if !self.can_monitor(ctx, is_monitor, msg).await.to_racetime()? {
+                    ctx.say(if let French = goal.language() {
+                        format!("Désolé {reply_to}, seuls {} peuvent faire cela.", if self.is_official() { "les race monitors et les organisateurs du tournoi" } else { "les race monitors" })
+                    } else {
+                        format!("Sorry {reply_to}, only {} can do that.", if self.is_official() { "race monitors and tournament organizers" } else { "race monitors" })
+                    }).await?;
+                } else if !matches!(ctx.data().await.status.value, RaceStatusValue::Open | RaceStatusValue::Invitational) {
+                    ctx.say(if let French = goal.language() {
+                        format!("Désolé {reply_to}, mais la race a débuté.")
+                    } else {
+                        format!("Sorry {reply_to}, but the race has already started.")
+                    }).await?;
+                } else if let Some(seed) = lock!(@read state = self.race_state; if let RaceState::Rolled(ref seed) = *state { Some(seed.clone()) } else { None }) {
+                    self.upload_seed(ctx, goal, reply_to, seed).await?;
+                } else {
+                    ctx.say(if let French = goal.language() {
+                        format!("Désolé {reply_to}, il n'y a pas encore de seed à mettre en ligne pour cette race.")
+                    } else {
+                        format!("Sorry {reply_to}, there is no seed to upload for this race yet.")
+                    }).await?;
+                }
+            } else if cmd_name.eq_ignore_ascii_case("seed") && args.first().is_some_and(|arg| arg == "debug") {
+                // a known seed string would make the seed predictable, so this must never be available for real races
+                if !Environment::default().is_dev() {
+                    ctx.say(format!("Sorry {reply_to}, rolling seeds with a fixed seed string is only available in the development environment.")).await?;
+                } else if !self.can_monitor(ctx, is_monitor, msg).await.to_racetime()? {
+                    ctx.say(format!("Sorry {reply_to}, only race monitors can do that.")).await?;
+                } else if !matches!(ctx.data().await.status.value, RaceStatusValue::Open | RaceStatusValue::Invitational) {
+                    ctx.say(format!("Sorry {reply_to}, but the race has already started.")).await?;
+                } else if let [_, rng_seed, args @ ..] = &*args {
+                    if lock!(@read state = self.race_state; matches!(*state, RaceState::Init)) {
+                        let mut transaction = ctx.global_state.db_pool.begin().await.to_racetime()?;
+                        let parse_result = goal.parse_seed_command(&mut transaction, &ctx.global_state, self.official_data.as_ref().map(|OfficialRaceData { event, .. }| event), self.is_official(), false, args).await.to_racetime()?;
+                        // don't consume any prerolled seeds
+                        transaction.rollback().await.to_racetime()?;
+                        match parse_result {
+                            SeedCommandParseResult::Regular { settings, unlock_spoiler_log, language, article, description, notes } => {
+                                say_joined(ctx, notes).await?;
+                                let unlock_spoiler_log = self.unlock_spoiler_log_for_event(unlock_spoiler_log);
+                                self.roll_debug_seed(ctx, goal.rando_version(self.official_data.as_ref().map(|OfficialRaceData { event, .. }| event)), settings, unlock_spoiler_log, rng_seed.clone(), language, article, description).await;
+                            }
+                            | SeedCommandParseResult::Rsl { .. }
+                            | SeedCommandParseResult::Tfb { .. }
+                            | SeedCommandParseResult::QueueExisting { .. }
+                            | SeedCommandParseResult::StartDraft { .. }
+                                => ctx.say(format!("Sorry {reply_to}, fixed seed strings are only supported for seeds rolled directly with the randomizer.")).await?,
+                            SeedCommandParseResult::SendPresets { msg, .. } => {
+                                ctx.say(format!("Sorry {reply_to}, {msg}. Use one of the following:")).await?;
+                                goal.send_presets(ctx).await?;
+                            }
+                            SeedCommandParseResult::SendSettings { msg, .. } => self.send_settings(ctx, &format!("Sorry {reply_to}, {msg}"), reply_to).await?,
+                            SeedCommandParseResult::Error { msg, .. } => ctx.say(format!("Sorry {reply_to}, {msg}")).await?,
+                        }
+                    } else {
+                        ctx.say(format!("Sorry {reply_to}, but I already rolled a seed or am currently rolling one. Use !seed cancel to cancel seed rolling.")).await?;
+                    }
+                } else {
+                    ctx.say(format!("Sorry {reply_to}, I don't recognize that format for debug seeds. Use !seed debug <seed string> <preset…>")).await?;
+                }
+            } else if let RaceStatusValue::Open | RaceStatusValue::Invitational = ctx.data().await.status.value {
                 lock!(@write state = self.race_state; match *state {
-                    RaceState::Init => if self.locked && !self.can_monitor(ctx, is_monitor, msg).await.to_racetime()? {
+                    RaceState::Init => if (self.locked || self.on_hold) && !self.can_monitor(ctx, is_monitor, msg).await.to_racetime()? {
                         ctx.say(if let French = goal.language() {
                             format!("Désolé {reply_to}, la race est verrouillée. Seuls {} peuvent générer une seed pour cette race.", if self.is_official() { "les race monitors et les organisateurs du tournoi" } else { "les race monitors" })
                         } else {
                             format!("Sorry {reply_to}, seed rolling is locked. Only {} may roll a seed for this race.", if self.is_official() { "race monitors or tournament organizers" } else { "race monitors" })
                         }).await?;
                     } else {
+                        let spoiler_seed = cmd_name.to_ascii_lowercase() == "spoilerseed";
+                        let (password_lock, args) = match args.split_first() {
+                            Some((flag, args)) if flag == "lock" => (Some(true), args),
+                            Some((flag, args)) if flag == "unlock" => (Some(false), args),
+                            _ => (None, &args[..]),
+                        };
                         let mut transaction = ctx.global_state.db_pool.begin().await.to_racetime()?;
-                        match goal.parse_seed_command(&mut transaction, &ctx.global_state, self.is_official(), cmd_name.to_ascii_lowercase() == "spoilerseed", &args).await.to_racetime()? {
-                            SeedCommandParseResult::Regular { settings, unlock_spoiler_log, language, article, description } => self.roll_seed(ctx, goal.preroll_seeds(), goal.rando_version(self.official_data.as_ref().map(|OfficialRaceData { event, .. }| (event.series, &*event.event))), settings, unlock_spoiler_log, language, article, description).await,
+                        let mut keep_prerolled_seed = false;
+                        let mut parse_result = goal.parse_seed_command(&mut transaction, &ctx.global_state, self.official_data.as_ref().map(|OfficialRaceData { event, .. }| event), self.is_official(), spoiler_seed, args).await.to_racetime()?;
+                        if let Some(password_lock) = password_lock {
+                            parse_result = match parse_result {
+                                SeedCommandParseResult::Regular { .. } | SeedCommandParseResult::QueueExisting { .. } if password_lock && !goal.rando_version(self.official_data.as_ref().map(|OfficialRaceData { event, .. }| event)).supports_password_lock() => {
+                                    keep_prerolled_seed = true;
+                                    SeedCommandParseResult::Error { language: goal.language(), msg: if let French = goal.language() {
+                                        "la version du randomizer utilisée pour cette race ne supporte pas les seeds protégées par mot de passe.".into()
+                                    } else {
+                                        "the randomizer version used for this race doesn't support password protected seeds.".into()
+                                    } }
+                                }
+                                SeedCommandParseResult::Regular { mut settings, unlock_spoiler_log, language, article, description, notes } => {
+                                    settings.insert(format!("password_lock"), json!(password_lock));
+                                    SeedCommandParseResult::Regular { settings, unlock_spoiler_log, language, article, description, notes }
+                                }
+                                // prerolled seeds may or may not be password protected, so roll a new one instead
+                                SeedCommandParseResult::QueueExisting { language, article, description, .. } => if let Ok(mut settings) = goal.single_settings() {
+                                    keep_prerolled_seed = true;
+                                    settings.insert(format!("password_lock"), json!(password_lock));
+                                    SeedCommandParseResult::Regular { settings, unlock_spoiler_log: goal.unlock_spoiler_log(self.official_data.as_ref().map(|OfficialRaceData { event, .. }| event), self.is_official(), spoiler_seed), language, article, description, notes: Vec::default() }
+                                } else {
+                                    // e.g. the Triforce Blitz seed of the day, which is generated by triforceblitz.com
+                                    keep_prerolled_seed = true;
+                                    SeedCommandParseResult::Error { language: goal.language(), msg: if let French = goal.language() {
+                                        "la protection par mot de passe n'est pas disponible pour ce type de seed.".into()
+                                    } else {
+                                        "password protection isn't available for this kind of seed.".into()
+                                    } }
+                                },
+                                SeedCommandParseResult::Rsl { .. } | SeedCommandParseResult::Tfb { .. } | SeedCommandParseResult::StartDraft { .. } => SeedCommandParseResult::Error { language: goal.language(), msg: if let French = goal.language() {
+                                    "la protection par mot de passe n'est pas disponible pour ce type de seed.".into()
+                                } else {
+                                    "password protection isn't available for this kind of seed.".into()
+                                } },
+                                SeedCommandParseResult::SendPresets { .. } | SeedCommandParseResult::SendSettings { .. } | SeedCommandParseResult::Error { .. } => parse_result,
+                            };
+                            if let SeedCommandParseResult::Regular { .. } = parse_result {
+                                ctx.say(match (goal.language(), password_lock) {
+                                    (French, true) => "Cette seed sera protégée par mot de passe. Vous recevrez le mot de passe au début du compte à rebours.",
+                                    (French, false) => "Cette seed ne sera pas protégée par mot de passe.",
+                                    (_, true) => "This seed will be password protected. You will receive the password as soon as the countdown starts.",
+                                    (_, false) => "This seed will not be password protected.",
+                                }).await?;
+                            }
+                        }
+                        match parse_result {
+                            SeedCommandParseResult::Regular { settings, unlock_spoiler_log, language, article, description, notes } => {
+                                say_joined(ctx, notes).await?;
+                                self.roll_seed(ctx, goal.preroll_mode(self.official_data.as_ref().map(|OfficialRaceData { event, .. }| event)), goal.rando_version(self.official_data.as_ref().map(|OfficialRaceData { event, .. }| event)), settings, unlock_spoiler_log, language, article, description).await?;
+                            }
                             SeedCommandParseResult::Rsl { preset, world_count, unlock_spoiler_log, language, article, description } => self.roll_rsl_seed(ctx, preset, world_count, unlock_spoiler_log, language, article, description).await,
                             SeedCommandParseResult::Tfb { version, unlock_spoiler_log, language, article, description } => self.roll_tfb_seed(ctx, version, unlock_spoiler_log, language, article, description).await,
                             SeedCommandParseResult::QueueExisting { data, language, article, description } => self.queue_existing_seed(ctx, data, language, article, description).await,
@@ -3805,10 +6530,23 @@ impl RaceHandler<GlobalState> for Handler {
                                 format!("Sorry {reply_to}, {msg}")
                             }).await?,
                         }
-                        transaction.commit().await.to_racetime()?;
+                        if keep_prerolled_seed {
+                            transaction.rollback().await.to_racetime()?;
+                        } else {
+                            transaction.commit().await.to_racetime()?;
+                        }
+                    },
+                    RaceState::Draft { .. } => if args.is_empty() {
+                        ctx.say(if let French = goal.language() {
+                            format!("Désolé {reply_to}, le draft des settings est toujours en cours. La seed sera générée une fois le draft terminé.")
+                        } else {
+                            format!("Sorry {reply_to}, settings are still being drafted. The seed will be rolled once the draft is completed.")
+                        }).await?;
+                    } else {
+                        ctx.say(format!("Sorry {reply_to}, settings are already being drafted.")).await?;
                     },
-                    RaceState::Draft { .. } => ctx.say(format!("Sorry {reply_to}, settings are already being drafted.")).await?,
                     RaceState::Rolling => ctx.say(format!("Sorry {reply_to}, but I'm already rolling a seed for this room. Please wait.")).await?,
+                    RaceState::Rolled(ref seed) if args.is_empty() && cmd_name.eq_ignore_ascii_case("seed") => self.repost_seed(ctx, goal, seed).await?,
                     RaceState::Rolled(_) | RaceState::SpoilerSent => ctx.say(format!("Sorry {reply_to}, but I already rolled a seed. Check the race info!")).await?,
                 });
             } else {
@@ -3818,6 +6556,40 @@ impl RaceHandler<GlobalState> for Handler {
                     format!("Sorry {reply_to}, but the race has already started.")
                 }).await?;
             },
+            "setscore" => if !matches!(goal, Goal::TriforceBlitz | Goal::TriforceBlitzProgressionSpoiler) || self.official_data.is_none() {
+                ctx.say(format!("Sorry {reply_to}, this command is only available for official Triforce Blitz races.")).await?;
+            } else if !self.can_monitor(ctx, is_monitor, msg).await.to_racetime()? {
+                ctx.say(format!("Sorry {reply_to}, only race monitors and tournament organizers can do that.")).await?;
+            } else if let Some((name, score_args)) = args.split_first() {
+                let entrant = ctx.data().await.entrants.iter()
+                    .find(|entrant| entrant.status.value == EntrantStatusValue::Done && entrant.user.name.eq_ignore_ascii_case(name))
+                    .map(|entrant| (entrant.user.id.clone(), entrant.user.name.clone()));
+                if let Some((entrant_id, entrant_name)) = entrant {
+                    match tfb::Score::parse(score_args) {
+                        Ok(new_score) => {
+                            if let Some(OfficialRaceData { ref mut scores, scores_confirmed, .. }) = self.official_data {
+                                if scores_confirmed {
+                                    ctx.say(format!("Sorry {reply_to}, the scores for this race have already been confirmed and recorded.")).await?;
+                                } else {
+                                    let old_score = scores.insert(entrant_id, Some(new_score)).flatten();
+                                    ctx.say(if let Some(old_score) = old_score {
+                                        format!("Score for {entrant_name} set to {new_score} (was: {old_score}).")
+                                    } else {
+                                        format!("Score for {entrant_name} set to {new_score}.")
+                                    }).await?;
+                                    self.check_tfb_finish(ctx).await?;
+                                }
+                            }
+                        }
+                        Err(tfb::ScoreParseError::Time) => ctx.say(format!("Sorry {reply_to}, I don't recognize that time format. Example format: 1h23m45s")).await?,
+                        Err(tfb::ScoreParseError::Pieces) => ctx.say(format!("Sorry {reply_to}, I didn't quite understand that. Usage: !setscore <entrant> <pieces> <time of last piece>, e.g. !setscore {entrant_name} 3 1h23m45s")).await?,
+                    }
+                } else {
+                    ctx.say(format!("Sorry {reply_to}, I couldn't find a finished entrant with that name.")).await?;
+                }
+            } else {
+                ctx.say(format!("Sorry {reply_to}, I didn't quite understand that. Usage: !setscore <entrant> <pieces> <time of last piece>")).await?;
+            },
             "settings" => lock!(@read state = self.race_state; self.send_settings(ctx, if let RaceState::Draft { .. } = *state {
                 if let French = goal.language() {
                     "Settings pouvant être actuellement choisis :"
@@ -3832,13 +6604,179 @@ impl RaceHandler<GlobalState> for Handler {
                 }
             }, reply_to).await?),
             "skip" => self.draft_action(ctx, msg.user.as_ref(), draft::Action::Skip).await?,
+            "state" => lock!(@read state = self.race_state; if let Some(draft_kind) = goal.draft_kind() {
+                match *state {
+                    RaceState::Init => if let French = goal.language() {
+                        ctx.say(format!("Désolé {reply_to}, le draft n'a pas débuté. Utilisez “!seed draft” pour en commencer un.")).await?;
+                    } else {
+                        ctx.say(format!("Sorry {reply_to}, no draft has been started. Use “!seed draft” to start one.")).await?;
+                    },
+                    RaceState::Draft { state: ref draft, .. } => {
+                        let picks = draft.picks_summary(draft_kind, &self.high_seed_name, &self.low_seed_name);
+                        if picks.is_empty() {
+                            ctx.say(if let French = goal.language() {
+                                "Aucun setting n'a encore été banni ou choisi."
+                            } else {
+                                "No settings have been banned or picked yet."
+                            }).await?;
+                        } else {
+                            ctx.say(if let French = goal.language() {
+                                "Settings bannis ou choisis jusqu'à présent :"
+                            } else {
+                                "Settings banned or picked so far:"
+                            }).await?;
+                            say_joined(ctx, picks).await?;
+                        }
+                        let step = draft.next_step(draft_kind, self.official_data.as_ref().and_then(|OfficialRaceData { cal_event, .. }| cal_event.race.game), &mut draft::MessageContext::RaceTime { high_seed_name: &self.high_seed_name, low_seed_name: &self.low_seed_name, reply_to }).await.to_racetime()?;
+                        ctx.say(step.message).await?;
+                    }
+                    RaceState::Rolling | RaceState::Rolled(_) | RaceState::SpoilerSent => if let French = goal.language() {
+                        ctx.say(format!("Désolé {reply_to}, mais il n'y a pas de draft, ou la phase de pick&ban est terminée.")).await?;
+                    } else {
+                        ctx.say(format!("Sorry {reply_to}, there is no settings draft this race or the draft is already completed.")).await?;
+                    },
+                }
+            } else {
+                ctx.say(if goal.is_bilingual() {
+                    format!("Sorry {reply_to}, this event doesn't have a settings draft. / Désolé {reply_to}, cet événement n'a pas de draft de settings.")
+                } else if let French = goal.language() {
+                    format!("Désolé {reply_to}, cet événement n'a pas de draft de settings.")
+                } else {
+                    format!("Sorry {reply_to}, this event doesn't have a settings draft.")
+                }).await?;
+            }),
+            "streamdelay" => if let Some(ref stream_delay) = self.stream_delay {
+                if self.can_monitor(ctx, is_monitor, msg).await.to_racetime()? {
+                    if let [ref seconds] = args[..] {
+                        if let Some(new_delay) = seconds.parse().ok().map(Duration::from_secs).filter(|&new_delay| new_delay <= MAX_STREAM_DELAY) {
+                            if stream_delay.is_closed() {
+                                ctx.say(if let French = goal.language() {
+                                    format!("Désolé {reply_to}, le délai de stream ne peut plus être modifié pour cette race car aucun rappel n'est en attente.")
+                                } else {
+                                    format!("Sorry {reply_to}, the stream delay can't be changed for this race since no stream delay reminder is pending.")
+                                }).await?;
+                            } else if let RaceStatusValue::Open | RaceStatusValue::Invitational = ctx.data().await.status.value {
+                                let old_delay = stream_delay.send_replace(new_delay);
+                                println!("stream delay for https://{}{} changed from {}s to {}s by {reply_to}", racetime_host(), ctx.data().await.url, old_delay.as_secs(), new_delay.as_secs());
+                                ctx.say(if new_delay.is_zero() {
+                                    if let French = goal.language() {
+                                        format!("@entrants Le délai de stream n'est plus obligatoire pour cette race.")
+                                    } else {
+                                        format!("@entrants A stream delay is no longer required for this race.")
+                                    }
+                                } else if let French = goal.language() {
+                                    format!("@entrants Le délai de stream pour cette race est maintenant de {} ({} secondes).", French.format_duration(new_delay, true), new_delay.as_secs())
+                                } else {
+                                    format!("@entrants The stream delay for this race has been changed to {} ({} seconds).", English.format_duration(new_delay, true), new_delay.as_secs())
+                                }).await?;
+                            } else {
+                                ctx.say(if let French = goal.language() {
+                                    format!("Désolé {reply_to}, la race a déjà commencé.")
+                                } else {
+                                    format!("Sorry {reply_to}, the race has already started.")
+                                }).await?;
+                            }
+                        } else {
+                            ctx.say(if let French = goal.language() {
+                                format!("Désolé {reply_to}, le délai doit être un nombre de secondes entre 0 et {}.", MAX_STREAM_DELAY.as_secs())
+                            } else {
+                                format!("Sorry {reply_to}, the delay must be a number of seconds between 0 and {}.", MAX_STREAM_DELAY.as_secs())
+                            }).await?;
+                        }
+                    } else {
+                        ctx.say(if let French = goal.language() {
+                            format!("Désolé {reply_to}, je ne reconnais pas ce format. Utilisez par exemple “!streamdelay 300”.")
+                        } else {
+                            format!("Sorry {reply_to}, I didn't quite understand that. Use e.g. “!streamdelay 300”.")
+                        }).await?;
+                    }
+                } else {
+                    ctx.say(if let French = goal.language() {
+                        format!("Désolé {reply_to}, seuls les race monitors et les organisateurs du tournoi peuvent faire cela.")
+                    } else {
+                        format!("Sorry {reply_to}, only race monitors and tournament organizers can do that.")
+                    }).await?;
+                }
+            } else {
+                ctx.say(if let French = goal.language() {
+                    format!("Désolé {reply_to}, cette commande n'est disponible que pour les races officielles.")
+                } else {
+                    format!("Sorry {reply_to}, this command is only available for official races.")
+                }).await?;
+            },
+            "streams" => {
+                let data = ctx.data().await;
+                if data.entrants.is_empty() {
+                    ctx.say(if let French = goal.language() {
+                        "Il n'y a aucun participant dans cette race."
+                    } else {
+                        "There are no entrants in this race."
+                    }).await?;
+                } else {
+                    ctx.say(data.entrants.iter().map(|entrant| {
+                        let status = if entrant.stream_live {
+                            if let French = goal.language() { "en direct" } else { "live" }
+                        } else if data.streaming_required && !entrant.stream_override {
+                            if let French = goal.language() { "pas en direct, stream obligatoire" } else { "not live, streaming required" }
+                        } else {
+                            if let French = goal.language() { "pas en direct" } else { "not live" }
+                        };
+                        if let Some(ref twitch_channel) = entrant.user.twitch_channel {
+                            format!("{}: {twitch_channel} ({status})", entrant.user.name)
+                        } else if let French = goal.language() {
+                            format!("{}: aucune chaîne ({status})", entrant.user.name)
+                        } else {
+                            format!("{}: no channel ({status})", entrant.user.name)
+                        }
+                    }).join(" • ")).await?;
+                }
+            }
+            "time" => if let Some(start) = self.official_data.as_ref().and_then(|OfficialRaceData { cal_event, .. }| cal_event.start()) {
+                let now = Utc::now();
+                ctx.say(if let Ok(remaining) = (start - now).to_std() {
+                    if let French = goal.language() {
+                        format!("Cette race est prévue pour le {} UTC, c'est-à-dire dans {}.", start.format("%d/%m/%Y à %H:%M"), French.format_duration(remaining, true))
+                    } else {
+                        format!("This race is scheduled to start at {} UTC, which is in {}.", start.format("%Y-%m-%d %H:%M"), English.format_duration(remaining, true))
+                    }
+                } else {
+                    let elapsed = (now - start).to_std().unwrap_or_default();
+                    if let French = goal.language() {
+                        format!("Cette race était prévue pour le {} UTC, il y a {}.", start.format("%d/%m/%Y à %H:%M"), French.format_duration(elapsed, true))
+                    } else {
+                        format!("This race was scheduled to start at {} UTC, {} ago.", start.format("%Y-%m-%d %H:%M"), English.format_duration(elapsed, true))
+                    }
+                }).await?;
+            } else {
+                ctx.say(if let French = goal.language() {
+                    format!("Désolé {reply_to}, cette room n'a pas d'horaire prévu.")
+                } else {
+                    format!("Sorry {reply_to}, this room doesn't have a scheduled start time.")
+                }).await?;
+            },
+            "unlist" => if self.can_monitor(ctx, is_monitor, msg).await.to_racetime()? {
+                self.set_unlisted(ctx, goal, msg, true).await?;
+            } else {
+                ctx.say(if let French = goal.language() {
+                    format!("Désolé {reply_to}, seuls {} peuvent faire cela.", if self.is_official() { "les race monitors et les organisateurs du tournoi" } else { "les race monitors" })
+                } else {
+                    format!("Sorry {reply_to}, only {} can do that.", if self.is_official() { "race monitors and tournament organizers" } else { "race monitors" })
+                }).await?;
+            },
             "unlock" => if self.can_monitor(ctx, is_monitor, msg).await.to_racetime()? {
+                Self::record_lock(ctx, msg, false).await?;
                 self.locked = false;
                 ctx.say(if let French = goal.language() {
-                    "Race déverrouillée. N'importe qui peut désormais générer une seed."
+                    format!("Race déverrouillée par {reply_to}. N'importe qui peut désormais générer une seed.")
                 } else {
-                    "Lock released. Anyone may now roll a seed."
+                    format!("Lock released by {reply_to}. Anyone may now roll a seed.")
                 }).await?;
+                if mem::take(&mut self.on_hold) {
+                    // a settings draft may have been completed while the room was on hold
+                    lock!(@read state = self.race_state; if let RaceState::Draft { .. } = *state {
+                        self.advance_draft(ctx, &state).await?;
+                    });
+                }
             } else {
                 ctx.say(if let French = goal.language() {
                     format!("Désolé {reply_to}, seuls {} peuvent faire cela.", if self.is_official() { "les race monitors et les organisateurs du tournoi" } else { "les race monitors" })
@@ -3859,12 +6797,19 @@ impl RaceHandler<GlobalState> for Handler {
     async fn race_data(&mut self, ctx: &RaceContext<GlobalState>, _old_race_data: RaceData) -> Result<(), Error> {
         let data = ctx.data().await;
         let goal = self.goal(ctx).await.to_racetime()?;
-        if let Some(OfficialRaceData { ref entrants, ref mut scores, .. }) = self.official_data {
+        if let Some(OfficialRaceData { ref entrants, ref mut scores, ref alternates, ref event, ref cal_event, ref teams_by_entrant, ref mut partner_finish_notified, .. }) = self.official_data {
             for entrant in &data.entrants {
                 match entrant.status.value {
                     EntrantStatusValue::Requested => if entrants.contains(&entrant.user.id) {
                         ctx.accept_request(&entrant.user.id).await?;
                     },
+                    EntrantStatusValue::Declined => if let RaceStatusValue::Open | RaceStatusValue::Invitational = data.status.value {
+                        if let Some(alternate) = alternates.iter().find(|alternate| alternate.team_members.contains(&entrant.user.id)) {
+                            if !data.entrants.iter().any(|entrant| entrant.user.id == alternate.racetime_id) {
+                                Self::promote_alternate(ctx, alternate, &entrant.user.name, event.discord_organizer_channel, goal.language()).await?;
+                            }
+                        }
+                    },
                     EntrantStatusValue::Done => if let Goal::TriforceBlitz | Goal::TriforceBlitzProgressionSpoiler = goal {
                         if let hash_map::Entry::Vacant(entry) = scores.entry(entrant.user.id.clone()) {
                             let reply_to = &entrant.user.name;
@@ -3875,6 +6820,23 @@ impl RaceHandler<GlobalState> for Handler {
                             ).await?;
                             entry.insert(None);
                         }
+                    } else if let Goal::CoOpS3 = goal {
+                        // only say that a team member finished, since finish times stay hidden until the race ends
+                        if let Some(&team_id) = teams_by_entrant.get(&entrant.user.id) {
+                            if partner_finish_notified.insert(team_id) {
+                                let all_done = teams_by_entrant.iter()
+                                    .filter(|&(_, &team)| team == team_id)
+                                    .all(|(member, _)| data.entrants.iter().any(|entrant| entrant.user.id == *member && entrant.status.value == EntrantStatusValue::Done));
+                                if !all_done {
+                                    let team_name = cal_event.active_teams().find(|team| team.id == team_id).and_then(|team| team.name.as_deref());
+                                    ctx.say(if let French = goal.language() {
+                                        format!("{} : un membre de l'équipe a terminé.", team_name.unwrap_or("(équipe sans nom)"))
+                                    } else {
+                                        format!("{}: one team member has finished.", team_name.unwrap_or("(unnamed team)"))
+                                    }).await?;
+                                }
+                            }
+                        }
                     },
                     _ => {}
                 }
@@ -3886,6 +6848,15 @@ impl RaceHandler<GlobalState> for Handler {
                 self.start_saved = true;
             }
         }
+        if let RaceStatusValue::Finished = data.status.value {
+            // recorded before the spoiler log is unlocked below, since that discards the seed data
+            if let Some(OfficialRaceData { ref cal_event, fpa_invoked, .. }) = self.official_data {
+                // the summary is supplementary, so don't let a failure here keep the result from being reported
+                if let Err(e) = self.record_result_summary(ctx, &data, cal_event, fpa_invoked).await {
+                    eprintln!("failed to record result summary for https://{}{}: {e} ({e:?})", racetime_host(), data.url);
+                }
+            }
+        }
         match data.status.value {
             RaceStatusValue::Pending => if !self.password_sent {
                 lock!(@read state = self.race_state; if let RaceState::Rolled(ref seed) = *state {
@@ -3904,6 +6875,41 @@ impl RaceHandler<GlobalState> for Handler {
                                 }
                                 ChannelId::new(1306254442298998884).say(&*ctx.global_state.discord_ctx.read().await, msg.build()).await.to_racetime()?; //TODO move channel ID to database
                             }
+                            // also DM the password to entrants so they can look it up again if they miss it in the race room
+                            let discord_ctx = ctx.global_state.discord_ctx.read().await;
+                            let mut msg = MessageBuilder::default();
+                            msg.push("Seed password for your ");
+                            msg.push_safe(&event.display_name);
+                            msg.push(" race: ");
+                            msg.push_emoji(&ReactionType::Custom { animated: false, id: EmojiId::new(658692193338392614), name: Some(format!("staffClef")) });
+                            for note in password {
+                                msg.push_emoji(&ocarina_note_to_ootr_discord_emoji(note));
+                            }
+                            let msg = msg.build();
+                            let mut not_reached = Vec::default();
+                            for entrant in &data.entrants {
+                                if let Some(discord) = User::from_racetime(&ctx.global_state.db_pool, &entrant.user.id).await.to_racetime()?.and_then(|user| user.discord) {
+                                    let dm_result = async {
+                                        discord.id.create_dm_channel(&*discord_ctx).await?.say(&*discord_ctx, &msg).await?;
+                                        Ok::<_, serenity::Error>(())
+                                    }.await;
+                                    if dm_result.is_err() {
+                                        not_reached.push(&entrant.user.name);
+                                    }
+                                } else {
+                                    not_reached.push(&entrant.user.name);
+                                }
+                            }
+                            if !not_reached.is_empty() {
+                                if let Some(organizer_channel) = event.discord_organizer_channel {
+                                    let mut msg = MessageBuilder::default();
+                                    msg.push("Could not DM the seed password for ");
+                                    msg.push(format!("<https://{}{}>", racetime_host(), data.url));
+                                    msg.push(" to the following entrants because they have no Discord account linked to Mido's House or don't accept DMs: ");
+                                    msg.push_safe(not_reached.into_iter().join(", "));
+                                    organizer_channel.say(&*discord_ctx, msg.build()).await.to_racetime()?;
+                                }
+                            }
                         }
                     }
                 });
@@ -3914,7 +6920,13 @@ impl RaceHandler<GlobalState> for Handler {
                     self.break_notifications.get_or_insert_with(|| {
                         let ctx = ctx.clone();
                         tokio::spawn(async move {
-                            sleep(breaks.interval - Duration::from_secs(5 * 60)).await;
+                            // align with the start of the race in case it was already in progress when this handler started, e.g. after a reconnect
+                            let elapsed = ctx.data().await.started_at.and_then(|started_at| (Utc::now() - started_at).to_std().ok()).unwrap_or_default();
+                            let mut next_reminder = breaks.interval - Duration::from_secs(5 * 60);
+                            while next_reminder < elapsed {
+                                next_reminder += breaks.interval;
+                            }
+                            sleep(next_reminder - elapsed).await;
                             while Self::should_handle_inner(&*ctx.data().await, ctx.global_state.clone(), Some(None)).await {
                                 let (_, ()) = tokio::join!(
                                     ctx.say(if let French = goal.language() {
@@ -4041,7 +7053,38 @@ impl RaceHandler<GlobalState> for Handler {
             }
             RaceStatusValue::Finished => if self.unlock_spoiler_log(ctx, goal).await? {
                 if let Goal::TriforceBlitz | Goal::TriforceBlitzProgressionSpoiler = goal {
-                    self.check_tfb_finish(ctx).await?;
+                    if !self.check_tfb_finish(ctx).await? {
+                        if let Some(OfficialRaceData { ref event, goal, .. }) = self.official_data {
+                            let ctx = ctx.clone();
+                            let score_wait = event.score_wait.unwrap_or_else(|| goal.default_score_wait());
+                            let organizer_channel = event.discord_organizer_channel;
+                            self.cleanup_timeout = Some(tokio::spawn(async move {
+                                sleep(score_wait).await;
+                                let room_url = format!("https://{}{}", racetime_host(), ctx.data().await.url);
+                                let mut organizers_notified = false;
+                                if let Some(organizer_channel) = organizer_channel {
+                                    match organizer_channel.say(&*ctx.global_state.discord_ctx.read().await, MessageBuilder::default()
+                                        //TODO mention organizer role
+                                        .push("Triforce Blitz scores are still missing or unconfirmed after the score reporting period: <")
+                                        .push(&room_url)
+                                        .push('>')
+                                        .build()
+                                    ).await {
+                                        Ok(_) => organizers_notified = true,
+                                        Err(e) => eprintln!("failed to notify organizers about missing scores in {room_url}: {e} ({e:?})"),
+                                    }
+                                }
+                                // posting a message also makes the handler check whether it should stop
+                                if let Err(e) = ctx.say(if organizers_notified {
+                                    "The score reporting period is over. Tournament organizers have been notified."
+                                } else {
+                                    "The score reporting period is over."
+                                }).await {
+                                    eprintln!("failed to post end of score reporting period in {room_url}: {e} ({e:?})");
+                                }
+                            }));
+                        }
+                    }
                 } else {
                     if let Some(OfficialRaceData { ref cal_event, ref event, fpa_invoked, .. }) = self.official_data {
                         self.official_race_finished(ctx, data, cal_event, event, fpa_invoked, None).await?;
@@ -4049,6 +7092,12 @@ impl RaceHandler<GlobalState> for Handler {
                 }
             },
             RaceStatusValue::Cancelled => {
+                // stop a seed that's still waiting to be rolled or posted so it doesn't waste generation time or keep its spot in the multiworld queue, see until_closed
+                if let Some(seed_roll) = lock!(seed_roll = self.seed_roll; seed_roll.take()) {
+                    seed_roll.abort();
+                }
+                // make sure the seed isn't resumed from the queue if the bot restarts, since a new room for the same race would roll its own seed
+                sqlx::query!("DELETE FROM seed_queue WHERE room = $1", format!("https://{}{}", racetime_host(), ctx.data().await.url)).execute(&ctx.global_state.db_pool).await.to_racetime()?;
                 if !self.password_sent {
                     lock!(@read state = self.race_state; if let RaceState::Rolled(ref seed) = *state {
                         let extra = seed.extra(Utc::now()).await.to_racetime()?;
@@ -4206,6 +7255,7 @@ pub(crate) async fn create_room(transaction: &mut Transaction<'_, Postgres>, dis
                     goal, event, cal_event,
                     info_user,
                     String::default(),
+                    cal_event.is_private_async_part(),
                     cal_event.is_private_async_part() || cal_event.race.video_urls.is_empty(),
                 ).await.start_with_host(host_info, &access_token, &http_client, CATEGORY).await?;
                 let room_url = Url::parse(&format!("https://{}/{CATEGORY}/{race_slug}", host_info.hostname))?;
@@ -4349,13 +7399,16 @@ async fn prepare_seeds(global_state: Arc<GlobalState>, mut seed_cache_rx: watch:
         let event_rows = sqlx::query!(r#"SELECT series AS "series: Series", event FROM events WHERE end_time IS NULL OR end_time > NOW()"#).fetch_all(&global_state.db_pool).await.to_racetime()?;
         for goal in all::<Goal>() {
             if let Ok(settings) = goal.single_settings() {
-                if goal.preroll_seeds() == PrerollMode::Long && event_rows.iter().any(|row| goal.matches_event(row.series, &row.event)) {
+                if event_rows.iter().any(|row| goal.matches_event(row.series, &row.event)) {
                     loop {
                         let mut transaction = global_state.db_pool.begin().await.to_racetime()?;
+                        let mut any_long = false;
                         let mut num_wanted_seeds = 1;
                         for row in &event_rows {
                             if goal.matches_event(row.series, &row.event) {
                                 let Some(event) = event::Data::new(&mut transaction, row.series, &row.event).await.to_racetime()? else { continue };
+                                if goal.preroll_mode(Some(&event)) != PrerollMode::Long { continue }
+                                any_long = true;
                                 num_wanted_seeds += Race::for_event(&mut transaction, &global_state.http_client, &event).await.to_racetime()?
                                     .into_iter()
                                     .filter(|race| race
@@ -4371,6 +7424,7 @@ async fn prepare_seeds(global_state: Arc<GlobalState>, mut seed_cache_rx: watch:
                             }
                         }
                         transaction.commit().await.to_racetime()?;
+                        if !any_long { break }
                         let num_prerolled_seeds = sqlx::query_scalar!("SELECT 1 FROM prerolled_seeds WHERE goal_name = $1", goal.as_str()).fetch(&global_state.db_pool)
                             .try_fold(0, |acc, _| future::ok(acc + 1)).await.to_racetime()?;
                         if num_prerolled_seeds >= num_wanted_seeds { break }
@@ -4378,19 +7432,23 @@ async fn prepare_seeds(global_state: Arc<GlobalState>, mut seed_cache_rx: watch:
                             let mut seed_rx = global_state.clone().roll_seed(
                                 PrerollMode::Long,
                                 false,
+                                false,
                                 None,
                                 goal.rando_version(None),
                                 settings.clone(),
-                                goal.unlock_spoiler_log(false, false),
+                                goal.unlock_spoiler_log(None, false, false),
                             );
                             loop {
                                 select! {
                                     () = &mut shutdown => break 'outer,
                                     Some(update) = seed_rx.recv() => match update {
-                                        SeedRollUpdate::Queued(_) |
+                                        SeedRollUpdate::Queued(..) |
                                         SeedRollUpdate::MovedForward(_) |
-                                        SeedRollUpdate::Started => {}
-                                        SeedRollUpdate::Done { seed, rsl_preset: _, unlock_spoiler_log: _ } => {
+                                        SeedRollUpdate::MovedBack(_) |
+                                        SeedRollUpdate::Started |
+                                        SeedRollUpdate::WebFallback |
+                                        SeedRollUpdate::LocalQueued => {}
+                                        SeedRollUpdate::Done { seed, rsl_preset: _, unlock_spoiler_log: _, rando_commit: _, attempts: _ } => {
                                             let extra = seed.extra(Utc::now()).await.to_racetime()?;
                                             let [hash1, hash2, hash3, hash4, hash5] = match extra.file_hash {
                                                 Some(hash) => hash.map(Some),
@@ -4412,7 +7470,7 @@ async fn prepare_seeds(global_state: Arc<GlobalState>, mut seed_cache_rx: watch:
                                                         hash4 as _,
                                                         hash5 as _,
                                                         extra.password.map(|password| password.into_iter().map(char::from).collect::<String>()),
-                                                        goal.unlock_spoiler_log(false, false) == UnlockSpoilerLog::Progression,
+                                                        goal.unlock_spoiler_log(None, false, false) == UnlockSpoilerLog::Progression,
                                                     ).execute(&global_state.db_pool).await.to_racetime()?;
                                                 }
                                                 _ => unimplemented!("unexpected seed files in prerolled seed"),
@@ -4536,7 +7594,34 @@ async fn handle_rooms(global_state: Arc<GlobalState>, racetime_config: &ConfigRa
     }
 }
 
+/// Removes multiworld seed queue entries for rooms that finished or were cancelled while the bot was offline.
+///
+/// Entries for rooms that are still open are resumed by their room handlers.
+/// Entries whose rooms can't be checked are kept and tried again on the next start.
+async fn clean_seed_queue(global_state: &GlobalState) -> Result<(), Error> {
+    for room in sqlx::query_scalar!("SELECT room FROM seed_queue ORDER BY queued_at").fetch_all(&global_state.db_pool).await.to_racetime()? {
+        if let Err(e) = clean_seed_queue_entry(global_state, &room).await {
+            eprintln!("failed to check seed queue entry for {room}: {e} ({e:?})");
+        }
+    }
+    Ok(())
+}
+
+async fn clean_seed_queue_entry(global_state: &GlobalState, room: &str) -> Result<(), Error> {
+    let race_data = global_state.http_client.get(format!("{room}/data"))
+        .send().await?
+        .detailed_error_for_status().await.to_racetime()?
+        .json_with_text_in_error::<RaceData>().await.to_racetime()?;
+    if let RaceStatusValue::Finished | RaceStatusValue::Cancelled = race_data.status.value {
+        sqlx::query!("DELETE FROM seed_queue WHERE room = $1", room).execute(&global_state.db_pool).await.to_racetime()?;
+    }
+    Ok(())
+}
+
 pub(crate) async fn main(config: Config, shutdown: rocket::Shutdown, global_state: Arc<GlobalState>, seed_cache_rx: watch::Receiver<()>) -> Result<(), Error> {
+    if let Err(e) = clean_seed_queue(&global_state).await {
+        eprintln!("failed to clean up multiworld seed queue: {e} ({e:?})");
+    }
     let ((), (), ()) = tokio::try_join!(
         prepare_seeds(global_state.clone(), seed_cache_rx, shutdown.clone()),
         create_rooms(global_state.clone(), shutdown.clone()),
@@ -359,17 +359,75 @@ async fn report_ffa(ctx: &RaceContext<GlobalState>, cal_event: &cal::Event, even
 }
 
 impl Handler {
-    pub(super) async fn check_tfb_finish(&self, ctx: &RaceContext<GlobalState>) -> Result<(), Error> {
+    /// Returns `false` if some entrants have yet to report their scores.
+    pub(super) async fn check_tfb_finish(&self, ctx: &RaceContext<GlobalState>) -> Result<bool, Error> {
         let data = ctx.data().await;
-        let Some(OfficialRaceData { ref cal_event, ref event, fpa_invoked, ref scores, .. }) = self.official_data else { return Ok(()) };
+        let Some(OfficialRaceData { ref cal_event, ref event, fpa_invoked, ref scores, scores_confirmed, .. }) = self.official_data else { return Ok(false) };
         if let Some(scores) = data.entrants.iter().map(|entrant| match entrant.status.value {
             EntrantStatusValue::Dnf => Some(tfb::Score::default()),
             EntrantStatusValue::Done => scores.get(&entrant.user.id).and_then(|&score| score),
             _ => None,
-        }.map(|score| (entrant.user.id.clone(), score))).collect() {
-            ctx.say("All scores received. Thank you for playing Triforce Blitz, see you next race!").await?;
-            self.official_race_finished(ctx, data, cal_event, event, fpa_invoked, Some(scores)).await?;
+        }.map(|score| (entrant.user.id.clone(), score))).collect::<Option<HashMap<_, _>>>() {
+            if scores_confirmed {
+                // keep the timeout running until then so the result isn't lost if nobody confirms the scores, see Handler::should_stop
+                if let Some(ref cleanup_timeout) = self.cleanup_timeout {
+                    cleanup_timeout.abort();
+                }
+                sqlx::query!("UPDATE races SET results_published = TRUE WHERE id = $1", cal_event.race.id as _).execute(&ctx.global_state.db_pool).await.to_racetime()?;
+                ctx.say("Scores confirmed. Thank you for playing Triforce Blitz, see you next race!").await?;
+                self.official_race_finished(ctx, data, cal_event, event, fpa_invoked, Some(scores)).await?;
+            } else {
+                ctx.say("All scores received. A race monitor can now confirm them using !confirmscores or correct a score using !setscore <entrant> <pieces> <time of last piece>.").await?;
+                if let Some(organizer_channel) = event.discord_organizer_channel {
+                    organizer_channel.say(&*ctx.global_state.discord_ctx.read().await, MessageBuilder::default()
+                        //TODO mention organizer role
+                        .push("Triforce Blitz scores need to be confirmed: <https://")
+                        .push(racetime_host())
+                        .push(&data.url)
+                        .push('>')
+                        .build()
+                    ).await.to_racetime()?;
+                }
+            }
+            Ok(true)
+        } else {
+            Ok(false)
         }
+    }
+
+    /// Stores a machine-readable summary of this official race room so it can be retrieved via [`crate::api::race_result_summary`].
+    pub(super) async fn record_result_summary(&self, ctx: &RaceContext<GlobalState>, data: &RaceData, cal_event: &cal::Event, fpa_invoked: bool) -> Result<(), Error> {
+        let room = format!("https://{}{}", racetime_host(), data.url);
+        let (seed_link, file_hash) = lock!(@read state = self.race_state; if let RaceState::Rolled(ref seed) = *state {
+            let extra = seed.extra(Utc::now()).await.to_racetime()?;
+            (seed.files.as_ref().map(seed_url), extra.file_hash)
+        } else {
+            (None, None)
+        });
+        let summary = json!({
+            "room": room,
+            "entrants": data.entrants.iter().map(|entrant| json!({
+                "racetimeId": entrant.user.id,
+                "name": entrant.user.name,
+                "team": entrant.team.as_ref().map(|team| &team.slug),
+                "status": match entrant.status.value {
+                    EntrantStatusValue::Done => "done",
+                    EntrantStatusValue::Dnf => "dnf",
+                    EntrantStatusValue::Dq => "dq",
+                    _ => "other",
+                },
+                "finishTime": entrant.finish_time.map(|finish_time| finish_time.as_secs_f64()),
+            })).collect_vec(),
+            "fpaInvoked": fpa_invoked,
+            "breaks": self.breaks.map(|breaks| json!({
+                "duration": breaks.duration.as_secs(),
+                "interval": breaks.interval.as_secs(),
+            })),
+            "seedUrl": seed_link,
+            "fileHash": file_hash.map(|file_hash| file_hash.map(|icon| icon.to_string())),
+        });
+        // the race data may be updated again after the race has finished, at which point the seed may no longer be available
+        sqlx::query!("INSERT INTO race_result_summaries (race, room, summary) VALUES ($1, $2, $3) ON CONFLICT (room) DO NOTHING", cal_event.race.id as _, room, sqlx::types::Json(&summary) as _).execute(&ctx.global_state.db_pool).await.to_racetime()?;
         Ok(())
     }
 
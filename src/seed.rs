@@ -13,7 +13,10 @@ use {
     rocket_util::OptSuffix,
     crate::{
         prelude::*,
-        racetime_bot::SeedMetadata,
+        racetime_bot::{
+            SeedMetadata,
+            UnlockSpoilerLog,
+        },
     },
 };
 
@@ -21,7 +24,144 @@ use {
 #[cfg(windows)] pub(crate) const DIR: &str = "C:/Users/fenhl/games/zelda/oot/midos-house-seeds";
 
 /// ootrandomizer.com seeds are deleted after 60 days (https://discord.com/channels/274180765816848384/1248210891636342846/1257367685658837126)
-const WEB_TIMEOUT: TimeDelta = TimeDelta::days(60);
+pub(crate) const WEB_TIMEOUT: TimeDelta = TimeDelta::days(60);
+
+/// Settings which are worth pointing out to players ahead of a race, in the order in which they should be listed.
+const NOTABLE_SETTINGS: [&str; 32] = [
+    "world_count",
+    "bridge",
+    "bridge_medallions",
+    "bridge_stones",
+    "bridge_rewards",
+    "bridge_tokens",
+    "bridge_hearts",
+    "trials",
+    "shuffle_ganon_bosskey",
+    "ganon_bosskey_medallions",
+    "ganon_bosskey_stones",
+    "ganon_bosskey_rewards",
+    "ganon_bosskey_tokens",
+    "ganon_bosskey_hearts",
+    "shuffle_smallkeys",
+    "shuffle_hideoutkeys",
+    "shuffle_bosskeys",
+    "shuffle_mapcompass",
+    "open_forest",
+    "open_kakariko",
+    "open_door_of_time",
+    "zora_fountain",
+    "gerudo_fortress",
+    "starting_age",
+    "shuffle_dungeon_entrances",
+    "shuffle_interior_entrances",
+    "shuffle_overworld_entrances",
+    "shuffle_song_items",
+    "shopsanity",
+    "tokensanity",
+    "shuffle_scrubs",
+    "mq_dungeons_mode",
+];
+
+fn format_setting_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => format!("none"),
+        serde_json::Value::Bool(true) => format!("on"),
+        serde_json::Value::Bool(false) => format!("off"),
+        serde_json::Value::Number(n) => n.to_string(),
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Array(values) => if values.is_empty() { format!("none") } else { values.iter().map(format_setting_value).join(", ") },
+        serde_json::Value::Object(_) => value.to_string(),
+    }
+}
+
+/// Lists the [`NOTABLE_SETTINGS`] which are explicitly set in the given settings, formatted for chat messages.
+pub(crate) fn notable_settings(settings: &serde_json::Map<String, serde_json::Value>) -> Vec<String> {
+    NOTABLE_SETTINGS.into_iter()
+        .filter_map(|name| settings.get(name).map(|value| format!("{name}: {}", format_setting_value(value))))
+        .collect()
+}
+
+/// Lists the settings which differ between `base` and `settings`, formatted for chat messages.
+///
+/// Settings which are only present in `base` are listed as being reset to the randomizer's default.
+pub(crate) fn settings_diff(base: &serde_json::Map<String, serde_json::Value>, settings: &serde_json::Map<String, serde_json::Value>) -> Vec<String> {
+    settings.iter()
+        .filter(|&(name, value)| base.get(name) != Some(value))
+        .map(|(name, value)| format!("{name}: {}", format_setting_value(value)))
+        .chain(base.keys().filter(|name| !settings.contains_key(*name)).map(|name| format!("{name}: default")))
+        .collect()
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum SettingsPresetError {
+    #[error(transparent)] Json(#[from] serde_json::Error),
+    #[error("settings preset is empty")]
+    Empty,
+    #[error("settings preset sets {0}, which is managed by Mido's House")]
+    Managed(String),
+    #[error("setting names in a settings preset must be nonempty")]
+    EmptyName,
+    #[error("user_message in a settings preset must be a string")]
+    UserMessage,
+    #[error("cosmetics preset sets {0}, which isn't a cosmetic setting")]
+    NotCosmetic(String),
+}
+
+/// Parses and validates a settings preset, i.e. a JSON object mapping randomizer setting names to their values.
+///
+/// This allows the settings for a goal to be stored as a file in `assets/event` instead of being built in code.
+pub(crate) fn parse_settings_preset(json: &str) -> Result<serde_json::Map<String, serde_json::Value>, SettingsPresetError> {
+    let settings = serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(json)?;
+    if settings.is_empty() { return Err(SettingsPresetError::Empty) }
+    for (name, value) in &settings {
+        match &**name {
+            "" => return Err(SettingsPresetError::EmptyName),
+            // set based on the goal's spoiler log unlock mode
+            "create_spoiler" => return Err(SettingsPresetError::Managed(name.clone())),
+            "user_message" => if !value.is_string() { return Err(SettingsPresetError::UserMessage) },
+            _ => {}
+        }
+    }
+    Ok(settings)
+}
+
+/// Whether the given randomizer setting is purely cosmetic, i.e. it can't affect logic or the file hash.
+fn is_cosmetic_setting(name: &str) -> bool {
+    matches!(name,
+        | "background_music"
+        | "correct_model_colors"
+        | "default_targeting"
+        | "disable_battle_music"
+        | "display_dpad"
+        | "dpad_dungeon_menu"
+        | "fanfares"
+        | "model_adult"
+        | "model_child"
+        | "ocarina_fanfares"
+        | "randomize_all_cosmetics"
+        | "randomize_all_sfx"
+        | "slowdown_music_when_lowhp"
+        | "speedup_music_for_last_triforce_piece"
+        | "sword_trail_duration"
+        | "uninvert_y_axis"
+    )
+    || name.starts_with("sfx_")
+    || name.ends_with("_color")
+    || name.ends_with("_color_inner")
+    || name.ends_with("_color_outer")
+    || name.starts_with("navi_color_") && (name.ends_with("_inner") || name.ends_with("_outer"))
+}
+
+/// Parses a cosmetics preset, i.e. a settings preset which may only contain cosmetic settings, to be merged into a goal's settings.
+///
+/// Since cosmetics don't affect the seed, this allows organizers to change them without affecting the race.
+pub(crate) fn parse_cosmetics_preset(json: &str) -> Result<serde_json::Map<String, serde_json::Value>, SettingsPresetError> {
+    let cosmetics = parse_settings_preset(json)?;
+    if let Some(name) = cosmetics.keys().find(|name| !is_cosmetic_setting(name)) {
+        return Err(SettingsPresetError::NotCosmetic(name.clone()))
+    }
+    Ok(cosmetics)
+}
 
 pub(crate) trait HashIconExt {
     fn to_html(&self) -> RawHtml<String>;
@@ -321,6 +461,33 @@ pub(crate) async fn table(seeds: impl Stream<Item = Data>, spoiler_logs: bool) -
     })
 }
 
+impl Data {
+    /// Looks up the spoiler log unlock state of a seed hosted on Mido's House, using the in-memory metadata for rooms handled by this instance and falling back to the database.
+    async fn from_file_stem(transaction: &mut Transaction<'_, Postgres>, seed_metadata: &RwLock<HashMap<String, SeedMetadata>>, file_stem: &str) -> sqlx::Result<Self> {
+        let SeedMetadata { locked_spoiler_log_path, progression_spoiler, .. } = if let Some(info) = lock!(@read seed_metadata = seed_metadata; seed_metadata.get(file_stem).cloned()) {
+            info
+        } else if let Some(row) = sqlx::query!(r#"SELECT locked_spoiler_log_path, series AS "series: Series", event FROM races WHERE file_stem = $1"#, file_stem).fetch_optional(&mut **transaction).await? {
+            SeedMetadata {
+                locked_spoiler_log_path: row.locked_spoiler_log_path,
+                progression_spoiler: racetime_bot::Goal::for_event(row.series, &row.event).is_some_and(|goal| goal.unlock_spoiler_log(None, true, false) == UnlockSpoilerLog::Progression),
+                rando_commit: None,
+                rolled_at: None,
+            }
+        } else {
+            SeedMetadata::default()
+        };
+        Ok(Self {
+            password: None, // not displayed
+            files: Some(Files::MidosHouse {
+                file_stem: Cow::Owned(file_stem.to_owned()),
+                locked_spoiler_log_path,
+            }),
+            file_hash: None,
+            progression_spoiler,
+        })
+    }
+}
+
 #[derive(Responder)]
 pub(crate) enum GetResponse {
     Page(RawHtml<String>),
@@ -368,22 +535,7 @@ pub(crate) async fn get(pool: &State<PgPool>, me: Option<User>, uri: Origin<'_>,
         }
         Some("json") => if let Some(file_stem) = file_stem.strip_suffix("_Progression") {
             let mut transaction = pool.begin().await?;
-            let SeedMetadata { locked_spoiler_log_path, progression_spoiler } = if let Some(info) = lock!(@read seed_metadata = seed_metadata; seed_metadata.get(file_stem).cloned()) {
-                info
-            } else if let Some(locked_spoiler_log_path) = sqlx::query_scalar!("SELECT locked_spoiler_log_path FROM races WHERE file_stem = $1", file_stem).fetch_optional(&mut *transaction).await? {
-                SeedMetadata { locked_spoiler_log_path, progression_spoiler: false /* no official races with progression spoilers so far */ }
-            } else {
-                SeedMetadata::default()
-            };
-            let seed = Data {
-                password: None, // not displayed
-                files: Some(Files::MidosHouse {
-                    file_stem: Cow::Owned(file_stem.to_owned()),
-                    locked_spoiler_log_path,
-                }),
-                file_hash: None,
-                progression_spoiler,
-            };
+            let seed = Data::from_file_stem(&mut transaction, seed_metadata, file_stem).await?;
             let extra = seed.extra(Utc::now()).await?;
             match extra.spoiler_status {
                 SpoilerStatus::Unlocked(_) | SpoilerStatus::Progression => {}
@@ -431,22 +583,7 @@ pub(crate) async fn get(pool: &State<PgPool>, me: Option<User>, uri: Origin<'_>,
         Some(_) => return Err(StatusOrError::Status(Status::NotFound)),
         None => {
             let mut transaction = pool.begin().await?;
-            let SeedMetadata { locked_spoiler_log_path, progression_spoiler } = if let Some(info) = lock!(@read seed_metadata = seed_metadata; seed_metadata.get(file_stem).cloned()) {
-                info
-            } else if let Some(locked_spoiler_log_path) = sqlx::query_scalar!("SELECT locked_spoiler_log_path FROM races WHERE file_stem = $1", file_stem).fetch_optional(&mut *transaction).await? {
-                SeedMetadata { locked_spoiler_log_path, progression_spoiler: false /* no official races with progression spoilers so far */ }
-            } else {
-                SeedMetadata::default()
-            };
-            let seed = Data {
-                password: None, // not displayed
-                files: Some(Files::MidosHouse {
-                    file_stem: Cow::Owned(file_stem.to_owned()),
-                    locked_spoiler_log_path,
-                }),
-                file_hash: None,
-                progression_spoiler,
-            };
+            let seed = Data::from_file_stem(&mut transaction, seed_metadata, file_stem).await?;
             let extra = seed.extra(Utc::now()).await?;
             let patch_suffix = if let Some(world_count) = extra.world_count {
                 if world_count.get() > 1 { "zpfz" } else { "zpf" }
@@ -494,3 +631,95 @@ pub(crate) async fn get(pool: &State<PgPool>, me: Option<User>, uri: Origin<'_>,
         }
     })
 }
+
+/// Serves the spoiler log of a seed hosted on Mido's House, respecting its unlock status.
+///
+/// If only the progression spoiler is available, it is served instead of the full spoiler log.
+#[rocket::get("/seed/<file_stem>/spoiler")]
+pub(crate) async fn spoiler(pool: &State<PgPool>, seed_metadata: &State<Arc<RwLock<HashMap<String, SeedMetadata>>>>, file_stem: &str) -> Result<GetResponse, StatusOrError<GetError>> {
+    if !regex_is_match!("^[0-9A-Za-z_-]+$", file_stem) { return Err(StatusOrError::Status(Status::NotFound)) }
+    let mut transaction = pool.begin().await?;
+    let seed = Data::from_file_stem(&mut transaction, seed_metadata, file_stem).await?;
+    transaction.commit().await?;
+    let extra = seed.extra(Utc::now()).await?;
+    let (spoiler, chests) = match extra.spoiler_status {
+        SpoilerStatus::Unlocked(spoiler_file_name) => {
+            let spoiler = match fs::read(Path::new(DIR).join(spoiler_file_name)).await {
+                Ok(spoiler) => spoiler,
+                Err(wheel::Error::Io { inner, .. }) if inner.kind() == io::ErrorKind::NotFound => return Err(StatusOrError::Status(Status::NotFound)),
+                Err(e) => return Err(e.into()),
+            };
+            (spoiler, extra.chests)
+        }
+        SpoilerStatus::Progression => {
+            let spoiler_path = if let Some(Files::MidosHouse { locked_spoiler_log_path: Some(path), .. }) = seed.files {
+                PathBuf::from(path)
+            } else {
+                Path::new(DIR).join(format!("{file_stem}_Spoiler.json"))
+            };
+            let spoiler = match fs::read_json(spoiler_path).await {
+                Ok(spoiler) => spoiler,
+                Err(wheel::Error::Io { inner, .. }) if inner.kind() == io::ErrorKind::NotFound => return Err(StatusOrError::Status(Status::NotFound)),
+                Err(e) => return Err(e.into()),
+            };
+            (serde_json::to_vec_pretty(&tfb::progression_spoiler(spoiler))?, extra.chests)
+        }
+        SpoilerStatus::Locked => return Err(StatusOrError::Status(Status::Forbidden)),
+        SpoilerStatus::NotFound => return Err(StatusOrError::Status(Status::NotFound)),
+    };
+    Ok(GetResponse::Spoiler {
+        inner: RawJson(spoiler),
+        content_disposition: Header::new(CONTENT_DISPOSITION.as_str(), "inline"),
+        // may not work in all browsers, see https://bugzilla.mozilla.org/show_bug.cgi?id=1185705
+        link: Header::new(LINK.as_str(), format!(r#"<{}>; rel="icon"; sizes="1024x1024""#, uri!(favicon::favicon_png(Suffix(chests.textures(), "png"))))),
+    })
+}
+
+/// Settings which are omitted from [`settings`] while the spoiler log is locked since they would allow reproducing the seed.
+const LOCKED_SETTINGS: [&str; 1] = [
+    "seed",
+];
+
+/// Returns the settings a seed hosted on Mido's House was rolled with, as a JSON object.
+///
+/// While the spoiler log is locked, settings which would allow reproducing the seed are omitted.
+/// Random settings seeds are not served at all until their spoiler log is unlocked.
+#[rocket::get("/seed/<file_stem>/settings.json")]
+pub(crate) async fn settings(pool: &State<PgPool>, seed_metadata: &State<Arc<RwLock<HashMap<String, SeedMetadata>>>>, file_stem: &str) -> Result<RawJson<Vec<u8>>, StatusOrError<GetError>> {
+    if !regex_is_match!("^[0-9A-Za-z_-]+$", file_stem) { return Err(StatusOrError::Status(Status::NotFound)) }
+    let mut transaction = pool.begin().await?;
+    let seed = Data::from_file_stem(&mut transaction, seed_metadata, file_stem).await?;
+    let is_rsl = sqlx::query_scalar!(r#"SELECT EXISTS (SELECT 1 FROM rsl_seeds WHERE file_stem = $1) AS "exists!""#, file_stem).fetch_one(&mut *transaction).await?;
+    transaction.commit().await?;
+    let extra = seed.extra(Utc::now()).await?;
+    let locked = match extra.spoiler_status {
+        SpoilerStatus::Unlocked(_) => false,
+        SpoilerStatus::Progression | SpoilerStatus::Locked => if is_rsl { return Err(StatusOrError::Status(Status::Forbidden)) } else { true },
+        SpoilerStatus::NotFound => return Err(StatusOrError::Status(Status::NotFound)),
+    };
+    let spoiler_path = if let Some(Files::MidosHouse { locked_spoiler_log_path: Some(path), .. }) = seed.files {
+        PathBuf::from(path)
+    } else {
+        Path::new(DIR).join(format!("{file_stem}_Spoiler.json"))
+    };
+    let mut spoiler = match fs::read_json::<serde_json::Map<String, serde_json::Value>>(spoiler_path).await {
+        Ok(spoiler) => spoiler,
+        Err(wheel::Error::Io { inner, .. }) if inner.kind() == io::ErrorKind::NotFound => return Err(StatusOrError::Status(Status::NotFound)),
+        Err(e) => return Err(e.into()),
+    };
+    let Some(serde_json::Value::Object(mut settings)) = spoiler.remove("settings") else { return Err(StatusOrError::Status(Status::NotFound)) };
+    if locked {
+        for name in LOCKED_SETTINGS {
+            settings.remove(name);
+        }
+    }
+    Ok(RawJson(serde_json::to_vec_pretty(&settings)?))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn pic7_cosmetics_preset() {
+        super::parse_cosmetics_preset(include_str!("../assets/event/pic/cosmetics-7.json")).expect("invalid cosmetics preset");
+    }
+}
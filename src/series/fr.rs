@@ -122,6 +122,29 @@ pub(crate) const S4_SETTINGS: [Setting; 27] = [
     Setting { name: "reachable", display: "reachable locations", default: "all", default_display: "all locations reachable", other: &[("required", true, "required only")], description: "reachable: all (défaut) ou required (difficile)" },
 ];
 
+/// If picking this value overrides another setting, returns the name of that setting.
+///
+/// In live drafts, such picks are rejected once the other setting has been banned, since they would effectively undo the ban.
+pub(crate) fn overridden_setting(setting: &str, value: &str) -> Option<&'static str> {
+    match (setting, value) {
+        // small keys are removed regardless of where keysanity would place them
+        ("keysy", "on") => Some("keysanity"),
+        (_, _) => None,
+    }
+}
+
+/// The key under which a ban of the given setting is recorded in the draft picks.
+///
+/// Bans have to be recorded separately from the setting's value since picking the default value is also allowed.
+pub(crate) fn ban_key(setting: &str) -> Cow<'static, str> {
+    Cow::Owned(format!("{setting}_banned"))
+}
+
+/// Whether dungeon entrances can be mixed with interiors and grottos, which requires both to be shuffled.
+pub(crate) fn can_mix_dungeons(picks: &draft::Picks) -> bool {
+    picks.get("dungeon-er").map(|dungeon_er| &**dungeon_er).unwrap_or("off") == "on" && picks.get("mixed-er").map(|mixed_er| &**mixed_er).unwrap_or("off") == "on"
+}
+
 pub(crate) fn display_draft_picks(language: Language, all_settings: &[Setting], picks: &draft::Picks) -> String {
     let mut picks_display = Vec::default();
     if picks.get("mq_ok").map(|mq_ok| &**mq_ok).unwrap_or("no") == "ok" || picks.get("mq_dungeons_count").map(|mq_dungeons_count| &**mq_dungeons_count).unwrap_or("0") != "0" {
@@ -648,7 +648,9 @@ pub(crate) async fn find_team_form(mut transaction: Transaction<'_, Postgres>, m
 }
 
 pub(crate) fn race7_settings() -> serde_json::Map<String, Json> {
-    collect![
+    static COSMETICS: LazyLock<serde_json::Map<String, Json>> = LazyLock::new(|| seed::parse_cosmetics_preset(include_str!("../../assets/event/pic/cosmetics-7.json")).expect("invalid cosmetics preset"));
+
+    let mut settings = collect![
         format!("user_message") => json!("7th Pictionary Spoiler Log Race"),
         format!("open_forest") => json!("closed_deku"),
         format!("open_kakariko") => json!("open"),
@@ -719,5 +721,7 @@ pub(crate) fn race7_settings() -> serde_json::Map<String, Json> {
             "Eyedrops",
             "Claim Check",
         ]),
-    ]
+    ];
+    settings.extend(COSMETICS.clone());
+    settings
 }
@@ -33,59 +33,6 @@ pub(crate) async fn info(transaction: &mut Transaction<'_, Postgres>, data: &Dat
 }
 
 pub(crate) fn settings() -> serde_json::Map<String, Json> {
-    collect![
-        format!("user_message") => json!("Songs of Hope Charity Tournament"),
-        format!("trials") => json!(0),
-        format!("shuffle_ganon_bosskey") => json!("on_lacs"),
-        format!("shuffle_mapcompass") => json!("startwith"),
-        format!("enhance_map_compass") => json!(true),
-        format!("open_forest") => json!("closed_deku"),
-        format!("open_kakariko") => json!("open"),
-        format!("open_door_of_time") => json!(true),
-        format!("gerudo_fortress") => json!("fast"),
-        format!("starting_age") => json!("random"),
-        format!("disabled_locations") => json!([
-            "Deku Theater Skull Mask",
-            "Deku Theater Mask of Truth",
-            "Kak 30 Gold Skulltula Reward",
-            "Kak 40 Gold Skulltula Reward",
-            "Kak 50 Gold Skulltula Reward",
-            "DMC Deku Scrub",
-            "ZR Frogs Ocarina Game",
-            "GF HBA 1000 Points",
-            "GF HBA 1500 Points",
-        ]),
-        format!("allowed_tricks") => json!([
-            "logic_grottos_without_agony",
-            "logic_man_on_roof",
-        ]),
-        format!("starting_equipment") => json!([
-            "deku_shield",
-        ]),
-        format!("start_with_consumables") => json!(true),
-        format!("start_with_rupees") => json!(true),
-        format!("no_escape_sequence") => json!(true),
-        format!("no_guard_stealth") => json!(true),
-        format!("no_epona_race") => json!(true),
-        format!("skip_some_minigame_phases") => json!(true),
-        format!("complete_mask_quest") => json!(true),
-        format!("free_scarecrow") => json!(true),
-        format!("fast_bunny_hood") => json!(true),
-        format!("auto_equip_masks") => json!(true),
-        format!("plant_beans") => json!(true),
-        format!("chicken_count") => json!(0),
-        format!("big_poe_count") => json!(1),
-        format!("ruto_already_f1_jabu") => json!(true),
-        format!("correct_chest_appearances") => json!("both"),
-        format!("hint_dist") => json!("tournament"),
-        format!("misc_hints") => json!([
-            "altar",
-            "dampe_diary",
-            "ganondorf",
-            "warp_songs_and_owls",
-            "10_skulltulas",
-            "20_skulltulas",
-        ]),
-        format!("blue_fire_arrows") => json!(true),
-    ]
+    static SETTINGS: LazyLock<serde_json::Map<String, Json>> = LazyLock::new(|| seed::parse_settings_preset(include_str!("../../assets/event/soh/settings-1.json")).expect("invalid settings preset"));
+    SETTINGS.clone()
 }
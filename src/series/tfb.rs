@@ -26,6 +26,27 @@ impl fmt::Display for Score {
     }
 }
 
+pub(crate) enum ScoreParseError {
+    Pieces,
+    Time,
+}
+
+impl Score {
+    /// Parses a score given as chat command arguments, e.g. `3 1h23m45s`.
+    pub(crate) fn parse(args: &[String]) -> Result<Self, ScoreParseError> {
+        let (pieces, duration) = args.split_first().ok_or(ScoreParseError::Pieces)?;
+        let pieces = pieces.parse().ok().filter(|&pieces| pieces <= 3).ok_or(ScoreParseError::Pieces)?;
+        Ok(Self {
+            last_collection_time: if pieces == 0 {
+                Duration::default()
+            } else {
+                parse_duration(&duration.join(" "), DurationUnit::Hours).ok_or(ScoreParseError::Time)?
+            },
+            pieces,
+        })
+    }
+}
+
 pub(crate) fn report_score_button(finish_time: Option<Duration>) -> (&'static str, ActionButton) {
     ("Report score", ActionButton::Message {
         message: format!("!score ${{pieces}} ${{last_collection_time}}"),
@@ -113,6 +134,15 @@ pub(crate) async fn info(transaction: &mut Transaction<'_, Postgres>, data: &Dat
                 }
             }
         }),
+        "progression" => Some(html! {
+            article {
+                p {
+                    : "This is a Triforce Blitz event with progression spoilers, organized by ";
+                    : English.join_html(data.organizers(transaction).await?);
+                    : ". A spoiler log showing only the items required to beat the seed is available on the seed page during the race; the full spoiler log is unlocked afterwards.";
+                }
+            }
+        }),
         _ => None,
     })
 }
@@ -18,6 +18,8 @@ use {
 const RATE_LIMIT: Duration = Duration::from_secs(1);
 
 static CACHE: LazyLock<Mutex<(Instant, HashMap<(String, String), (Instant, Vec<Vec<String>>)>)>> = LazyLock::new(|| Mutex::new((Instant::now() + RATE_LIMIT, HashMap::default())));
+/// The contents of each schedule sheet as of the last import, so that unchanged sheets can be skipped.
+static SCHEDULE_SHEETS: LazyLock<Mutex<HashMap<Url, Vec<u8>>>> = LazyLock::new(Mutex::default);
 
 #[derive(Debug, thiserror::Error)]
 enum UncachedError {
@@ -117,3 +119,77 @@ pub(crate) async fn values(http_client: reqwest::Client, sheet_id: &str, range:
         })
     })
 }
+
+/// Reads races from an event's schedule sheet, published as CSV (e.g. via “File → Share → Publish to web” in Google Sheets).
+///
+/// The first row must be a header row. A column labelled “start” (in UTC, either RFC 3339 or formatted like “2024-12-31 18:00”) is required,
+/// “phase” and “round” columns are optional, and each column whose label starts with “entrant” holds the name of one entrant.
+/// Rows missing a valid start time or without exactly 2 or 3 entrants are skipped, as are races which already exist for this event.
+/// If the sheet hasn't changed since the last call, no races are returned without checking the existing races.
+pub(crate) async fn races_to_import(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &event::Data<'_>, url: &Url) -> Result<Vec<Race>, cal::Error> {
+    let body = http_client.get(url.clone())
+        .send().await?
+        .detailed_error_for_status().await?
+        .bytes().await?;
+    let unchanged = lock!(schedule_sheets = SCHEDULE_SHEETS; {
+        if schedule_sheets.get(url).is_some_and(|last_body| *last_body == *body) {
+            true
+        } else {
+            schedule_sheets.insert(url.clone(), body.to_vec());
+            false
+        }
+    });
+    if unchanged { return Ok(Vec::default()) }
+    let mut reader = csv::Reader::from_reader(&*body);
+    let headers = reader.headers()?.iter().map(|header| header.trim().to_ascii_lowercase()).collect_vec();
+    let start_column = headers.iter().position(|header| header == "start").ok_or(cal::Error::ScheduleSheetStartColumn)?;
+    let phase_column = headers.iter().position(|header| header == "phase");
+    let round_column = headers.iter().position(|header| header == "round");
+    let entrant_columns = headers.iter().positions(|header| header.starts_with("entrant")).collect_vec();
+    let mut existing_races = Vec::default();
+    for id in sqlx::query_scalar!(r#"SELECT id AS "id: Id<Races>" FROM races WHERE series = $1 AND event = $2"#, event.series as _, &event.event).fetch_all(&mut **transaction).await? {
+        existing_races.push(Race::from_id(&mut *transaction, http_client, id).await?);
+    }
+    let mut races = Vec::<Race>::default();
+    for row in reader.records() {
+        let row = row?;
+        let Some(start) = row.get(start_column).map(str::trim).filter(|start| !start.is_empty()) else { continue };
+        let Some(start) = DateTime::parse_from_rfc3339(start).map(|start| start.to_utc())
+            .or_else(|_| NaiveDateTime::parse_from_str(start, "%Y-%m-%d %H:%M").map(|start| start.and_utc()))
+            .ok() else { continue };
+        let field = |column: Option<usize>| column.and_then(|column| row.get(column)).map(str::trim).filter(|value| !value.is_empty()).map(str::to_owned);
+        let phase = field(phase_column);
+        let round = field(round_column);
+        let mut entrants = entrant_columns.iter().filter_map(|&column| field(Some(column))).map(|name| Entrant::Named { name, racetime_id: None, twitch_username: None });
+        let entrants = match (entrants.next(), entrants.next(), entrants.next(), entrants.next()) {
+            (Some(entrant1), Some(entrant2), None, None) => Entrants::Two([entrant1, entrant2]),
+            (Some(entrant1), Some(entrant2), Some(entrant3), None) => Entrants::Three([entrant1, entrant2, entrant3]),
+            (_, _, _, _) => continue,
+        };
+        if existing_races.iter().chain(&races).any(|race| race.phase == phase && race.round == round && race.entrants == entrants) { continue }
+        races.push(Race {
+            id: Id::new(&mut *transaction).await?,
+            series: event.series,
+            event: event.event.to_string(),
+            source: cal::Source::Manual, // the start column isn't a Google Form submission timestamp, so this isn't cal::Source::Sheet
+            game: None,
+            scheduling_thread: None,
+            schedule: RaceSchedule::Live { start, end: None, room: None },
+            schedule_updated_at: None,
+            draft: None,
+            seed: seed::Data::default(),
+            video_urls: HashMap::default(),
+            restreamers: HashMap::default(),
+            required_restream_languages: HashSet::default(),
+            last_edited_by: None,
+            last_edited_at: None,
+            ignored: false,
+            schedule_locked: false,
+            allow_prerace_chat: None,
+            allow_midrace_chat: None,
+            notified: false,
+            phase, round, entrants,
+        });
+    }
+    Ok(races)
+}
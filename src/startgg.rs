@@ -226,10 +226,13 @@ pub(crate) async fn races_to_import(transaction: &mut Transaction<'_, Postgres>,
             seed: seed::Data::default(),
             video_urls: HashMap::default(),
             restreamers: HashMap::default(),
+            required_restream_languages: HashSet::default(),
             last_edited_by: None,
             last_edited_at: None,
             ignored: false,
             schedule_locked: false,
+            allow_prerace_chat: None,
+            allow_midrace_chat: None,
             notified: false,
             phase, round,
         });
@@ -41,6 +41,11 @@ impl Team {
         sqlx::query_as!(Self, r#"SELECT id AS "id: Id<Teams>", name, racetime_slug, teams.startgg_id AS "startgg_id: startgg::ID", plural_name, restream_consent, mw_impl AS "mw_impl: mw::Impl", qualifier_rank FROM teams, team_members WHERE series = $1 AND event = $2 AND NOT resigned AND id = team AND member = $3"#, series as _, event, member_id as _).fetch_optional(&mut **transaction).await
     }
 
+    /// Like [`Team::from_event_and_member`], but returns all teams the user is on, so accidental duplicate registrations can be detected.
+    pub(crate) async fn all_from_event_and_member(transaction: &mut Transaction<'_, Postgres>, series: Series, event: &str, member_id: Id<Users>) -> sqlx::Result<Vec<Self>> {
+        sqlx::query_as!(Self, r#"SELECT id AS "id: Id<Teams>", name, racetime_slug, teams.startgg_id AS "startgg_id: startgg::ID", plural_name, restream_consent, mw_impl AS "mw_impl: mw::Impl", qualifier_rank FROM teams, team_members WHERE series = $1 AND event = $2 AND NOT resigned AND id = team AND member = $3"#, series as _, event, member_id as _).fetch_all(&mut **transaction).await
+    }
+
     pub(crate) fn dummy() -> Self {
         Self {
             id: Id::dummy(),
@@ -35,6 +35,11 @@ pub(crate) enum ClientMessage {
     CleanupRoles {
         guild_id: GuildId,
     },
+    /// Aborts the handler task for a racetime.gg room, e.g. if it's stuck and blocking a clean shutdown.
+    ForceStopRoom {
+        /// The room URL, either including the racetime.gg host or as a path like `/ootr/random-slug-1234`.
+        room_url: String,
+    },
     PrepareStop {
         #[clap(long)]
         no_new_rooms: bool,
@@ -52,19 +57,40 @@ pub(crate) enum ClientMessage {
         branch: String,
         #[clap(long)]
         rsl_version: Option<ootr_utils::Version>,
+        /// A commit, tag, or branch of the RSL script to use instead of the latest version.
+        #[clap(long, conflicts_with = "rsl_version")]
+        rsl_ref: Option<String>,
         #[clap(short = 'n', long, default_value_t = 1)]
         worlds: u8,
         #[clap(short = 'l', long)]
         spoiler_log: bool,
     },
+    /// Rolls a Triforce Blitz seed with additional generator form fields, given as `name=value`.
+    RollTfb {
+        version: String,
+        #[clap(short = 'l', long)]
+        spoiler_log: bool,
+        fields: Vec<String>,
+    },
     Seed {
         #[clap(long = "official")]
         is_official: bool,
         #[clap(short = 'l', long, alias = "spoiler-log")]
         spoiler_seed: bool,
+        /// The series of the event whose configuration (e.g. preroll mode or randomizer version) should be used.
+        #[clap(long, requires = "event")]
+        series: Option<String>,
+        /// The identifier of the event within `--series`.
+        #[clap(long, requires = "series")]
+        event: Option<String>,
         goal: Goal,
         args: Vec<String>,
     },
+    /// Replays the lifecycle of an existing race room without connecting to its websocket. Only available in the dev environment.
+    SimulateRace {
+        /// The room URL, either including the racetime.gg host or as a path like `/ootr/random-slug-1234`.
+        room_url: String,
+    },
     UpdateRegionalVc {
         user_id: Id<Users>,
         scene: u8,
@@ -106,6 +132,17 @@ pub(crate) async fn listen(mut shutdown: rocket::Shutdown, clean_shutdown: Arc<M
                                 transaction.commit().await.expect("error cleaning up Discord roles");
                                 0u8.write(&mut sock).await.expect("error writing to UNIX socket");
                             }
+                            Ok(ClientMessage::ForceStopRoom { room_url }) => {
+                                let room_url = room_url.strip_prefix(&format!("https://{}", racetime_host())).unwrap_or(&room_url);
+                                let abort_handle = lock!(clean_shutdown = clean_shutdown; clean_shutdown.open_rooms.get(room_url).and_then(|open_room| open_room.abort_handle.clone()));
+                                if let Some(abort_handle) = abort_handle {
+                                    // the room is removed from the list of open rooms (notifying a pending clean shutdown if applicable) once the handler task has stopped
+                                    abort_handle.abort();
+                                    0u8.write(&mut sock).await.expect("error writing to UNIX socket");
+                                } else {
+                                    1u8.write(&mut sock).await.expect("error writing to UNIX socket");
+                                }
+                            }
                             Ok(ClientMessage::PrepareStop { no_new_rooms }) => {
                                 println!("preparing to stop Mido's House: acquiring clean shutdown mutex");
                                 lock!(clean_shutdown = clean_shutdown; {
@@ -113,7 +150,7 @@ pub(crate) async fn listen(mut shutdown: rocket::Shutdown, clean_shutdown: Arc<M
                                     if no_new_rooms { clean_shutdown.block_new = true }
                                     if !clean_shutdown.open_rooms.is_empty() {
                                         println!("preparing to stop Mido's House: waiting for {} rooms to close:", clean_shutdown.open_rooms.len());
-                                        for room_url in &clean_shutdown.open_rooms {
+                                        for room_url in clean_shutdown.open_rooms.keys() {
                                             println!("https://{}{room_url}", racetime_host());
                                         }
                                         let notifier = Arc::clone(&clean_shutdown.notifier);
@@ -131,7 +168,7 @@ pub(crate) async fn listen(mut shutdown: rocket::Shutdown, clean_shutdown: Arc<M
                                 break
                             }
                             Ok(ClientMessage::Roll { version, settings, spoiler_log }) => if let Json::Object(settings) = settings {
-                                let mut rx = global_state.clone().roll_seed(PrerollMode::Medium, true, None, VersionedBranch::Pinned(version), settings, if spoiler_log { UnlockSpoilerLog::Now } else { UnlockSpoilerLog::Never });
+                                let mut rx = global_state.clone().roll_seed(PrerollMode::Medium, true, false, None, VersionedBranch::Pinned(version), settings, if spoiler_log { UnlockSpoilerLog::Now } else { UnlockSpoilerLog::Never });
                                 loop {
                                     let update = rx.recv().await;
                                     update.write(&mut sock).await.expect("error writing to UNIX socket");
@@ -142,11 +179,11 @@ pub(crate) async fn listen(mut shutdown: rocket::Shutdown, clean_shutdown: Arc<M
                                 None::<SeedRollUpdate>.write(&mut sock).await.expect("error writing to UNIX socket");
                                 break
                             },
-                            Ok(ClientMessage::RollRsl { preset, branch, rsl_version, worlds, spoiler_log }) => {
+                            Ok(ClientMessage::RollRsl { preset, branch, rsl_version, rsl_ref, worlds, spoiler_log }) => {
                                 let preset = if let Some(rsl_version) = rsl_version {
                                     VersionedRslPreset::new_versioned(rsl_version, preset.as_deref())
                                 } else {
-                                    VersionedRslPreset::new_unversioned(&branch, preset.as_deref())
+                                    VersionedRslPreset::new_unversioned(&branch, rsl_ref, preset.as_deref())
                                 };
                                 if let Ok(preset) = preset {
                                     let mut rx = global_state.clone().roll_rsl_seed(None, preset, worlds, if spoiler_log { UnlockSpoilerLog::Now } else { UnlockSpoilerLog::Never });
@@ -161,7 +198,31 @@ pub(crate) async fn listen(mut shutdown: rocket::Shutdown, clean_shutdown: Arc<M
                                     break
                                 }
                             }
-                            Ok(ClientMessage::Seed { goal, is_official, spoiler_seed, args }) => {
+                            Ok(ClientMessage::RollTfb { version, spoiler_log, fields }) => if let Some(fields) = fields.iter().map(|field| field.split_once('=').map(|(name, value)| (name.to_owned(), value.to_owned()))).collect::<Option<Vec<_>>>() {
+                                let mut rx = global_state.clone().roll_tfb_dev_seed(version, if spoiler_log { UnlockSpoilerLog::Now } else { UnlockSpoilerLog::Never }, fields);
+                                loop {
+                                    let update = rx.recv().await;
+                                    update.write(&mut sock).await.expect("error writing to UNIX socket");
+                                    if update.is_none() { break }
+                                }
+                            } else {
+                                Some(SeedRollUpdate::Error(RollError::Cloned { debug: String::default(), display: "generator fields must be formatted like name=value".to_owned() })).write(&mut sock).await.expect("error writing to UNIX socket");
+                                None::<SeedRollUpdate>.write(&mut sock).await.expect("error writing to UNIX socket");
+                                break
+                            },
+                            Ok(ClientMessage::SimulateRace { room_url }) => if Environment::default().is_dev() {
+                                let mut rx = global_state.clone().simulate_race(room_url);
+                                loop {
+                                    let update = rx.recv().await;
+                                    update.write(&mut sock).await.expect("error writing to UNIX socket");
+                                    if update.is_none() { break }
+                                }
+                            } else {
+                                Some(format!("race simulation is only available in the dev environment")).write(&mut sock).await.expect("error writing to UNIX socket");
+                                None::<String>.write(&mut sock).await.expect("error writing to UNIX socket");
+                                break
+                            },
+                            Ok(ClientMessage::Seed { goal, is_official, spoiler_seed, series, event, args }) => {
                                 let mut transaction = match global_state.db_pool.begin().await {
                                     Ok(transaction) => transaction,
                                     Err(e) => {
@@ -170,10 +231,32 @@ pub(crate) async fn listen(mut shutdown: rocket::Shutdown, clean_shutdown: Arc<M
                                         break
                                     }
                                 };
-                                let mut rx = match goal.parse_seed_command(&mut transaction, &global_state, is_official, spoiler_seed, &args).await {
+                                let event = if let (Some(series), Some(event)) = (series, event) {
+                                    let Ok(series) = series.parse() else {
+                                        Some(SeedRollUpdate::Error(RollError::Cloned { debug: String::default(), display: format!("no series with the identifier {series:?}") })).write(&mut sock).await.expect("error writing to UNIX socket");
+                                        None::<SeedRollUpdate>.write(&mut sock).await.expect("error writing to UNIX socket");
+                                        break
+                                    };
+                                    match event::Data::new(&mut transaction, series, event).await {
+                                        Ok(Some(event)) => Some(event),
+                                        Ok(None) => {
+                                            Some(SeedRollUpdate::Error(RollError::Cloned { debug: String::default(), display: format!("no event with this series and identifier") })).write(&mut sock).await.expect("error writing to UNIX socket");
+                                            None::<SeedRollUpdate>.write(&mut sock).await.expect("error writing to UNIX socket");
+                                            break
+                                        }
+                                        Err(e) => {
+                                            Some(SeedRollUpdate::Error(RollError::Cloned { debug: format!("{e:?}"), display: e.to_string() })).write(&mut sock).await.expect("error writing to UNIX socket");
+                                            None::<SeedRollUpdate>.write(&mut sock).await.expect("error writing to UNIX socket");
+                                            break
+                                        }
+                                    }
+                                } else {
+                                    None
+                                };
+                                let mut rx = match goal.parse_seed_command(&mut transaction, &global_state, event.as_ref(), is_official, spoiler_seed, &args).await {
                                     Ok(SeedCommandParseResult::Regular { settings, unlock_spoiler_log, description, .. }) => {
                                         Some(SeedRollUpdate::Message(description)).write(&mut sock).await.expect("error writing to UNIX socket");
-                                        global_state.clone().roll_seed(goal.preroll_seeds(), true, None, goal.rando_version(None /*TODO replace is_official parameter with optional series and event*/), settings, unlock_spoiler_log)
+                                        global_state.clone().roll_seed(goal.preroll_mode(event.as_ref()), true, is_official, None, goal.rando_version(event.as_ref()), settings, unlock_spoiler_log)
                                     }
                                     Ok(SeedCommandParseResult::Rsl { preset, world_count, unlock_spoiler_log, description, .. }) => {
                                         Some(SeedRollUpdate::Message(description)).write(&mut sock).await.expect("error writing to UNIX socket");
@@ -185,7 +268,7 @@ pub(crate) async fn listen(mut shutdown: rocket::Shutdown, clean_shutdown: Arc<M
                                     }
                                     Ok(SeedCommandParseResult::QueueExisting { data, description, .. }) => {
                                         Some(SeedRollUpdate::Message(description)).write(&mut sock).await.expect("error writing to UNIX socket");
-                                        Some(SeedRollUpdate::Done { rsl_preset: None, unlock_spoiler_log: UnlockSpoilerLog::After, seed: data }).write(&mut sock).await.expect("error writing to UNIX socket");
+                                        Some(SeedRollUpdate::Done { rsl_preset: None, unlock_spoiler_log: UnlockSpoilerLog::After, rando_commit: None, attempts: None, seed: data }).write(&mut sock).await.expect("error writing to UNIX socket");
                                         None::<SeedRollUpdate>.write(&mut sock).await.expect("error writing to UNIX socket");
                                         break
                                     }